@@ -1,6 +1,6 @@
 use clap::{Arg, Command};
 use std::fs;
-use rust_janus::{JanusClient, JanusServer, JanusClientConfig, ServerConfig, ManifestParser, Manifest};
+use rust_janus::{JanusClient, JanusServer, JanusClientConfig, ServerConfig, CoercionMode, ManifestParser, Manifest, RustCodeGenerator, RustCodegenOptions, ManifestLinter, LintSeverity};
 use std::collections::HashMap;
 use serde_json::Value;
 use tokio;
@@ -48,20 +48,121 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .help("Message to send")
                 .default_value("hello"),
         )
+        .arg(
+            Arg::new("arg")
+                .short('a')
+                .long("arg")
+                .value_name("NAME=VALUE")
+                .help("Request argument, converted to the type the server's Manifest declares")
+                .action(clap::ArgAction::Append),
+        )
         .arg(
             Arg::new("manifest")
                 .long("manifest")
                 .value_name("FILE")
                 .help("Manifest file (required for validation)"),
         )
+        .arg(
+            Arg::new("watch-manifest")
+                .long("watch-manifest")
+                .help("Reload the Manifest file when it changes while listening")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("coerce")
+                .long("coerce")
+                .help("Convert string arguments to their Manifest types when listening")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("strict-responses")
+                .long("strict-responses")
+                .help("Reject handler results that violate the Manifest when listening")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .subcommand(
+            Command::new("generate")
+                .about("Generate typed Rust code from a Manifest")
+                .arg(
+                    Arg::new("manifest")
+                        .long("manifest")
+                        .value_name("FILE")
+                        .help("Manifest file to generate code for")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Write generated code to FILE instead of stdout"),
+                )
+                .arg(
+                    Arg::new("kind")
+                        .long("kind")
+                        .value_name("KIND")
+                        .help("Code to generate next to the types")
+                        .value_parser(["client", "server", "both"])
+                        .default_value("client"),
+                )
+                .arg(
+                    Arg::new("client-name")
+                        .long("client-name")
+                        .value_name("NAME")
+                        .help("Name of the generated client type")
+                        .default_value("ManifestClient"),
+                )
+                .arg(
+                    Arg::new("server-name")
+                        .long("server-name")
+                        .value_name("NAME")
+                        .help("Name of the generated server trait")
+                        .default_value("ManifestService"),
+                ),
+        )
+        .subcommand(
+            Command::new("lint")
+                .about("Report every issue in a Manifest with its line and column")
+                .arg(
+                    Arg::new("manifest")
+                        .long("manifest")
+                        .value_name("FILE")
+                        .help("Manifest file to lint")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_name("FORMAT")
+                        .help("Output format")
+                        .value_parser(["text", "json"])
+                        .default_value("text"),
+                )
+                .arg(
+                    Arg::new("deny-warnings")
+                        .long("deny-warnings")
+                        .help("Exit with an error status on warnings too")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
         .get_matches();
 
+    match matches.subcommand() {
+        Some(("generate", generate_matches)) => return generate_code(generate_matches).await,
+        Some(("lint", lint_matches)) => return lint_manifest(lint_matches).await,
+        _ => {}
+    }
+
     let socket_path = matches.get_one::<String>("socket").unwrap();
     let listen = matches.get_flag("listen");
     let send_to = matches.get_one::<String>("send-to");
     let request = matches.get_one::<String>("request").unwrap();
     let message = matches.get_one::<String>("message").unwrap();
     let manifest_path = matches.get_one::<String>("manifest");
+    let request_args: Vec<&String> = matches.get_many::<String>("arg").unwrap_or_default().collect();
+    let coerce = matches.get_flag("coerce");
+    let strict_responses = matches.get_flag("strict-responses");
+    let watch_manifest = if matches.get_flag("watch-manifest") { manifest_path } else { None };
     // Channel removed from protocol
 
     // Load Manifest if provided
@@ -78,10 +179,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     if listen {
         // Server mode - use JanusServer API
-        listen_for_datagrams(socket_path, manifest).await?;
+        listen_for_datagrams(socket_path, manifest, watch_manifest, coerce, strict_responses).await?;
     } else if let Some(target_socket) = send_to {
         // Client mode - use JanusClient API
-        send_datagram(target_socket, request, message).await?;
+        send_datagram(target_socket, request, message, &request_args).await?;
     } else {
         eprintln!("Usage: either --listen or --send-to required");
         std::process::exit(1);
@@ -90,9 +191,61 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+async fn generate_code(matches: &clap::ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let manifest_path = matches.get_one::<String>("manifest").unwrap();
+    let kind = matches.get_one::<String>("kind").unwrap();
+    let options = RustCodegenOptions {
+        client: kind != "server",
+        client_name: matches.get_one::<String>("client-name").unwrap().clone(),
+        server: kind != "client",
+        server_name: matches.get_one::<String>("server-name").unwrap().clone(),
+    };
+
+    let manifest = ManifestParser::load_and_validate(manifest_path).await?;
+    let source = RustCodeGenerator::generate(&manifest, &options)?;
+
+    match matches.get_one::<String>("output") {
+        Some(output_path) => {
+            fs::write(output_path, source)?;
+            eprintln!("Generated {} from {}", output_path, manifest_path);
+        }
+        None => print!("{}", source),
+    }
+
+    Ok(())
+}
+
+async fn lint_manifest(matches: &clap::ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let manifest_path = matches.get_one::<String>("manifest").unwrap();
+    let report = ManifestLinter::lint_file(manifest_path).await?;
+
+    if matches.get_one::<String>("format").unwrap() == "json" {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        for issue in &report.issues {
+            println!("{}:{}", manifest_path, issue);
+        }
+        println!(
+            "{} errors, {} warnings, {} info",
+            report.count(LintSeverity::Error),
+            report.count(LintSeverity::Warning),
+            report.count(LintSeverity::Info)
+        );
+    }
+
+    let deny_warnings = matches.get_flag("deny-warnings");
+    if report.has_errors() || (deny_warnings && report.count(LintSeverity::Warning) > 0) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
 async fn listen_for_datagrams(
     socket_path: &str,
-    _manifest: Option<Manifest>,
+    manifest: Option<Manifest>,
+    watch_manifest: Option<&String>,
+    coerce: bool,
+    strict_responses: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     println!("Listening for SOCK_DGRAM on: {}", socket_path);
 
@@ -107,6 +260,18 @@ async fn listen_for_datagrams(
     // Create and start server using library API
     let mut server = JanusServer::new(config);
 
+    // Validate request arguments against the loaded Manifest before dispatch
+    if let Some(manifest) = manifest {
+        server.set_manifest(manifest).await;
+    }
+    if let Some(manifest_path) = watch_manifest {
+        server.watch_manifest(manifest_path, std::time::Duration::from_secs(1)).await?;
+    }
+    if coerce {
+        server.set_coercion_mode(CoercionMode::Lenient).await;
+    }
+    server.set_strict_responses(strict_responses).await;
+
     // Register built-in request handlers (handled by library)
     // Built-in handlers (ping, echo, get_info, validate, slow_process, manifest) 
     // are automatically registered by the library
@@ -137,11 +302,15 @@ async fn send_datagram(
     target_socket: &str,
     request: &str,
     message: &str,
+    request_args: &[&String],
 ) -> Result<(), Box<dyn std::error::Error>> {
     println!("Sending SOCK_DGRAM to: {}", target_socket);
 
-    // Create client configuration
-    let config = JanusClientConfig::default();
+    // Command line arguments are always strings, let the Manifest decide their types
+    let config = JanusClientConfig {
+        coercion_mode: CoercionMode::Lenient,
+        ..Default::default()
+    };
 
     // Create client using library API (channels removed)
     let mut client = JanusClient::new(
@@ -154,6 +323,12 @@ async fn send_datagram(
     if ["echo", "get_info", "validate", "slow_process"].contains(&request) {
         args.insert("message".to_string(), Value::String(message.to_string()));
     }
+    for request_arg in request_args {
+        let (name, value) = request_arg
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid argument '{}', expected NAME=VALUE", request_arg))?;
+        args.insert(name.to_string(), Value::String(value.to_string()));
+    }
 
     // Send request using library API
     let response = client
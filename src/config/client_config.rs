@@ -1,3 +1,4 @@
+use crate::manifest::{CoercionMode, ResponseValidationMode, VersionRequirement};
 use std::time::Duration;
 
 /// Configuration for JanusClient (exact SwiftJanus parity)
@@ -32,6 +33,18 @@ pub struct JanusClientConfig {
     
     /// Enable Manifest validation (Default: true)
     pub enable_validation: bool,
+    
+    /// Manifest versions the client was built against, e.g. "^1.2" (Default: None, any version)
+    pub manifest_version_requirement: Option<String>,
+    
+    /// Convert string arguments to their Manifest types before validation (Default: Strict)
+    pub coercion_mode: CoercionMode,
+    
+    /// How long a Manifest shared between clients is used before revalidating it (Default: 60s)
+    pub manifest_cache_ttl: Duration,
+    
+    /// Check successful results against the fetched Manifest: off, warn or reject (Default: Off)
+    pub response_validation: ResponseValidationMode,
 }
 
 impl Default for JanusClientConfig {
@@ -47,6 +60,10 @@ impl Default for JanusClientConfig {
             max_request_name_length: 256,
             max_args_data_size: 5_000_000,  // 5MB
             enable_validation: true,
+            manifest_version_requirement: None,
+            coercion_mode: CoercionMode::Strict,
+            manifest_cache_ttl: Duration::from_secs(60),
+            response_validation: ResponseValidationMode::Off,
         }
     }
 }
@@ -70,6 +87,10 @@ impl JanusClientConfig {
             max_request_name_length: 512,
             max_args_data_size: 25_000_000,  // 25MB
             enable_validation: true,
+            manifest_version_requirement: None,
+            coercion_mode: CoercionMode::Strict,
+            manifest_cache_ttl: Duration::from_secs(60),
+            response_validation: ResponseValidationMode::Off,
         }
     }
     
@@ -86,6 +107,10 @@ impl JanusClientConfig {
             max_request_name_length: 128,
             max_args_data_size: 500_000,  // 500KB
             enable_validation: true,
+            manifest_version_requirement: None,
+            coercion_mode: CoercionMode::Strict,
+            manifest_cache_ttl: Duration::from_secs(60),
+            response_validation: ResponseValidationMode::Off,
         }
     }
     
@@ -123,6 +148,10 @@ impl JanusClientConfig {
             return Err("max_args_data_size must be greater than 0".to_string());
         }
         
+        if let Some(requirement) = &self.manifest_version_requirement {
+            VersionRequirement::parse(requirement).map_err(|e| e.to_string())?;
+        }
+        
         Ok(())
     }
 }
\ No newline at end of file
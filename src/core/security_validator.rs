@@ -1,5 +1,6 @@
 use crate::error::{JSONRPCError, JSONRPCErrorCode};
 use crate::config::JanusClientConfig;
+use crate::manifest::StringFormat;
 use std::path::Path;
 use regex::Regex;
 
@@ -198,9 +199,8 @@ impl SecurityValidator {
     
     /// Validate UUID format (matches TypeScript implementation)
     pub fn validate_uuid_format(uuid: &str) -> Result<(), JSONRPCError> {
-        // UUID v4 format: 8-4-4-4-12 hexadecimal digits
-        let uuid_regex = Regex::new(r"^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$")?;
-        if !uuid_regex.is_match(uuid) {
+        // UUID v4 format: 8-4-4-4-12 lowercase hexadecimal digits
+        if !StringFormat::Uuid.matches(uuid) || uuid.bytes().any(|byte| byte.is_ascii_uppercase()) {
             return Err(JSONRPCError::new(
                 JSONRPCErrorCode::SecurityViolation,
                 Some(format!("Invalid UUID format: {}", uuid))
@@ -214,7 +214,7 @@ impl SecurityValidator {
         use chrono::{DateTime, Utc};
         
         // Try parsing as ISO 8601 format
-        if DateTime::parse_from_rfc3339(timestamp).is_ok() {
+        if StringFormat::DateTime.matches(timestamp) {
             return Ok(());
         }
         
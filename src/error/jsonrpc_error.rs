@@ -26,10 +26,11 @@ pub enum JSONRPCErrorCode {
     SecurityViolation = -32009,
     ResourceLimitExceeded = -32010,
 
-    // Janus Protocol-Manifestific Error Codes (-32011 to -32013)
+    // Janus Protocol-Manifestific Error Codes (-32011 to -32014)
     MessageFramingError = -32011,
     ResponseTrackingError = -32012,
     ManifestValidationError = -32013,
+    IncompatibleManifestVersion = -32014,
 }
 
 impl JSONRPCErrorCode {
@@ -55,6 +56,7 @@ impl JSONRPCErrorCode {
             JSONRPCErrorCode::MessageFramingError => "MESSAGE_FRAMING_ERROR",
             JSONRPCErrorCode::ResponseTrackingError => "RESPONSE_TRACKING_ERROR",
             JSONRPCErrorCode::ManifestValidationError => "MANIFEST_VALIDATION_ERROR",
+            JSONRPCErrorCode::IncompatibleManifestVersion => "INCOMPATIBLE_MANIFEST_VERSION",
         }
     }
 
@@ -80,6 +82,7 @@ impl JSONRPCErrorCode {
             JSONRPCErrorCode::MessageFramingError => "Message framing error",
             JSONRPCErrorCode::ResponseTrackingError => "Response tracking error",
             JSONRPCErrorCode::ManifestValidationError => "Manifest validation error",
+            JSONRPCErrorCode::IncompatibleManifestVersion => "Incompatible manifest version",
         }
     }
 
@@ -167,6 +170,9 @@ impl Default for JSONRPCErrorData {
     }
 }
 
+/// Error context key holding the name of a declared application error
+pub const ERROR_NAME_KEY: &str = "errorName";
+
 /// JSON-RPC 2.0 compliant error structure
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct JSONRPCError {
@@ -225,6 +231,34 @@ impl JSONRPCError {
         }
     }
 
+    /// Creates an application error by the name its request declares in `error_codes`.
+    /// The server replaces the placeholder code and message with the declared ones.
+    pub fn declared(name: &str, details: Option<String>) -> Self {
+        let data = JSONRPCErrorData::with_details(details.unwrap_or_default())
+            .with_context(HashMap::from([
+                (ERROR_NAME_KEY.to_string(), serde_json::Value::String(name.to_string())),
+            ]));
+        
+        Self {
+            code: JSONRPCErrorCode::ServerError.code(),
+            message: JSONRPCErrorCode::ServerError.message().to_string(),
+            data: Some(data),
+        }
+    }
+
+    /// Returns the declared error name carried in the error context, if any
+    pub fn error_name(&self) -> Option<&str> {
+        self.data.as_ref()?.context.as_ref()?.get(ERROR_NAME_KEY)?.as_str()
+    }
+
+    /// Records the declared error name in the error context
+    pub fn set_error_name(&mut self, name: &str) {
+        let data = self.data.get_or_insert_with(JSONRPCErrorData::new);
+        data.context
+            .get_or_insert_with(HashMap::new)
+            .insert(ERROR_NAME_KEY.to_string(), serde_json::Value::String(name.to_string()));
+    }
+
     /// Returns the error code as an enum if it's a known code
     pub fn error_code(&self) -> Option<JSONRPCErrorCode> {
         match self.code {
@@ -247,6 +281,7 @@ impl JSONRPCError {
             -32011 => Some(JSONRPCErrorCode::MessageFramingError),
             -32012 => Some(JSONRPCErrorCode::ResponseTrackingError),
             -32013 => Some(JSONRPCErrorCode::ManifestValidationError),
+            -32014 => Some(JSONRPCErrorCode::IncompatibleManifestVersion),
             _ => None,
         }
     }
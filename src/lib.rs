@@ -68,17 +68,22 @@ pub use core::{CoreJanusClient, SecurityValidator};
 // Protocol exports (SOCK_DGRAM API communication layer)
 pub use protocol::{
     JanusRequest, JanusResponse, SocketMessage, MessageType,
-    JanusClient, TimeoutManager
+    JanusClient, TimeoutManager, ManifestCache, CachedManifest
 };
 
 // High-level API exports (simple one-line usage)
-pub use server::{JanusServer, JanusRequestHandler, ServerConfig};
+pub use server::{JanusServer, JanusRequestHandler, RequestPolicy, ServerConfig};
 
 // Manifest exports (API definition layer)
 pub use manifest::{
     Manifest, RequestManifest, ArgumentManifest,
     ValidationManifest, ResponseManifest, ErrorCodeManifest, ModelManifest,
-    ManifestParser, ValidationEngine, ArgumentValidator
+    ManifestParser, ValidationEngine, ArgumentValidator, ArgumentCoercion, CoercionMode,
+    ManifestSchema, ManifestModel, JsonSchemaExporter, JsonSchemaImporter, OpenRpcGenerator,
+    ManifestVersion, VersionBump, VersionRequirement, ManifestCompatibility, CompatibilityReport, ManifestChange, ChangeSeverity,
+    RustCodeGenerator, RustCodegenOptions, ManifestLinter, LintReport, LintIssue, LintSeverity, StringFormat,
+    ValidatorRegistry, CustomValidator, ResponseValidationMode, DeprecationWarning,
+    DeclaredError
 };
 
 // Configuration exports
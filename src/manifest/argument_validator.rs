@@ -1,7 +1,188 @@
-pub struct ArgumentValidator;
+/*!
+ * Argument Validator for Rust Janus Implementation
+ * Validates JanusRequest arguments against Manifest RequestManifest definitions
+ */
 
-impl ArgumentValidator {
-    pub fn new() -> Self {
-        Self
+use crate::manifest::model_registry::{ArgumentManifest, Manifest, RequestManifest};
+use crate::manifest::response_validator::{ValidationError, ValidationResult};
+use crate::manifest::validation_engine::ValidationEngine;
+use crate::manifest::validator_registry::ValidatorRegistry;
+use crate::protocol::message_types::JanusRequest;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// How argument values of the wrong JSON type are treated before validation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CoercionMode {
+    /// Values must already have the declared type
+    #[default]
+    Strict,
+    /// String values are converted to the declared integer, number, boolean, array or object type
+    Lenient,
+}
+
+/// A string argument converted to its declared type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArgumentCoercion {
+    /// Argument name
+    pub field: String,
+
+    /// Declared argument type
+    pub r#type: String,
+
+    /// Value sent by the caller
+    pub from: Value,
+
+    /// Value after coercion
+    pub to: Value,
+}
+
+/// Argument validator that checks request arguments against
+/// the RequestManifest definitions of a Manifest
+pub struct ArgumentValidator<'a> {
+    engine: ValidationEngine<'a>,
+    coercion_mode: CoercionMode,
+}
+
+impl<'a> ArgumentValidator<'a> {
+    /// Create a new argument validator for the given Manifest
+    pub fn new(manifest: &'a Manifest) -> Self {
+        Self {
+            engine: ValidationEngine::new(manifest),
+            coercion_mode: CoercionMode::Strict,
+        }
+    }
+
+    /// Run the named validators the Manifest references from this registry
+    pub fn with_validators(mut self, validators: &'a ValidatorRegistry) -> Self {
+        self.engine = self.engine.with_validators(validators);
+        self
+    }
+
+    /// Set how mistyped string arguments are treated by `coerce_args`
+    pub fn with_coercion(mut self, coercion_mode: CoercionMode) -> Self {
+        self.coercion_mode = coercion_mode;
+        self
+    }
+
+    /// Convert string arguments to their declared types in lenient mode.
+    /// Returns every conversion made, ordered by argument name; strict mode changes nothing.
+    /// Strings that do not parse as the declared type are left for validation to reject.
+    pub fn coerce_args(
+        &self,
+        args: &mut Option<HashMap<String, Value>>,
+        request_manifest: &RequestManifest,
+    ) -> Vec<ArgumentCoercion> {
+        let mut coercions = Vec::new();
+        let args = match (self.coercion_mode, args.as_mut()) {
+            (CoercionMode::Lenient, Some(args)) => args,
+            _ => return coercions,
+        };
+
+        for (arg_name, value) in args.iter_mut() {
+            let arg_manifest = match request_manifest.args.get(arg_name) {
+                Some(arg_manifest) => arg_manifest,
+                None => continue,
+            };
+            let coerced = match value {
+                Value::String(string_value) => Self::coerce_string(string_value, &arg_manifest.r#type),
+                _ => None,
+            };
+            if let Some(coerced) = coerced {
+                coercions.push(ArgumentCoercion {
+                    field: arg_name.clone(),
+                    r#type: arg_manifest.r#type.clone(),
+                    from: std::mem::replace(value, coerced.clone()),
+                    to: coerced,
+                });
+            }
+        }
+
+        coercions.sort_by(|a, b| a.field.cmp(&b.field));
+        coercions
+    }
+
+    /// Parse a string as the given Manifest type
+    fn coerce_string(value: &str, target_type: &str) -> Option<Value> {
+        let trimmed = value.trim();
+        match target_type {
+            "integer" => trimmed.parse::<i64>().map(Value::from).ok()
+                .or_else(|| trimmed.parse::<u64>().map(Value::from).ok()),
+            "number" => trimmed.parse::<i64>().map(Value::from).ok()
+                .or_else(|| trimmed.parse::<f64>().ok().and_then(serde_json::Number::from_f64).map(Value::Number)),
+            "boolean" => match trimmed.to_ascii_lowercase().as_str() {
+                "true" => Some(Value::Bool(true)),
+                "false" => Some(Value::Bool(false)),
+                _ => None,
+            },
+            "array" => serde_json::from_str::<Value>(trimmed).ok().filter(Value::is_array),
+            "object" => serde_json::from_str::<Value>(trimmed).ok().filter(Value::is_object),
+            _ => None,
+        }
+    }
+
+    /// Validate a request by looking up its RequestManifest
+    pub fn validate_request(&self, request: &JanusRequest) -> ValidationResult {
+        match self.engine.manifest().get_request_manifest(&request.request) {
+            Some(request_manifest) => self.validate_args(request.args.as_ref(), request_manifest),
+            None => Self::create_missing_request_error(&request.request),
+        }
+    }
+
+    /// Validate request arguments against a RequestManifest
+    pub fn validate_args(
+        &self,
+        args: Option<&HashMap<String, Value>>,
+        request_manifest: &RequestManifest,
+    ) -> ValidationResult {
+        let start_time = Instant::now();
+        let mut errors = Vec::new();
+
+        // Check arguments by name so violations are reported in a stable order
+        let mut arg_manifests: Vec<(&String, &ArgumentManifest)> = request_manifest.args.iter().collect();
+        arg_manifests.sort_by(|a, b| a.0.cmp(b.0));
+        for (arg_name, arg_manifest) in arg_manifests {
+            match args.and_then(|args| args.get(arg_name)) {
+                None | Some(Value::Null) if arg_manifest.is_required() => {
+                    errors.push(ValidationError {
+                        field: arg_name.clone(),
+                        message: "Required argument is missing or null".to_string(),
+                        expected: format!("non-null {}", arg_manifest.r#type),
+                        actual: Value::Null,
+                        context: None,
+                    });
+                }
+                Some(value) => {
+                    self.engine.validate_value(value, arg_manifest, arg_name, &mut errors);
+                }
+                None => {}
+            }
+        }
+
+        ValidationResult {
+            valid: errors.is_empty(),
+            errors,
+            validation_time: start_time.elapsed().as_secs_f64() * 1000.0,
+            fields_validated: request_manifest.args.len(),
+        }
+    }
+
+    /// Create a validation result for a request missing from the Manifest
+    pub fn create_missing_request_error(request_name: &str) -> ValidationResult {
+        ValidationResult {
+            valid: false,
+            errors: vec![ValidationError {
+                field: "request".to_string(),
+                message: format!("Request '{}' is not defined in manifest", request_name),
+                expected: "request manifest".to_string(),
+                actual: Value::String(request_name.to_string()),
+                context: None,
+            }],
+            validation_time: 0.0,
+            fields_validated: 0,
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,565 @@
+/*!
+ * Rust code generation for Rust Janus Implementation
+ * Generates typed structs, client wrappers and server traits from Manifest definitions
+ */
+
+use crate::error::{JSONRPCError, JSONRPCErrorCode};
+use crate::manifest::manifest_parser::ManifestParser;
+use crate::manifest::model_registry::{ArgumentManifest, Manifest, RequestManifest, ResponseManifest};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Methods of the generated client wrapper that requests may not shadow
+const CLIENT_METHODS: &[&str] = &["new", "with_timeout", "client", "client_mut", "into_client"];
+
+/// Words that need a raw identifier when used as field or method names
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where",
+    "while", "yield",
+];
+
+/// Options controlling generated Rust code
+#[derive(Debug, Clone)]
+pub struct RustCodegenOptions {
+    /// Generate the typed client wrapper
+    pub client: bool,
+    /// Name of the generated client wrapper type
+    pub client_name: String,
+    /// Generate the server trait and its registration function
+    pub server: bool,
+    /// Name of the generated server trait
+    pub server_name: String,
+}
+
+impl Default for RustCodegenOptions {
+    fn default() -> Self {
+        Self {
+            client: true,
+            client_name: "ManifestClient".to_string(),
+            server: false,
+            server_name: "ManifestService".to_string(),
+        }
+    }
+}
+
+/// Generates Rust source from a Manifest.
+/// Models become serde structs and request arguments become `{Request}Args` structs.
+/// The client wrapper gets one async method per request returning the deserialized result,
+/// the server trait one method per request that implementations must provide.
+/// Generated code refers to `serde`, `serde_json` and `rust_janus` by absolute path.
+pub struct RustCodeGenerator;
+
+impl RustCodeGenerator {
+    /// Generate source for a Manifest with the parts selected in `options`
+    pub fn generate(manifest: &Manifest, options: &RustCodegenOptions) -> Result<String, JSONRPCError> {
+        let mut writer = CodeWriter::new(manifest);
+        writer.write_header();
+        writer.write_models()?;
+        let bindings = writer.write_request_types(options.client)?;
+        if options.client {
+            writer.write_client(&options.client_name, &bindings)?;
+        }
+        if options.server {
+            writer.write_server(&options.server_name, &bindings)?;
+        }
+        Ok(writer.out)
+    }
+
+    /// Load and validate a manifest file and write the generated source to `output_path`.
+    /// Blocking, so it can be called from `build.rs`; an unchanged output file is not rewritten.
+    pub fn write(
+        manifest_path: impl AsRef<Path>,
+        output_path: impl AsRef<Path>,
+        options: &RustCodegenOptions,
+    ) -> Result<(), JSONRPCError> {
+        let manifest = Self::load_manifest(manifest_path.as_ref())?;
+        Self::write_source(output_path.as_ref(), &Self::generate(&manifest, options)?)
+    }
+
+    /// Load and validate a manifest outside of an async runtime
+    fn load_manifest(manifest_path: &Path) -> Result<Manifest, JSONRPCError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| JSONRPCError::new(JSONRPCErrorCode::InternalError, Some(format!("Failed to start runtime: {}", e))))?;
+        runtime.block_on(ManifestParser::load_and_validate(&manifest_path.to_string_lossy()))
+    }
+
+    fn write_source(output_path: &Path, source: &str) -> Result<(), JSONRPCError> {
+        if std::fs::read_to_string(output_path).is_ok_and(|existing| existing == source) {
+            return Ok(());
+        }
+        std::fs::write(output_path, source).map_err(|e| {
+            JSONRPCError::new(
+                JSONRPCErrorCode::ResourceNotFound,
+                Some(format!("Failed to write file {}: {}", output_path.display(), e)),
+            )
+        })
+    }
+}
+
+/// A manifest request together with the Rust names generated for it
+struct RequestBinding<'a> {
+    name: &'a str,
+    manifest: &'a RequestManifest,
+    method: String,
+    args_type: Option<String>,
+    result_type: String,
+}
+
+/// Accumulates generated source, tracking type names to report collisions
+struct CodeWriter<'a> {
+    manifest: &'a Manifest,
+    out: String,
+    type_origins: HashMap<String, String>,
+}
+
+impl<'a> CodeWriter<'a> {
+    fn new(manifest: &'a Manifest) -> Self {
+        Self {
+            manifest,
+            out: String::new(),
+            type_origins: HashMap::new(),
+        }
+    }
+
+    fn line(&mut self, text: &str) {
+        self.out.push_str(text);
+        self.out.push('\n');
+    }
+
+    fn write_doc(&mut self, indent: &str, text: &str) {
+        for doc_line in text.lines() {
+            let doc_line = doc_line.trim_end();
+            if doc_line.is_empty() {
+                self.line(&format!("{}///", indent));
+            } else {
+                self.line(&format!("{}/// {}", indent, doc_line));
+            }
+        }
+    }
+
+    fn write_header(&mut self) {
+        self.line(&format!(
+            "// Generated by rust_janus from manifest version {}. Do not edit by hand.",
+            self.manifest.version
+        ));
+    }
+
+    /// Claim a generated type name, failing when another definition already maps to it
+    fn reserve_type(&mut self, type_name: &str, origin: String) -> Result<(), JSONRPCError> {
+        if let Some(existing) = self.type_origins.get(type_name) {
+            return Err(generation_error(format!(
+                "Generated type '{}' for {} conflicts with {}",
+                type_name, origin, existing
+            )));
+        }
+        self.type_origins.insert(type_name.to_string(), origin);
+        Ok(())
+    }
+
+    fn write_models(&mut self) -> Result<(), JSONRPCError> {
+        let models = match &self.manifest.models {
+            Some(models) => models,
+            None => return Ok(()),
+        };
+
+        let mut model_names: Vec<&String> = models.keys().collect();
+        model_names.sort();
+        for model_name in model_names {
+            let model = &models[model_name];
+            let type_name = type_ident(model_name)?;
+            self.reserve_type(&type_name, format!("model '{}'", model_name))?;
+
+            self.line("");
+            self.write_doc("", &format!("Model `{}`", model_name));
+            self.write_struct(&type_name, &model.properties, |name, property| {
+                property.is_required() || model.is_property_required(name)
+            }, Some(model_name))?;
+        }
+        Ok(())
+    }
+
+    /// Write argument and response structs, returning the generated names of every request
+    fn write_request_types(&mut self, client: bool) -> Result<Vec<RequestBinding<'a>>, JSONRPCError> {
+        let manifest = self.manifest;
+        let mut request_names = manifest.request_names();
+        request_names.sort();
+
+        let mut bindings = Vec::new();
+        let mut methods: HashMap<String, &str> = HashMap::new();
+        for request_name in request_names {
+            let request_manifest = &manifest.requests.as_ref().unwrap()[request_name];
+            let base_name = type_ident(request_name)?;
+
+            let method = field_ident(request_name);
+            if client && CLIENT_METHODS.contains(&method.as_str()) {
+                return Err(generation_error(format!(
+                    "Request '{}' would shadow the generated '{}' method",
+                    request_name, method
+                )));
+            }
+            if let Some(existing) = methods.insert(method.clone(), request_name) {
+                return Err(generation_error(format!(
+                    "Requests '{}' and '{}' both generate method '{}'",
+                    existing, request_name, method
+                )));
+            }
+
+            let args_type = if request_manifest.args.is_empty() {
+                None
+            } else {
+                let args_type = format!("{}Args", base_name);
+                self.reserve_type(&args_type, format!("arguments of request '{}'", request_name))?;
+                self.line("");
+                self.write_doc("", &format!("Arguments of the `{}` request", request_name));
+                self.write_struct(&args_type, &request_manifest.args, |_, arg| arg.is_required(), None)?;
+                Some(args_type)
+            };
+
+            let result_type = self.write_result_type(request_name, &base_name, &request_manifest.response)?;
+
+            bindings.push(RequestBinding {
+                name: request_name,
+                manifest: request_manifest,
+                method,
+                args_type,
+                result_type,
+            });
+        }
+        Ok(bindings)
+    }
+
+    /// Resolve the Rust type of a response, writing a struct for inline response properties
+    fn write_result_type(
+        &mut self,
+        request_name: &str,
+        base_name: &str,
+        response: &ResponseManifest,
+    ) -> Result<String, JSONRPCError> {
+        if let Some(model_ref) = &response.model_ref {
+            return type_ident(model_ref);
+        }
+
+        match (response.r#type.as_str(), &response.items, &response.properties) {
+            ("array", Some(items), _) => Ok(format!("Vec<{}>", self.value_type(items)?)),
+            (response_type @ ("object" | "array"), None, Some(properties)) if !properties.is_empty() => {
+                let is_array = response_type == "array";
+                let type_name = if is_array {
+                    format!("{}ResponseItem", base_name)
+                } else {
+                    format!("{}Response", base_name)
+                };
+                self.reserve_type(&type_name, format!("response of request '{}'", request_name))?;
+                self.line("");
+                self.write_doc("", &format!("Result of the `{}` request", request_name));
+                self.write_struct(&type_name, properties, |_, property| property.is_required(), None)?;
+                Ok(if is_array { format!("Vec<{}>", type_name) } else { type_name })
+            }
+            (response_type, _, _) => Ok(primitive_type(response_type).to_string()),
+        }
+    }
+
+    fn write_struct(
+        &mut self,
+        type_name: &str,
+        properties: &HashMap<String, ArgumentManifest>,
+        required: impl Fn(&str, &ArgumentManifest) -> bool,
+        owner_model: Option<&str>,
+    ) -> Result<(), JSONRPCError> {
+        let mut property_names: Vec<&String> = properties.keys().collect();
+        property_names.sort();
+
+        self.line("#[derive(Debug, Clone, PartialEq, ::serde::Serialize, ::serde::Deserialize)]");
+        self.line(&format!("pub struct {} {{", type_name));
+
+        let mut fields: HashMap<String, &str> = HashMap::new();
+        for property_name in property_names {
+            let property = &properties[property_name];
+            let field = field_ident(property_name);
+            if let Some(existing) = fields.insert(field.clone(), property_name) {
+                return Err(generation_error(format!(
+                    "Properties '{}' and '{}' of {} both generate field '{}'",
+                    existing, property_name, type_name, field
+                )));
+            }
+
+            let mut field_type = self.value_type(property)?;
+            if let (Some(owner), Some(model_ref)) = (owner_model, &property.model_ref) {
+                // Directly nested recursive models need indirection to have a finite size
+                if model_ref == owner || self.reaches_model(model_ref, owner, &mut HashSet::new()) {
+                    field_type = format!("Box<{}>", field_type);
+                }
+            }
+
+            if let Some(description) = &property.description {
+                self.write_doc("    ", description);
+            }
+            if field.trim_start_matches("r#") != property_name.as_str() {
+                self.line(&format!("    #[serde(rename = {:?})]", property_name));
+            }
+            if required(property_name, property) {
+                self.line(&format!("    pub {}: {},", field, field_type));
+            } else {
+                self.line("    #[serde(default, skip_serializing_if = \"Option::is_none\")]");
+                self.line(&format!("    pub {}: Option<{}>,", field, field_type));
+            }
+        }
+
+        self.line("}");
+        Ok(())
+    }
+
+    /// Rust type of a value described by an argument manifest
+    fn value_type(&self, argument: &ArgumentManifest) -> Result<String, JSONRPCError> {
+        if let Some(model_ref) = &argument.model_ref {
+            return type_ident(model_ref);
+        }
+
+        Ok(match (argument.r#type.as_str(), &argument.items, &argument.additional_properties) {
+            ("array", Some(items), _) => format!("Vec<{}>", self.value_type(items)?),
+            ("object", _, Some(values)) => {
+                format!("::std::collections::HashMap<String, {}>", self.value_type(values)?)
+            }
+            (argument_type, _, _) => primitive_type(argument_type).to_string(),
+        })
+    }
+
+    /// Whether `from` contains `target` through directly nested model properties
+    fn reaches_model(&self, from: &str, target: &str, visited: &mut HashSet<String>) -> bool {
+        if !visited.insert(from.to_string()) {
+            return false;
+        }
+        let model = match self.manifest.get_model(from) {
+            Some(model) => model,
+            None => return false,
+        };
+        model.properties.values().any(|property| match &property.model_ref {
+            Some(model_ref) => model_ref == target || self.reaches_model(model_ref, target, visited),
+            None => false,
+        })
+    }
+
+    fn write_client(&mut self, client_name: &str, bindings: &[RequestBinding<'a>]) -> Result<(), JSONRPCError> {
+        let client_type = type_ident(client_name)?;
+        self.reserve_type(&client_type, "the client wrapper".to_string())?;
+
+        self.line("");
+        self.write_doc("", &format!(
+            "Typed client for the requests of manifest version {}",
+            self.manifest.version
+        ));
+        self.line(&format!("pub struct {} {{", client_type));
+        self.line("    client: ::rust_janus::JanusClient,");
+        self.line("    timeout: Option<::std::time::Duration>,");
+        self.line("}");
+        self.line("");
+        self.line(&format!("impl {} {{", client_type));
+        self.line("    /// Wrap a JanusClient connected to a server of this manifest");
+        self.line("    pub fn new(client: ::rust_janus::JanusClient) -> Self {");
+        self.line("        Self { client, timeout: None }");
+        self.line("    }");
+        self.line("");
+        self.line("    /// Set the timeout sent with every request");
+        self.line("    pub fn with_timeout(mut self, timeout: ::std::time::Duration) -> Self {");
+        self.line("        self.timeout = Some(timeout);");
+        self.line("        self");
+        self.line("    }");
+        self.line("");
+        self.line("    /// Get the underlying JanusClient");
+        self.line("    pub fn client(&self) -> &::rust_janus::JanusClient {");
+        self.line("        &self.client");
+        self.line("    }");
+        self.line("");
+        self.line("    /// Get the underlying JanusClient mutably");
+        self.line("    pub fn client_mut(&mut self) -> &mut ::rust_janus::JanusClient {");
+        self.line("        &mut self.client");
+        self.line("    }");
+        self.line("");
+        self.line("    /// Unwrap the underlying JanusClient");
+        self.line("    pub fn into_client(self) -> ::rust_janus::JanusClient {");
+        self.line("        self.client");
+        self.line("    }");
+
+        for binding in bindings {
+            self.line("");
+            self.write_doc("    ", &binding.manifest.description);
+            let (params, args) = match &binding.args_type {
+                Some(args_type) => (format!(", args: {}", args_type), "&args"),
+                None => (String::new(), "&()"),
+            };
+            self.line(&format!(
+                "    pub async fn {}(&mut self{}) -> ::std::result::Result<{}, ::rust_janus::JSONRPCError> {{",
+                binding.method, params, binding.result_type
+            ));
+            self.line(&format!(
+                "        self.client.send_typed_request({:?}, {}, self.timeout).await",
+                binding.name, args
+            ));
+            self.line("    }");
+        }
+
+        self.line("}");
+        Ok(())
+    }
+
+    fn write_server(&mut self, server_name: &str, bindings: &[RequestBinding<'a>]) -> Result<(), JSONRPCError> {
+        let trait_name = type_ident(server_name)?;
+        self.reserve_type(&trait_name, "the server trait".to_string())?;
+        let register_fn = format!("register_{}", field_ident(&trait_name).trim_start_matches("r#"));
+
+        // Going through a Value sorts object keys, keeping the output deterministic
+        let manifest_json = serde_json::to_value(self.manifest)
+            .and_then(|value| serde_json::to_string_pretty(&value))
+            .map_err(|e| JSONRPCError::new(JSONRPCErrorCode::InternalError, Some(format!("Manifest serialization error: {}", e))))?;
+        let hashes = "#".repeat(longest_hash_run(&manifest_json) + 1);
+        self.line("");
+        self.line("/// Manifest the generated code was built from");
+        self.line(&format!("pub const MANIFEST_JSON: &str = r{}\"{}\"{};", hashes, manifest_json, hashes));
+        self.line("");
+        self.line("/// Parse the embedded manifest");
+        self.line("pub fn manifest() -> ::rust_janus::Manifest {");
+        self.line("    ::rust_janus::ManifestParser::from_json(MANIFEST_JSON).expect(\"embedded manifest is valid\")");
+        self.line("}");
+
+        self.line("");
+        self.write_doc("", &format!(
+            "Handlers for the requests of manifest version {}.\nRegister an implementation with [`{}`].",
+            self.manifest.version, register_fn
+        ));
+        self.line(&format!("pub trait {}: Send + Sync + 'static {{", trait_name));
+        for (index, binding) in bindings.iter().enumerate() {
+            if index > 0 {
+                self.line("");
+            }
+            self.write_doc("    ", &binding.manifest.description);
+            let params = match &binding.args_type {
+                Some(args_type) => format!(", args: {}", args_type),
+                None => String::new(),
+            };
+            self.line(&format!(
+                "    fn {}(&self{}) -> impl ::std::future::Future<Output = ::std::result::Result<{}, ::rust_janus::JSONRPCError>> + Send;",
+                binding.method, params, binding.result_type
+            ));
+        }
+        self.line("}");
+
+        self.line("");
+        self.line("/// Attach the embedded manifest and register a handler for every request of `service`");
+        self.line(&format!(
+            "pub async fn {}<S: {}>(server: &mut ::rust_janus::JanusServer, service: S) {{",
+            register_fn, trait_name
+        ));
+        self.line("    let service = ::std::sync::Arc::new(service);");
+        self.line("    server.set_manifest(manifest()).await;");
+        for binding in bindings {
+            let request_param = if binding.args_type.is_some() { "request" } else { "_request" };
+            self.line("    {");
+            self.line("        let service = ::std::sync::Arc::clone(&service);");
+            self.line(&format!(
+                "        server.register_async_handler({:?}, move |{}| {{",
+                binding.name, request_param
+            ));
+            self.line("            let service = ::std::sync::Arc::clone(&service);");
+            self.line("            async move {");
+            match &binding.args_type {
+                Some(args_type) => {
+                    self.line(&format!(
+                        "                let args = ::rust_janus::JanusServer::decode_typed_args::<{}>(&request)?;",
+                        args_type
+                    ));
+                    self.line(&format!(
+                        "                ::rust_janus::JanusServer::encode_typed_result(service.{}(args).await?)",
+                        binding.method
+                    ));
+                }
+                None => self.line(&format!(
+                    "                ::rust_janus::JanusServer::encode_typed_result(service.{}().await?)",
+                    binding.method
+                )),
+            }
+            self.line("            }");
+            self.line("        }).await;");
+            self.line("    }");
+        }
+        self.line("}");
+        Ok(())
+    }
+}
+
+/// Length of the longest run of `#` following a quote, to pick a safe raw string delimiter
+fn longest_hash_run(text: &str) -> usize {
+    text.split('"')
+        .skip(1)
+        .map(|rest| rest.chars().take_while(|c| *c == '#').count())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Rust type of a manifest primitive type, free-form JSON for anything else
+fn primitive_type(manifest_type: &str) -> &'static str {
+    match manifest_type {
+        "string" => "String",
+        "integer" => "i64",
+        "number" => "f64",
+        "boolean" => "bool",
+        "array" => "Vec<::serde_json::Value>",
+        _ => "::serde_json::Value",
+    }
+}
+
+fn generation_error(message: String) -> JSONRPCError {
+    JSONRPCError::new(JSONRPCErrorCode::ManifestValidationError, Some(message))
+}
+
+/// Convert a manifest name to an UpperCamelCase type name
+fn type_ident(name: &str) -> Result<String, JSONRPCError> {
+    let type_name: String = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            let mut chars = segment.chars();
+            let first = chars.next().map(|c| c.to_ascii_uppercase()).into_iter();
+            first.chain(chars).collect::<String>()
+        })
+        .collect();
+
+    match type_name.chars().next() {
+        Some(first) if first.is_ascii_alphabetic() => Ok(type_name),
+        _ => Err(generation_error(format!("Cannot derive a Rust type name from '{}'", name))),
+    }
+}
+
+/// Convert a manifest name to a snake_case field or method name
+fn field_ident(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut ident = String::new();
+    for (index, &c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !ident.ends_with('_') {
+                ident.push('_');
+            }
+            continue;
+        }
+        if c.is_ascii_uppercase() && index > 0 && !ident.ends_with('_') {
+            let previous = chars[index - 1];
+            let next_is_lower = chars.get(index + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if previous.is_ascii_lowercase() || previous.is_ascii_digit() || (previous.is_ascii_uppercase() && next_is_lower) {
+                ident.push('_');
+            }
+        }
+        ident.push(c.to_ascii_lowercase());
+    }
+
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    match ident.as_str() {
+        "self" | "super" | "crate" | "_" => format!("{}_", ident),
+        _ if KEYWORDS.contains(&ident.as_str()) => format!("r#{}", ident),
+        _ => ident,
+    }
+}
@@ -0,0 +1,606 @@
+/*!
+ * Manifest Compatibility for Rust Janus Implementation
+ * Compares two Manifests, classifies changes and checks the version bump against them
+ */
+
+use crate::error::{JSONRPCError, JSONRPCErrorCode};
+use crate::manifest::manifest_version::{ManifestVersion, VersionBump};
+use crate::manifest::model_registry::{
+    ArgumentManifest, Manifest, ModelManifest, RequestManifest, ResponseManifest, ValidationManifest,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+/// Impact of a change on existing callers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeSeverity {
+    /// Documentation-only change
+    Patch,
+    /// Additive or relaxing change that existing callers keep working with
+    NonBreaking,
+    /// Change that can break existing callers
+    Breaking,
+}
+
+impl ChangeSeverity {
+    /// Minimum version bump required for a change of this severity
+    pub fn required_bump(&self) -> VersionBump {
+        match self {
+            ChangeSeverity::Patch => VersionBump::Patch,
+            ChangeSeverity::NonBreaking => VersionBump::Minor,
+            ChangeSeverity::Breaking => VersionBump::Major,
+        }
+    }
+}
+
+/// A single difference between two Manifests
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestChange {
+    /// Impact of the change
+    pub severity: ChangeSeverity,
+
+    /// Dotted path of the changed element, e.g. `requests.get_user.args.user_id`
+    pub path: String,
+
+    /// Human-readable description of the change
+    pub description: String,
+}
+
+impl fmt::Display for ManifestChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.description)
+    }
+}
+
+/// Result of comparing two Manifests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompatibilityReport {
+    /// Version of the previous Manifest
+    pub old_version: String,
+
+    /// Version of the new Manifest
+    pub new_version: String,
+
+    /// All detected changes, ordered by path
+    pub changes: Vec<ManifestChange>,
+}
+
+impl CompatibilityReport {
+    /// Check whether existing callers keep working with the new Manifest
+    pub fn is_compatible(&self) -> bool {
+        !self.changes.iter().any(|change| change.severity == ChangeSeverity::Breaking)
+    }
+
+    /// Get the breaking changes
+    pub fn breaking_changes(&self) -> Vec<&ManifestChange> {
+        self.changes
+            .iter()
+            .filter(|change| change.severity == ChangeSeverity::Breaking)
+            .collect()
+    }
+
+    /// Minimum version bump the detected changes require
+    pub fn required_bump(&self) -> VersionBump {
+        self.changes
+            .iter()
+            .map(|change| change.severity.required_bump())
+            .max()
+            .unwrap_or(VersionBump::None)
+    }
+
+    /// Actual version bump between the two Manifests
+    pub fn actual_bump(&self) -> Result<VersionBump, JSONRPCError> {
+        let old_version = ManifestVersion::parse(&self.old_version)?;
+        let new_version = ManifestVersion::parse(&self.new_version)?;
+
+        new_version.bump_from(&old_version).ok_or_else(|| {
+            JSONRPCError::new(
+                JSONRPCErrorCode::ManifestValidationError,
+                Some(format!("Manifest version went backwards: {} -> {}", self.old_version, self.new_version)),
+            )
+        })
+    }
+
+    /// Check that the version bump matches the severity of the changes.
+    /// For `0.x` versions a minor bump is enough for breaking changes.
+    pub fn check_version_bump(&self) -> Result<(), JSONRPCError> {
+        let actual = self.actual_bump()?;
+        let mut required = self.required_bump();
+        if required == VersionBump::Major && ManifestVersion::parse(&self.old_version)?.major == 0 {
+            required = VersionBump::Minor;
+        }
+
+        if actual >= required {
+            return Ok(());
+        }
+
+        let relevant: Vec<String> = self.changes
+            .iter()
+            .filter(|change| change.severity.required_bump() > actual)
+            .map(|change| change.to_string())
+            .collect();
+
+        Err(JSONRPCError::with_context(
+            JSONRPCErrorCode::ManifestValidationError,
+            Some(format!(
+                "Manifest version {} -> {} is a {} bump but the changes require a {} bump: {}",
+                self.old_version,
+                self.new_version,
+                actual,
+                required,
+                relevant.join("; ")
+            )),
+            HashMap::from([
+                ("requiredBump".to_string(), Value::String(required.to_string())),
+                ("actualBump".to_string(), Value::String(actual.to_string())),
+                ("changes".to_string(), serde_json::to_value(&self.changes).unwrap_or(Value::Null)),
+            ]),
+        ))
+    }
+}
+
+/// Data flow direction of a definition, deciding whether narrowing or widening breaks callers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    /// Values sent by callers (request arguments)
+    Input,
+    /// Values returned to callers (responses)
+    Output,
+    /// Values used in both directions
+    Both,
+}
+
+/// Effect of a change on the set of accepted values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Effect {
+    /// Fewer values are accepted
+    Narrowing,
+    /// More values are accepted
+    Widening,
+    /// Accepted values changed in a way that is neither
+    Incompatible,
+}
+
+impl Direction {
+    fn severity(self, effect: Effect) -> ChangeSeverity {
+        match (effect, self) {
+            (Effect::Incompatible, _) => ChangeSeverity::Breaking,
+            (Effect::Narrowing, Direction::Output) => ChangeSeverity::NonBreaking,
+            (Effect::Widening, Direction::Input) => ChangeSeverity::NonBreaking,
+            _ => ChangeSeverity::Breaking,
+        }
+    }
+}
+
+/// Compares Manifests and classifies the differences
+pub struct ManifestCompatibility<'a> {
+    old: &'a Manifest,
+    new: &'a Manifest,
+    changes: Vec<ManifestChange>,
+}
+
+impl<'a> ManifestCompatibility<'a> {
+    /// Compare two Manifests
+    pub fn compare(old: &'a Manifest, new: &'a Manifest) -> CompatibilityReport {
+        let mut compatibility = Self { old, new, changes: Vec::new() };
+        compatibility.compare_requests();
+        compatibility.compare_models();
+
+        let mut changes = compatibility.changes;
+        changes.sort_by(|a, b| a.path.cmp(&b.path).then(a.description.cmp(&b.description)));
+
+        CompatibilityReport {
+            old_version: old.version.clone(),
+            new_version: new.version.clone(),
+            changes,
+        }
+    }
+
+    fn record(&mut self, severity: ChangeSeverity, path: &str, description: String) {
+        self.changes.push(ManifestChange {
+            severity,
+            path: path.to_string(),
+            description,
+        });
+    }
+
+    fn compare_requests(&mut self) {
+        let empty = HashMap::new();
+        let old_requests = self.old.requests.as_ref().unwrap_or(&empty);
+        let new_requests = self.new.requests.as_ref().unwrap_or(&empty);
+
+        for name in Self::all_keys(old_requests, new_requests) {
+            let path = format!("requests.{}", name);
+            match (old_requests.get(&name), new_requests.get(&name)) {
+                (Some(_), None) => self.record(ChangeSeverity::Breaking, &path, "request removed".to_string()),
+                (None, Some(_)) => self.record(ChangeSeverity::NonBreaking, &path, "request added".to_string()),
+                (Some(old_request), Some(new_request)) => self.compare_request(&path, old_request, new_request),
+                (None, None) => {}
+            }
+        }
+    }
+
+    fn compare_request(&mut self, path: &str, old: &RequestManifest, new: &RequestManifest) {
+        if old.description != new.description {
+            self.record(ChangeSeverity::Patch, path, "description changed".to_string());
+        }
+        self.compare_deprecation(
+            path,
+            (old.is_deprecated(), &old.replacement, &old.removal_version),
+            (new.is_deprecated(), &new.replacement, &new.removal_version),
+        );
+
+        self.compare_properties(
+            &format!("{}.args", path),
+            &old.args,
+            &new.args,
+            &|_, arg| arg.is_required(),
+            &|_, arg| arg.is_required(),
+            Direction::Input,
+        );
+        self.compare_response(&format!("{}.response", path), &old.response, &new.response);
+        self.compare_error_codes(&format!("{}.error_codes", path), old, new);
+    }
+
+    /// Deprecating announces a removal without breaking callers, so it needs a minor bump.
+    /// Changing only the replacement or removal version is documentation.
+    fn compare_deprecation(
+        &mut self,
+        path: &str,
+        (old_deprecated, old_replacement, old_removal): (bool, &Option<String>, &Option<String>),
+        (new_deprecated, new_replacement, new_removal): (bool, &Option<String>, &Option<String>),
+    ) {
+        match (old_deprecated, new_deprecated) {
+            (false, true) => self.record(ChangeSeverity::NonBreaking, path, "deprecated".to_string()),
+            (true, false) => self.record(ChangeSeverity::NonBreaking, path, "no longer deprecated".to_string()),
+            _ if old_replacement != new_replacement || old_removal != new_removal => {
+                self.record(ChangeSeverity::Patch, path, "deprecation details changed".to_string());
+            }
+            _ => {}
+        }
+    }
+
+    fn compare_response(&mut self, path: &str, old: &ResponseManifest, new: &ResponseManifest) {
+        if old.r#type != new.r#type {
+            let effect = Self::type_effect(&old.r#type, &new.r#type);
+            self.record(
+                Direction::Output.severity(effect),
+                path,
+                format!("response type changed from '{}' to '{}'", old.r#type, new.r#type),
+            );
+        }
+        if old.model_ref != new.model_ref {
+            self.record(
+                ChangeSeverity::Breaking,
+                path,
+                format!("response model changed from {:?} to {:?}", old.model_ref, new.model_ref),
+            );
+        }
+        self.compare_nested(&format!("{}.items", path), old.items.as_deref(), new.items.as_deref(), Direction::Output);
+
+        let empty = HashMap::new();
+        self.compare_properties(
+            &format!("{}.properties", path),
+            old.properties.as_ref().unwrap_or(&empty),
+            new.properties.as_ref().unwrap_or(&empty),
+            &|_, property| property.is_required(),
+            &|_, property| property.is_required(),
+            Direction::Output,
+        );
+    }
+
+    fn compare_error_codes(&mut self, path: &str, old: &RequestManifest, new: &RequestManifest) {
+        let empty = HashMap::new();
+        let old_codes = old.error_codes.as_ref().unwrap_or(&empty);
+        let new_codes = new.error_codes.as_ref().unwrap_or(&empty);
+
+        for name in Self::all_keys(old_codes, new_codes) {
+            let code_path = format!("{}.{}", path, name);
+            match (old_codes.get(&name), new_codes.get(&name)) {
+                (Some(_), None) => self.record(ChangeSeverity::NonBreaking, &code_path, "error code removed".to_string()),
+                (None, Some(_)) => self.record(ChangeSeverity::NonBreaking, &code_path, "error code added".to_string()),
+                (Some(old_code), Some(new_code)) => {
+                    if old_code.code != new_code.code {
+                        self.record(
+                            ChangeSeverity::Breaking,
+                            &code_path,
+                            format!("error code changed from {} to {}", old_code.code, new_code.code),
+                        );
+                    } else if old_code.message != new_code.message || old_code.description != new_code.description {
+                        self.record(ChangeSeverity::Patch, &code_path, "error message changed".to_string());
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
+    fn compare_models(&mut self) {
+        let empty = HashMap::new();
+        let old_models = self.old.models.as_ref().unwrap_or(&empty);
+        let new_models = self.new.models.as_ref().unwrap_or(&empty);
+        let directions = self.model_directions();
+
+        for name in Self::all_keys(old_models, new_models) {
+            let path = format!("models.{}", name);
+            match (old_models.get(&name), new_models.get(&name)) {
+                (Some(_), None) => self.record(ChangeSeverity::Breaking, &path, "model removed".to_string()),
+                (None, Some(_)) => self.record(ChangeSeverity::NonBreaking, &path, "model added".to_string()),
+                (Some(old_model), Some(new_model)) => {
+                    let direction = directions.get(&name).copied().unwrap_or(Direction::Both);
+                    self.compare_model(&path, old_model, new_model, direction);
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
+    fn compare_model(&mut self, path: &str, old: &ModelManifest, new: &ModelManifest, direction: Direction) {
+        if old.r#type != new.r#type {
+            self.record(
+                ChangeSeverity::Breaking,
+                path,
+                format!("model type changed from '{}' to '{}'", old.r#type, new.r#type),
+            );
+        }
+
+        self.compare_properties(
+            &format!("{}.properties", path),
+            &old.properties,
+            &new.properties,
+            &|name, property| property.is_required() || old.is_property_required(name),
+            &|name, property| property.is_required() || new.is_property_required(name),
+            direction,
+        );
+    }
+
+    fn compare_properties(
+        &mut self,
+        path: &str,
+        old: &HashMap<String, ArgumentManifest>,
+        new: &HashMap<String, ArgumentManifest>,
+        old_required: &dyn Fn(&str, &ArgumentManifest) -> bool,
+        new_required: &dyn Fn(&str, &ArgumentManifest) -> bool,
+        direction: Direction,
+    ) {
+        for name in Self::all_keys(old, new) {
+            let property_path = format!("{}.{}", path, name);
+            match (old.get(&name), new.get(&name)) {
+                (Some(_), None) => self.record(ChangeSeverity::Breaking, &property_path, "removed".to_string()),
+                (None, Some(property)) => {
+                    if new_required(&name, property) {
+                        let severity = direction.severity(Effect::Narrowing);
+                        self.record(severity, &property_path, "required property added".to_string());
+                    } else {
+                        self.record(ChangeSeverity::NonBreaking, &property_path, "optional property added".to_string());
+                    }
+                }
+                (Some(old_property), Some(new_property)) => {
+                    let was_required = old_required(&name, old_property);
+                    let is_required = new_required(&name, new_property);
+                    if was_required != is_required {
+                        let (effect, description) = if is_required {
+                            (Effect::Narrowing, "changed from optional to required")
+                        } else {
+                            (Effect::Widening, "changed from required to optional")
+                        };
+                        self.record(direction.severity(effect), &property_path, description.to_string());
+                    }
+                    self.compare_argument(&property_path, old_property, new_property, direction);
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
+    fn compare_argument(&mut self, path: &str, old: &ArgumentManifest, new: &ArgumentManifest, direction: Direction) {
+        if old.r#type != new.r#type {
+            let effect = Self::type_effect(&old.r#type, &new.r#type);
+            self.record(
+                direction.severity(effect),
+                path,
+                format!("type changed from '{}' to '{}'", old.r#type, new.r#type),
+            );
+        }
+        if old.model_ref != new.model_ref {
+            self.record(
+                ChangeSeverity::Breaking,
+                path,
+                format!("model reference changed from {:?} to {:?}", old.model_ref, new.model_ref),
+            );
+        }
+        if old.default_value != new.default_value {
+            self.record(ChangeSeverity::NonBreaking, path, "default value changed".to_string());
+        }
+        if old.description != new.description {
+            self.record(ChangeSeverity::Patch, path, "description changed".to_string());
+        }
+        self.compare_deprecation(
+            path,
+            (old.is_deprecated(), &old.replacement, &old.removal_version),
+            (new.is_deprecated(), &new.replacement, &new.removal_version),
+        );
+
+        let unconstrained = ValidationManifest::new();
+        let old_validation = old.validation.as_ref().unwrap_or(&unconstrained);
+        let new_validation = new.validation.as_ref().unwrap_or(&unconstrained);
+        self.compare_validation(path, old_validation, new_validation, direction);
+
+        self.compare_nested(&format!("{}.items", path), old.items.as_deref(), new.items.as_deref(), direction);
+        self.compare_nested(
+            &format!("{}.additionalProperties", path),
+            old.additional_properties.as_deref(),
+            new.additional_properties.as_deref(),
+            direction,
+        );
+    }
+
+    /// Compare array item or map value definitions, where a missing definition accepts anything
+    fn compare_nested(
+        &mut self,
+        path: &str,
+        old: Option<&ArgumentManifest>,
+        new: Option<&ArgumentManifest>,
+        direction: Direction,
+    ) {
+        match (old, new) {
+            (Some(old), Some(new)) => self.compare_argument(path, old, new, direction),
+            (None, Some(_)) => self.record(direction.severity(Effect::Narrowing), path, "definition added".to_string()),
+            (Some(_), None) => self.record(direction.severity(Effect::Widening), path, "definition removed".to_string()),
+            (None, None) => {}
+        }
+    }
+
+    fn compare_validation(&mut self, path: &str, old: &ValidationManifest, new: &ValidationManifest, direction: Direction) {
+        let mut effects: Vec<(Effect, String)> = Vec::new();
+
+        Self::lower_bound_effect(&mut effects, "minimum length", old.min_length.map(|v| v as f64), new.min_length.map(|v| v as f64));
+        Self::upper_bound_effect(&mut effects, "maximum length", old.max_length.map(|v| v as f64), new.max_length.map(|v| v as f64));
+        Self::lower_bound_effect(&mut effects, "minimum", old.minimum, new.minimum);
+        Self::upper_bound_effect(&mut effects, "maximum", old.maximum, new.maximum);
+
+        match (&old.pattern, &new.pattern) {
+            (None, Some(pattern)) => effects.push((Effect::Narrowing, format!("pattern '{}' added", pattern))),
+            (Some(pattern), None) => effects.push((Effect::Widening, format!("pattern '{}' removed", pattern))),
+            (Some(old_pattern), Some(new_pattern)) if old_pattern != new_pattern => effects.push((
+                Effect::Incompatible,
+                format!("pattern changed from '{}' to '{}'", old_pattern, new_pattern),
+            )),
+            _ => {}
+        }
+
+        match (&old.format, &new.format) {
+            (None, Some(format)) => effects.push((Effect::Narrowing, format!("format '{}' added", format))),
+            (Some(format), None) => effects.push((Effect::Widening, format!("format '{}' removed", format))),
+            (Some(old_format), Some(new_format)) if old_format != new_format => effects.push((
+                Effect::Incompatible,
+                format!("format changed from '{}' to '{}'", old_format, new_format),
+            )),
+            _ => {}
+        }
+
+        let old_validators = old.validators.as_deref().unwrap_or_default();
+        let new_validators = new.validators.as_deref().unwrap_or_default();
+        for name in new_validators.iter().filter(|name| !old_validators.contains(name)) {
+            effects.push((Effect::Narrowing, format!("validator '{}' added", name)));
+        }
+        for name in old_validators.iter().filter(|name| !new_validators.contains(name)) {
+            effects.push((Effect::Widening, format!("validator '{}' removed", name)));
+        }
+
+        match (&old.r#enum, &new.r#enum) {
+            (None, Some(_)) => effects.push((Effect::Narrowing, "enum constraint added".to_string())),
+            (Some(_), None) => effects.push((Effect::Widening, "enum constraint removed".to_string())),
+            (Some(old_values), Some(new_values)) => {
+                let removed: Vec<String> = old_values.iter().filter(|v| !new_values.contains(v)).map(|v| v.to_string()).collect();
+                let added: Vec<String> = new_values.iter().filter(|v| !old_values.contains(v)).map(|v| v.to_string()).collect();
+                match (removed.is_empty(), added.is_empty()) {
+                    (false, true) => effects.push((Effect::Narrowing, format!("enum narrowed, removed {}", removed.join(", ")))),
+                    (true, false) => effects.push((Effect::Widening, format!("enum widened, added {}", added.join(", ")))),
+                    (false, false) => effects.push((
+                        Effect::Incompatible,
+                        format!("enum changed, removed {} and added {}", removed.join(", "), added.join(", ")),
+                    )),
+                    (true, true) => {}
+                }
+            }
+            (None, None) => {}
+        }
+
+        for (effect, description) in effects {
+            self.record(direction.severity(effect), path, description);
+        }
+    }
+
+    fn lower_bound_effect(effects: &mut Vec<(Effect, String)>, name: &str, old: Option<f64>, new: Option<f64>) {
+        match (old, new) {
+            (None, Some(new)) => effects.push((Effect::Narrowing, format!("{} {} added", name, new))),
+            (Some(old), None) => effects.push((Effect::Widening, format!("{} {} removed", name, old))),
+            (Some(old), Some(new)) if new > old => effects.push((Effect::Narrowing, format!("{} raised from {} to {}", name, old, new))),
+            (Some(old), Some(new)) if new < old => effects.push((Effect::Widening, format!("{} lowered from {} to {}", name, old, new))),
+            _ => {}
+        }
+    }
+
+    fn upper_bound_effect(effects: &mut Vec<(Effect, String)>, name: &str, old: Option<f64>, new: Option<f64>) {
+        match (old, new) {
+            (None, Some(new)) => effects.push((Effect::Narrowing, format!("{} {} added", name, new))),
+            (Some(old), None) => effects.push((Effect::Widening, format!("{} {} removed", name, old))),
+            (Some(old), Some(new)) if new < old => effects.push((Effect::Narrowing, format!("{} lowered from {} to {}", name, old, new))),
+            (Some(old), Some(new)) if new > old => effects.push((Effect::Widening, format!("{} raised from {} to {}", name, old, new))),
+            _ => {}
+        }
+    }
+
+    /// Effect of a type change, `integer` being a subset of `number`
+    fn type_effect(old_type: &str, new_type: &str) -> Effect {
+        match (old_type, new_type) {
+            ("integer", "number") => Effect::Widening,
+            ("number", "integer") => Effect::Narrowing,
+            _ => Effect::Incompatible,
+        }
+    }
+
+    /// Directions in which the models of the old Manifest are used
+    fn model_directions(&self) -> HashMap<String, Direction> {
+        let mut input = HashSet::new();
+        let mut output = HashSet::new();
+
+        if let Some(requests) = &self.old.requests {
+            for request in requests.values() {
+                for arg in request.args.values() {
+                    self.mark_argument(arg, &mut input);
+                }
+                if let Some(model_ref) = &request.response.model_ref {
+                    self.mark_model(model_ref, &mut output);
+                }
+                if let Some(items) = &request.response.items {
+                    self.mark_argument(items, &mut output);
+                }
+                if let Some(properties) = &request.response.properties {
+                    for property in properties.values() {
+                        self.mark_argument(property, &mut output);
+                    }
+                }
+            }
+        }
+
+        let mut directions = HashMap::new();
+        for name in input.union(&output) {
+            let direction = match (input.contains(name), output.contains(name)) {
+                (true, false) => Direction::Input,
+                (false, true) => Direction::Output,
+                _ => Direction::Both,
+            };
+            directions.insert(name.clone(), direction);
+        }
+        directions
+    }
+
+    fn mark_argument(&self, arg: &ArgumentManifest, used: &mut HashSet<String>) {
+        if let Some(model_ref) = &arg.model_ref {
+            self.mark_model(model_ref, used);
+        }
+        for nested in arg.items.iter().chain(&arg.additional_properties) {
+            self.mark_argument(nested, used);
+        }
+    }
+
+    fn mark_model(&self, model_name: &str, used: &mut HashSet<String>) {
+        if !used.insert(model_name.to_string()) {
+            return;
+        }
+        if let Some(model) = self.old.get_model(model_name) {
+            for property in model.properties.values() {
+                self.mark_argument(property, used);
+            }
+        }
+    }
+
+    fn all_keys<V>(old: &HashMap<String, V>, new: &HashMap<String, V>) -> BTreeSet<String> {
+        old.keys().chain(new.keys()).cloned().collect()
+    }
+}
@@ -0,0 +1,732 @@
+/*!
+ * JSON Schema support for Rust Janus Implementation
+ * Exports Manifest definitions as JSON Schema (draft 2020-12) documents
+ * and imports JSON Schema object definitions as Manifest models
+ */
+
+use crate::error::{JSONRPCError, JSONRPCErrorCode};
+use crate::manifest::model_registry::{
+    ArgumentManifest, Manifest, ModelManifest, RequestManifest, ResponseManifest, ValidationManifest,
+};
+use crate::manifest::string_format::StringFormat;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeSet, HashMap};
+
+/// JSON Schema dialect used for exported documents
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Exports Manifest requests and models as JSON Schema documents.
+/// Models are emitted under `$defs` and `modelRef` becomes `$ref: "#/$defs/<Model>"`.
+pub struct JsonSchemaExporter;
+
+impl JsonSchemaExporter {
+    /// Export the whole Manifest as a single schema bundle.
+    /// Models are placed under `$defs`, request schemas under `requests.<name>.args` / `.response`.
+    pub fn export_manifest(manifest: &Manifest) -> Value {
+        let mut requests = Map::new();
+        if let Some(request_manifests) = &manifest.requests {
+            for (request_name, request_manifest) in request_manifests {
+                let mut request_schema = Map::new();
+                request_schema.insert("description".to_string(), json!(request_manifest.description));
+                if request_manifest.is_deprecated() {
+                    request_schema.insert("deprecated".to_string(), json!(true));
+                }
+                request_schema.insert("args".to_string(), Self::args_schema(request_manifest));
+                request_schema.insert("response".to_string(), Self::response_schema(&request_manifest.response));
+                requests.insert(request_name.clone(), Value::Object(request_schema));
+            }
+        }
+
+        let mut document = Map::new();
+        document.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+        document.insert("title".to_string(), json!(format!("Janus Manifest {}", manifest.version)));
+        document.insert("version".to_string(), json!(manifest.version));
+        if let Some(models) = &manifest.models {
+            let defs = models
+                .iter()
+                .map(|(model_name, model)| (model_name.clone(), Self::model_schema(model)))
+                .collect();
+            document.insert("$defs".to_string(), Value::Object(defs));
+        }
+        document.insert("requests".to_string(), Value::Object(requests));
+
+        Value::Object(document)
+    }
+
+    /// Export the arguments of a request as a standalone schema document
+    pub fn export_request_args(manifest: &Manifest, request_name: &str) -> Result<Value, JSONRPCError> {
+        let request_manifest = Self::find_request(manifest, request_name)?;
+        let mut referenced = BTreeSet::new();
+        for arg_manifest in request_manifest.args.values() {
+            Self::collect_argument_refs(manifest, arg_manifest, &mut referenced);
+        }
+
+        let schema = Self::args_schema(request_manifest);
+        Ok(Self::document(
+            schema,
+            &format!("{} arguments", request_name),
+            Some(&request_manifest.description),
+            manifest,
+            &referenced,
+        ))
+    }
+
+    /// Export the response of a request as a standalone schema document
+    pub fn export_request_response(manifest: &Manifest, request_name: &str) -> Result<Value, JSONRPCError> {
+        let request_manifest = Self::find_request(manifest, request_name)?;
+        let response = &request_manifest.response;
+
+        let mut referenced = BTreeSet::new();
+        if let Some(model_ref) = &response.model_ref {
+            Self::collect_model_refs(manifest, model_ref, &mut referenced);
+        }
+        if let Some(items) = &response.items {
+            Self::collect_argument_refs(manifest, items, &mut referenced);
+        }
+        if let Some(properties) = &response.properties {
+            for property in properties.values() {
+                Self::collect_argument_refs(manifest, property, &mut referenced);
+            }
+        }
+
+        let schema = Self::response_schema(response);
+        Ok(Self::document(schema, &format!("{} response", request_name), None, manifest, &referenced))
+    }
+
+    /// Export a single model as a standalone schema document
+    pub fn export_model(manifest: &Manifest, model_name: &str) -> Result<Value, JSONRPCError> {
+        let model = manifest.get_model(model_name).ok_or_else(|| {
+            JSONRPCError::new(
+                JSONRPCErrorCode::ResourceNotFound,
+                Some(format!("Model '{}' is not defined in manifest", model_name)),
+            )
+        })?;
+
+        let mut referenced = BTreeSet::new();
+        for property in model.properties.values() {
+            Self::collect_argument_refs(manifest, property, &mut referenced);
+        }
+
+        Ok(Self::document(Self::model_schema(model), model_name, None, manifest, &referenced))
+    }
+
+    /// Convert an argument or property definition into a schema
+    pub fn argument_schema(arg_manifest: &ArgumentManifest) -> Value {
+        let mut schema = Map::new();
+
+        match &arg_manifest.model_ref {
+            Some(model_ref) => {
+                schema.insert("$ref".to_string(), json!(Self::model_ref_pointer(model_ref)));
+            }
+            None => {
+                schema.insert("type".to_string(), json!(arg_manifest.r#type));
+            }
+        }
+
+        if let Some(description) = &arg_manifest.description {
+            schema.insert("description".to_string(), json!(description));
+        }
+        if let Some(default_value) = &arg_manifest.default_value {
+            schema.insert("default".to_string(), default_value.clone());
+        }
+        if arg_manifest.is_deprecated() {
+            schema.insert("deprecated".to_string(), json!(true));
+        }
+
+        if let Some(validation) = &arg_manifest.validation {
+            let (min_key, max_key) = if arg_manifest.r#type == "array" {
+                ("minItems", "maxItems")
+            } else {
+                ("minLength", "maxLength")
+            };
+            if let Some(min_length) = validation.min_length {
+                schema.insert(min_key.to_string(), json!(min_length));
+            }
+            if let Some(max_length) = validation.max_length {
+                schema.insert(max_key.to_string(), json!(max_length));
+            }
+            if let Some(pattern) = &validation.pattern {
+                schema.insert("pattern".to_string(), json!(pattern));
+            }
+            if let Some(format) = &validation.format {
+                schema.insert("format".to_string(), json!(format));
+            }
+            if let Some(minimum) = validation.minimum {
+                schema.insert("minimum".to_string(), Self::number(minimum));
+            }
+            if let Some(maximum) = validation.maximum {
+                schema.insert("maximum".to_string(), Self::number(maximum));
+            }
+            if let Some(enum_values) = &validation.r#enum {
+                schema.insert("enum".to_string(), Value::Array(enum_values.clone()));
+            }
+        }
+
+        if let Some(items) = &arg_manifest.items {
+            schema.insert("items".to_string(), Self::argument_schema(items));
+        }
+        if let Some(values) = &arg_manifest.additional_properties {
+            schema.insert("additionalProperties".to_string(), Self::argument_schema(values));
+        }
+
+        Value::Object(schema)
+    }
+
+    /// Convert a model definition into an object schema
+    pub fn model_schema(model: &ModelManifest) -> Value {
+        let mut required: BTreeSet<&String> = model.required_properties().into_iter().collect();
+        required.extend(
+            model.properties
+                .iter()
+                .filter(|(_, property)| property.is_required())
+                .map(|(name, _)| name),
+        );
+
+        Self::object_schema(&model.r#type, &model.properties, required)
+    }
+
+    /// Convert request arguments into an object schema
+    pub fn args_schema(request_manifest: &RequestManifest) -> Value {
+        let required = request_manifest.required_arguments().into_iter().collect();
+        Self::object_schema("object", &request_manifest.args, required)
+    }
+
+    /// Convert a response definition into a schema
+    pub fn response_schema(response: &ResponseManifest) -> Value {
+        if let Some(model_ref) = &response.model_ref {
+            return json!({"$ref": Self::model_ref_pointer(model_ref)});
+        }
+        if let Some(items) = &response.items {
+            return json!({"type": response.r#type, "items": Self::argument_schema(items)});
+        }
+
+        match &response.properties {
+            Some(properties) => {
+                let required = properties
+                    .iter()
+                    .filter(|(_, property)| property.is_required())
+                    .map(|(name, _)| name)
+                    .collect();
+                Self::object_schema(&response.r#type, properties, required)
+            }
+            None => json!({"type": response.r#type}),
+        }
+    }
+
+    /// JSON pointer to a model definition
+    pub fn model_ref_pointer(model_ref: &str) -> String {
+        format!("#/$defs/{}", model_ref.replace('~', "~0").replace('/', "~1"))
+    }
+
+    fn object_schema(
+        object_type: &str,
+        properties: &HashMap<String, ArgumentManifest>,
+        required: BTreeSet<&String>,
+    ) -> Value {
+        let property_schemas: Map<String, Value> = properties
+            .iter()
+            .map(|(name, property)| (name.clone(), Self::argument_schema(property)))
+            .collect();
+
+        let mut schema = Map::new();
+        schema.insert("type".to_string(), json!(object_type));
+        schema.insert("properties".to_string(), Value::Object(property_schemas));
+        if !required.is_empty() {
+            schema.insert("required".to_string(), json!(required));
+        }
+        Value::Object(schema)
+    }
+
+    /// Wrap a schema into a standalone document with the referenced models under `$defs`
+    fn document(
+        schema: Value,
+        title: &str,
+        description: Option<&str>,
+        manifest: &Manifest,
+        referenced: &BTreeSet<String>,
+    ) -> Value {
+        let mut document = match schema {
+            Value::Object(object) => object,
+            other => {
+                let mut object = Map::new();
+                object.insert("allOf".to_string(), json!([other]));
+                object
+            }
+        };
+
+        document.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+        document.insert("title".to_string(), json!(title));
+        if let Some(description) = description {
+            document.insert("description".to_string(), json!(description));
+        }
+        if !referenced.is_empty() {
+            let defs = referenced
+                .iter()
+                .filter_map(|model_name| {
+                    manifest.get_model(model_name).map(|model| (model_name.clone(), Self::model_schema(model)))
+                })
+                .collect();
+            document.insert("$defs".to_string(), Value::Object(defs));
+        }
+
+        Value::Object(document)
+    }
+
+    fn find_request<'a>(manifest: &'a Manifest, request_name: &str) -> Result<&'a RequestManifest, JSONRPCError> {
+        manifest.get_request_manifest(request_name).ok_or_else(|| {
+            JSONRPCError::new(
+                JSONRPCErrorCode::ResourceNotFound,
+                Some(format!("Request '{}' is not defined in manifest", request_name)),
+            )
+        })
+    }
+
+    /// Collect the models reachable from an argument definition
+    fn collect_argument_refs(manifest: &Manifest, arg_manifest: &ArgumentManifest, referenced: &mut BTreeSet<String>) {
+        if let Some(model_ref) = &arg_manifest.model_ref {
+            Self::collect_model_refs(manifest, model_ref, referenced);
+        }
+        if let Some(items) = &arg_manifest.items {
+            Self::collect_argument_refs(manifest, items, referenced);
+        }
+        if let Some(values) = &arg_manifest.additional_properties {
+            Self::collect_argument_refs(manifest, values, referenced);
+        }
+    }
+
+    /// Collect a model and the models it refers to
+    fn collect_model_refs(manifest: &Manifest, model_name: &str, referenced: &mut BTreeSet<String>) {
+        if !referenced.insert(model_name.to_string()) {
+            return;
+        }
+        if let Some(model) = manifest.get_model(model_name) {
+            for property in model.properties.values() {
+                Self::collect_argument_refs(manifest, property, referenced);
+            }
+        }
+    }
+
+    /// Emit whole numbers as integers so `minimum: 1` does not become `1.0`
+    fn number(value: f64) -> Value {
+        if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+            json!(value as i64)
+        } else {
+            json!(value)
+        }
+    }
+}
+
+/// Keywords carried as annotations only, accepted and ignored on import
+const ANNOTATION_KEYWORDS: [&str; 8] = [
+    "$schema", "$id", "$comment", "title", "examples", "readOnly", "writeOnly", "$anchor",
+];
+
+/// Keywords mapped onto Manifest definitions on import
+const SUPPORTED_KEYWORDS: [&str; 20] = [
+    "type", "description", "default", "$ref", "properties", "required", "$defs", "definitions",
+    "minLength", "maxLength", "minItems", "maxItems", "pattern", "format", "minimum", "maximum", "enum",
+    "items", "additionalProperties", "deprecated",
+];
+
+/// Imports JSON Schema object definitions as Manifest models.
+/// Every construct that cannot be represented is reported with its JSON pointer path.
+pub struct JsonSchemaImporter {
+    errors: Vec<String>,
+}
+
+impl JsonSchemaImporter {
+    /// Import the `$defs` (or `definitions`) of a schema document as models.
+    /// When `root_name` is given the root object schema is imported as a model of that name.
+    pub fn import_models(document: &Value, root_name: Option<&str>) -> Result<HashMap<String, ModelManifest>, JSONRPCError> {
+        let mut importer = Self { errors: Vec::new() };
+        let mut models = HashMap::new();
+
+        let definitions = Self::definitions(document);
+        for (model_name, schema) in &definitions {
+            let path = format!("#/{}/{}", schema.0, model_name);
+            if let Some(model) = importer.import_model_schema(schema.1, &path, &definitions) {
+                models.insert(model_name.clone(), model);
+            }
+        }
+
+        if let Some(root_name) = root_name {
+            if models.contains_key(root_name) {
+                importer.error("#", format!("root model name '{}' conflicts with a definition", root_name));
+            } else if let Some(model) = importer.import_model_schema(document, "#", &definitions) {
+                models.insert(root_name.to_string(), model);
+            }
+        }
+
+        importer.finish(models)
+    }
+
+    /// Import a single object schema as a model, resolving `$ref`s against its `$defs`
+    pub fn import_model(schema: &Value) -> Result<ModelManifest, JSONRPCError> {
+        let mut importer = Self { errors: Vec::new() };
+        let definitions = Self::definitions(schema);
+        let model = importer.import_model_schema(schema, "#", &definitions);
+        importer.finish(model.unwrap_or_default())
+    }
+
+    /// Import a property schema as an argument definition
+    pub fn import_argument(schema: &Value) -> Result<ArgumentManifest, JSONRPCError> {
+        let mut importer = Self { errors: Vec::new() };
+        let definitions = Self::definitions(schema);
+        let argument = importer.import_argument_schema(schema, "#", &definitions);
+        importer.finish(argument.unwrap_or_else(|| ArgumentManifest::new("object".to_string())))
+    }
+
+    /// Import the models of a schema document into a Manifest, rejecting name conflicts
+    pub fn import_into(manifest: &mut Manifest, document: &Value, root_name: Option<&str>) -> Result<Vec<String>, JSONRPCError> {
+        let models = Self::import_models(document, root_name)?;
+
+        let mut conflicts: Vec<&String> = models.keys().filter(|name| manifest.get_model(name).is_some()).collect();
+        if !conflicts.is_empty() {
+            conflicts.sort();
+            return Err(JSONRPCError::new(
+                JSONRPCErrorCode::ManifestValidationError,
+                Some(format!("Models already exist in manifest: {}", conflicts.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(", "))),
+            ));
+        }
+
+        let mut imported: Vec<String> = models.keys().cloned().collect();
+        imported.sort();
+        for (model_name, model) in models {
+            manifest.add_model(model_name, model);
+        }
+        Ok(imported)
+    }
+
+    /// Definitions of a document by name, with the keyword they were found under
+    fn definitions(document: &Value) -> HashMap<String, (&'static str, &Value)> {
+        let mut definitions = HashMap::new();
+        for keyword in ["definitions", "$defs"] {
+            if let Some(defs) = document.get(keyword).and_then(Value::as_object) {
+                for (name, schema) in defs {
+                    definitions.insert(name.clone(), (keyword, schema));
+                }
+            }
+        }
+        definitions
+    }
+
+    fn import_model_schema(
+        &mut self,
+        schema: &Value,
+        path: &str,
+        definitions: &HashMap<String, (&'static str, &Value)>,
+    ) -> Option<ModelManifest> {
+        let object = match schema.as_object() {
+            Some(object) => object,
+            None => {
+                self.error(path, "schema must be an object".to_string());
+                return None;
+            }
+        };
+        self.check_keywords(object, path);
+
+        match object.get("type") {
+            Some(Value::String(schema_type)) if schema_type == "object" => {}
+            None if object.contains_key("properties") => {}
+            Some(other) => {
+                self.error(path, format!("only object schemas can be imported as models, found type {}", other));
+                return None;
+            }
+            None => {
+                self.error(path, "only object schemas can be imported as models, type is missing".to_string());
+                return None;
+            }
+        }
+        if object.contains_key("$ref") {
+            self.error(path, "model schemas cannot be a $ref".to_string());
+        }
+        for keyword in ["items", "additionalProperties"] {
+            if object.contains_key(keyword) {
+                self.error(&format!("{}/{}", path, keyword), format!("'{}' is not supported on models", keyword));
+            }
+        }
+
+        let mut model = ModelManifest::new();
+        if let Some(properties) = object.get("properties") {
+            match properties.as_object() {
+                Some(properties) => {
+                    for (prop_name, prop_schema) in properties {
+                        let prop_path = format!("{}/properties/{}", path, Self::escape_pointer(prop_name));
+                        if let Some(property) = self.import_argument_schema(prop_schema, &prop_path, definitions) {
+                            model.add_property(prop_name.clone(), property);
+                        }
+                    }
+                }
+                None => self.error(&format!("{}/properties", path), "properties must be an object".to_string()),
+            }
+        }
+
+        if let Some(required) = object.get("required") {
+            match required.as_array() {
+                Some(required) => {
+                    for (index, name) in required.iter().enumerate() {
+                        match name.as_str() {
+                            Some(name) => model.add_required(name.to_string()),
+                            None => self.error(&format!("{}/required/{}", path, index), "required entries must be strings".to_string()),
+                        }
+                    }
+                }
+                None => self.error(&format!("{}/required", path), "required must be an array".to_string()),
+            }
+        }
+
+        Some(model)
+    }
+
+    fn import_argument_schema(
+        &mut self,
+        schema: &Value,
+        path: &str,
+        definitions: &HashMap<String, (&'static str, &Value)>,
+    ) -> Option<ArgumentManifest> {
+        let object = match schema.as_object() {
+            Some(object) => object,
+            None => {
+                self.error(path, "schema must be an object".to_string());
+                return None;
+            }
+        };
+        self.check_keywords(object, path);
+
+        let mut argument = if let Some(reference) = object.get("$ref") {
+            let model_name = self.resolve_reference(reference, path, definitions)?;
+            ArgumentManifest::new("object".to_string()).with_model_ref(model_name)
+        } else {
+            let arg_type = self.schema_type(object, path)?;
+            if arg_type == "object" && object.contains_key("properties") {
+                self.error(path, "inline object schemas are not supported, move the object to $defs and use $ref".to_string());
+                return None;
+            }
+            ArgumentManifest::new(arg_type)
+        };
+
+        if let Some(items) = object.get("items") {
+            let items_path = format!("{}/items", path);
+            if argument.r#type != "array" {
+                self.error(&items_path, "items is only supported on array schemas".to_string());
+            } else if let Some(items) = self.import_argument_schema(items, &items_path, definitions) {
+                argument.items = Some(Box::new(items));
+            }
+        }
+        if let Some(values) = object.get("additionalProperties") {
+            let values_path = format!("{}/additionalProperties", path);
+            match values {
+                _ if argument.r#type != "object" => {
+                    self.error(&values_path, "additionalProperties is only supported on object schemas".to_string());
+                }
+                Value::Bool(true) => {}
+                Value::Bool(false) => {
+                    self.error(&values_path, "closed objects cannot be represented, remove additionalProperties".to_string());
+                }
+                _ => {
+                    if let Some(values) = self.import_argument_schema(values, &values_path, definitions) {
+                        argument.additional_properties = Some(Box::new(values));
+                    }
+                }
+            }
+        }
+
+        if let Some(description) = object.get("description") {
+            match description.as_str() {
+                Some(description) => argument.description = Some(description.to_string()),
+                None => self.error(&format!("{}/description", path), "description must be a string".to_string()),
+            }
+        }
+        if let Some(default_value) = object.get("default") {
+            argument.default_value = Some(default_value.clone());
+        }
+        match object.get("deprecated") {
+            Some(Value::Bool(true)) => argument.deprecated = Some(true),
+            Some(Value::Bool(false)) | None => {}
+            Some(_) => self.error(&format!("{}/deprecated", path), "deprecated must be a boolean".to_string()),
+        }
+
+        let validation = self.import_validation(object, &argument.r#type, path);
+        if validation != ValidationManifest::new() {
+            argument.validation = Some(validation);
+        }
+
+        Some(argument)
+    }
+
+    fn import_validation(&mut self, object: &Map<String, Value>, arg_type: &str, path: &str) -> ValidationManifest {
+        let mut validation = ValidationManifest::new();
+
+        let (min_key, max_key, other_min, other_max) = if arg_type == "array" {
+            ("minItems", "maxItems", "minLength", "maxLength")
+        } else {
+            ("minLength", "maxLength", "minItems", "maxItems")
+        };
+        validation.min_length = self.length(object, min_key, path);
+        validation.max_length = self.length(object, max_key, path);
+        for keyword in [other_min, other_max] {
+            if object.contains_key(keyword) {
+                self.error(&format!("{}/{}", path, keyword), format!("'{}' does not apply to type '{}'", keyword, arg_type));
+            }
+        }
+
+        if let Some(pattern) = object.get("pattern") {
+            match pattern.as_str() {
+                Some(pattern) if Regex::new(pattern).is_ok() => validation.pattern = Some(pattern.to_string()),
+                Some(pattern) => self.error(&format!("{}/pattern", path), format!("invalid regex pattern '{}'", pattern)),
+                None => self.error(&format!("{}/pattern", path), "pattern must be a string".to_string()),
+            }
+        }
+
+        // Formats without a built-in validator stay annotations, as JSON Schema treats them
+        match object.get("format") {
+            Some(Value::String(format)) if StringFormat::from_name(format).is_some() => validation.format = Some(format.clone()),
+            Some(Value::String(_)) | None => {}
+            Some(_) => self.error(&format!("{}/format", path), "format must be a string".to_string()),
+        }
+
+        for (keyword, target) in [("minimum", &mut validation.minimum), ("maximum", &mut validation.maximum)] {
+            if let Some(bound) = object.get(keyword) {
+                match bound.as_f64() {
+                    Some(bound) => *target = Some(bound),
+                    None => self.error(&format!("{}/{}", path, keyword), format!("{} must be a number", keyword)),
+                }
+            }
+        }
+
+        if let Some(enum_values) = object.get("enum") {
+            match enum_values.as_array() {
+                Some(enum_values) if !enum_values.is_empty() => validation.r#enum = Some(enum_values.clone()),
+                _ => self.error(&format!("{}/enum", path), "enum must be a non-empty array".to_string()),
+            }
+        }
+
+        validation
+    }
+
+    fn schema_type(&mut self, object: &Map<String, Value>, path: &str) -> Option<String> {
+        const TYPES: [&str; 6] = ["string", "integer", "number", "boolean", "array", "object"];
+
+        match object.get("type") {
+            Some(Value::String(schema_type)) if TYPES.contains(&schema_type.as_str()) => Some(schema_type.clone()),
+            Some(Value::String(schema_type)) => {
+                self.error(&format!("{}/type", path), format!("type '{}' cannot be represented", schema_type));
+                None
+            }
+            Some(Value::Array(_)) => {
+                self.error(&format!("{}/type", path), "multiple types cannot be represented".to_string());
+                None
+            }
+            Some(_) => {
+                self.error(&format!("{}/type", path), "type must be a string".to_string());
+                None
+            }
+            None => {
+                // Infer the type of enum-only schemas from their values, widening integers to numbers
+                let types: BTreeSet<&str> = object.get("enum")
+                    .and_then(Value::as_array)
+                    .map(|values| values.iter().map(Self::json_type).collect())
+                    .unwrap_or_default();
+                if types.contains("null") {
+                    self.error(&format!("{}/enum", path), "null enum values cannot be represented".to_string());
+                    return None;
+                }
+                let types: Vec<&str> = types.into_iter().collect();
+                match types[..] {
+                    [inferred] => Some(inferred.to_string()),
+                    ["integer", "number"] => Some("number".to_string()),
+                    _ => {
+                        self.error(path, "type is missing".to_string());
+                        None
+                    }
+                }
+            }
+        }
+    }
+
+    fn resolve_reference(
+        &mut self,
+        reference: &Value,
+        path: &str,
+        definitions: &HashMap<String, (&'static str, &Value)>,
+    ) -> Option<String> {
+        let ref_path = format!("{}/$ref", path);
+        let reference = match reference.as_str() {
+            Some(reference) => reference,
+            None => {
+                self.error(&ref_path, "$ref must be a string".to_string());
+                return None;
+            }
+        };
+
+        let model_name = reference
+            .strip_prefix("#/$defs/")
+            .or_else(|| reference.strip_prefix("#/definitions/"))
+            .map(|name| name.replace("~1", "/").replace("~0", "~"));
+        match model_name {
+            Some(model_name) if definitions.contains_key(&model_name) => Some(model_name),
+            Some(model_name) => {
+                self.error(&ref_path, format!("$ref target '{}' is not defined", model_name));
+                None
+            }
+            None => {
+                self.error(&ref_path, format!("only local $defs references are supported, found '{}'", reference));
+                None
+            }
+        }
+    }
+
+    fn length(&mut self, object: &Map<String, Value>, keyword: &str, path: &str) -> Option<usize> {
+        let value = object.get(keyword)?;
+        match value.as_u64() {
+            Some(length) => Some(length as usize),
+            None => {
+                self.error(&format!("{}/{}", path, keyword), format!("{} must be a non-negative integer", keyword));
+                None
+            }
+        }
+    }
+
+    fn check_keywords(&mut self, object: &Map<String, Value>, path: &str) {
+        for keyword in object.keys() {
+            let keyword = keyword.as_str();
+            if !SUPPORTED_KEYWORDS.contains(&keyword) && !ANNOTATION_KEYWORDS.contains(&keyword) {
+                self.error(&format!("{}/{}", path, Self::escape_pointer(keyword)), format!("unsupported keyword '{}'", keyword));
+            }
+        }
+    }
+
+    fn json_type(value: &Value) -> &'static str {
+        match value {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(number) if number.is_i64() || number.is_u64() => "integer",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+
+    fn escape_pointer(segment: &str) -> String {
+        segment.replace('~', "~0").replace('/', "~1")
+    }
+
+    fn error(&mut self, path: &str, message: String) {
+        self.errors.push(format!("{}: {}", path, message));
+    }
+
+    /// Return the imported value, or an error listing every problem found
+    fn finish<T>(self, value: T) -> Result<T, JSONRPCError> {
+        if self.errors.is_empty() {
+            return Ok(value);
+        }
+
+        Err(JSONRPCError::with_context(
+            JSONRPCErrorCode::ManifestValidationError,
+            Some(format!("JSON Schema import failed: {}", self.errors.join("; "))),
+            HashMap::from([("errors".to_string(), json!(self.errors))]),
+        ))
+    }
+}
@@ -0,0 +1,661 @@
+/*!
+ * Manifest linting for Rust Janus Implementation
+ * Collects every Manifest issue with a severity and its JSON/YAML source location
+ */
+
+use crate::error::{JSONRPCError, JSONRPCErrorCode};
+use crate::manifest::manifest_parser::ManifestParser;
+use crate::manifest::manifest_version::ManifestVersion;
+use crate::manifest::model_registry::{ArgumentManifest, Manifest, ResponseManifest, ValidationManifest};
+use crate::manifest::string_format::StringFormat;
+use crate::manifest::validation_engine::ValidationEngine;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Types accepted for arguments, properties and responses
+const VALID_TYPES: &[&str] = &["string", "integer", "number", "boolean", "array", "object"];
+
+/// How serious a lint issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    /// The manifest is rejected by validation or cannot work as written
+    Error,
+    /// Likely a mistake, the manifest still loads
+    Warning,
+    /// Style and documentation suggestions
+    Info,
+}
+
+impl fmt::Display for LintSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintSeverity::Error => write!(f, "error"),
+            LintSeverity::Warning => write!(f, "warning"),
+            LintSeverity::Info => write!(f, "info"),
+        }
+    }
+}
+
+/// A single issue found while linting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LintIssue {
+    pub severity: LintSeverity,
+    /// Stable rule identifier, e.g. `unused-model`
+    pub code: String,
+    pub message: String,
+    /// Dotted path of the offending definition, e.g. `requests.get_user.args.id`
+    pub path: String,
+    /// 1-based source line, when linting source text
+    pub line: Option<usize>,
+    /// 1-based source column, when linting source text
+    pub column: Option<usize>,
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            write!(f, "{}:{}: ", line, column)?;
+        }
+        write!(f, "{}[{}]: {}", self.severity, self.code, self.message)?;
+        if !self.path.is_empty() {
+            write!(f, " ({})", self.path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Every issue found in a manifest, ordered by source position then path
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LintReport {
+    pub issues: Vec<LintIssue>,
+}
+
+impl LintReport {
+    /// Whether any issue is an error
+    pub fn has_errors(&self) -> bool {
+        self.issues.iter().any(|issue| issue.severity == LintSeverity::Error)
+    }
+
+    /// Issues with exactly the given severity
+    pub fn with_severity(&self, severity: LintSeverity) -> Vec<&LintIssue> {
+        self.issues.iter().filter(|issue| issue.severity == severity).collect()
+    }
+
+    /// Issues reported by the given rule
+    pub fn with_code(&self, code: &str) -> Vec<&LintIssue> {
+        self.issues.iter().filter(|issue| issue.code == code).collect()
+    }
+
+    /// Count issues of a severity
+    pub fn count(&self, severity: LintSeverity) -> usize {
+        self.with_severity(severity).len()
+    }
+}
+
+/// Lints Manifests.
+/// Unlike `ManifestParser::validate`, which stops at the first error, every issue is collected.
+/// Linting source text adds the line and column of each offending definition.
+pub struct ManifestLinter;
+
+impl ManifestLinter {
+    /// Lint a parsed Manifest, issues carry paths but no source locations
+    pub fn lint(manifest: &Manifest) -> LintReport {
+        let mut linter = Linter::new(manifest);
+        linter.run();
+        LintReport { issues: linter.issues }
+    }
+
+    /// Lint JSON manifest source
+    pub fn lint_json(source: &str) -> LintReport {
+        match Self::parse_json(source) {
+            Ok((manifest, source_map)) => Self::locate(Self::lint(&manifest), &source_map),
+            Err(report) => report,
+        }
+    }
+
+    /// Lint YAML manifest source
+    #[cfg(feature = "yaml-support")]
+    pub fn lint_yaml(source: &str) -> LintReport {
+        match Self::parse_yaml(source) {
+            Ok((manifest, source_map)) => Self::locate(Self::lint(&manifest), &source_map),
+            Err(report) => report,
+        }
+    }
+
+    /// Lint a manifest file, choosing JSON or YAML by extension.
+    /// Includes are resolved so references into included files are not reported,
+    /// but only definitions of the file itself receive source locations.
+    pub async fn lint_file(path: &str) -> Result<LintReport, JSONRPCError> {
+        let source = tokio::fs::read_to_string(path).await.map_err(|e| {
+            JSONRPCError::new(JSONRPCErrorCode::ResourceNotFound, Some(format!("Failed to access file {}: {}", path, e)))
+        })?;
+
+        let parsed = if path.ends_with(".yaml") || path.ends_with(".yml") {
+            #[cfg(feature = "yaml-support")]
+            {
+                Self::parse_yaml(&source)
+            }
+            #[cfg(not(feature = "yaml-support"))]
+            {
+                return Err(JSONRPCError::new(
+                    JSONRPCErrorCode::ConfigurationError,
+                    Some("YAML support requires the 'yaml-support' feature".to_string()),
+                ));
+            }
+        } else {
+            Self::parse_json(&source)
+        };
+
+        let (manifest, source_map) = match parsed {
+            Ok(parsed) => parsed,
+            Err(report) => return Ok(report),
+        };
+        let manifest = if manifest.include.as_ref().is_some_and(|includes| !includes.is_empty()) {
+            ManifestParser::from_file(path).await?
+        } else {
+            manifest
+        };
+        Ok(Self::locate(Self::lint(&manifest), &source_map))
+    }
+
+    fn parse_json(source: &str) -> Result<(Manifest, SourceMap), LintReport> {
+        serde_json::from_str::<Manifest>(source)
+            .map(|manifest| (manifest, SourceMap::from_json(source)))
+            .map_err(|e| Self::parse_failure(format!("JSON parsing error: {}", e), Some((e.line(), e.column()))))
+    }
+
+    #[cfg(feature = "yaml-support")]
+    fn parse_yaml(source: &str) -> Result<(Manifest, SourceMap), LintReport> {
+        serde_yaml::from_str::<Manifest>(source)
+            .map(|manifest| (manifest, SourceMap::from_yaml(source)))
+            .map_err(|e| {
+                let location = e.location().map(|location| (location.line(), location.column()));
+                Self::parse_failure(format!("YAML parsing error: {}", e), location)
+            })
+    }
+
+    fn parse_failure(message: String, location: Option<(usize, usize)>) -> LintReport {
+        LintReport {
+            issues: vec![LintIssue {
+                severity: LintSeverity::Error,
+                code: "parse-error".to_string(),
+                message,
+                path: String::new(),
+                line: location.map(|(line, _)| line),
+                column: location.map(|(_, column)| column),
+            }],
+        }
+    }
+
+    /// Attach source locations and order issues as they appear in the source
+    fn locate(mut report: LintReport, source_map: &SourceMap) -> LintReport {
+        for issue in &mut report.issues {
+            if let Some((line, column)) = source_map.locate(&issue.path) {
+                issue.line = Some(line);
+                issue.column = Some(column);
+            }
+        }
+        report.issues.sort_by(|a, b| {
+            let position = |issue: &LintIssue| (issue.line.unwrap_or(usize::MAX), issue.column.unwrap_or(usize::MAX));
+            position(a).cmp(&position(b)).then_with(|| a.path.cmp(&b.path))
+        });
+        report
+    }
+}
+
+/// Walks a Manifest collecting issues
+struct Linter<'a> {
+    manifest: &'a Manifest,
+    issues: Vec<LintIssue>,
+}
+
+impl<'a> Linter<'a> {
+    fn new(manifest: &'a Manifest) -> Self {
+        Self { manifest, issues: Vec::new() }
+    }
+
+    fn report(&mut self, severity: LintSeverity, code: &str, path: &str, message: String) {
+        self.issues.push(LintIssue {
+            severity,
+            code: code.to_string(),
+            message,
+            path: path.to_string(),
+            line: None,
+            column: None,
+        });
+    }
+
+    fn run(&mut self) {
+        let manifest = self.manifest;
+        if manifest.version.is_empty() {
+            self.report(LintSeverity::Error, "invalid-version", "version", "Manifest version is required".to_string());
+        } else if ManifestVersion::parse(&manifest.version).is_err() {
+            self.report(LintSeverity::Error, "invalid-version", "version", format!(
+                "Version '{}' is not in MAJOR.MINOR.PATCH form", manifest.version
+            ));
+        }
+
+        if let Some(requests) = &manifest.requests {
+            for (request_name, request_manifest) in sorted(requests) {
+                let path = format!("requests.{}", request_name);
+                if request_manifest.description.trim().is_empty() {
+                    self.report(LintSeverity::Warning, "missing-description", &path, format!(
+                        "Request '{}' has no description", request_name
+                    ));
+                }
+                self.lint_deprecation(
+                    &format!("Request '{}'", request_name),
+                    (request_manifest.is_deprecated(), request_manifest.replacement.as_deref(), request_manifest.removal_version.as_deref()),
+                    manifest.has_request(request_manifest.replacement.as_deref().unwrap_or_default()),
+                    &path,
+                );
+                for (arg_name, arg_manifest) in sorted(&request_manifest.args) {
+                    let arg_path = format!("{}.args.{}", path, arg_name);
+                    self.lint_argument(arg_manifest, &arg_path, true);
+                    self.lint_deprecation(
+                        &format!("Argument '{}'", arg_name),
+                        (arg_manifest.is_deprecated(), arg_manifest.replacement.as_deref(), arg_manifest.removal_version.as_deref()),
+                        request_manifest.args.contains_key(arg_manifest.replacement.as_deref().unwrap_or_default()),
+                        &arg_path,
+                    );
+                }
+                self.lint_response(&request_manifest.response, &format!("{}.response", path));
+            }
+        }
+
+        if let Some(models) = &manifest.models {
+            let used = self.used_models();
+            for (model_name, model) in sorted(models) {
+                let path = format!("models.{}", model_name);
+                if model.properties.is_empty() {
+                    self.report(LintSeverity::Warning, "empty-properties", &format!("{}.properties", path), format!(
+                        "Model '{}' has no properties", model_name
+                    ));
+                }
+                for required in model.required_properties() {
+                    if !model.properties.contains_key(required) {
+                        self.report(LintSeverity::Error, "unknown-required-property", &format!("{}.required", path), format!(
+                            "Model '{}' requires '{}', which is not one of its properties", model_name, required
+                        ));
+                    }
+                }
+                for (prop_name, prop_manifest) in sorted(&model.properties) {
+                    self.lint_argument(prop_manifest, &format!("{}.properties.{}", path, prop_name), true);
+                }
+                if !used.contains(model_name.as_str()) {
+                    self.report(LintSeverity::Warning, "unused-model", &path, format!(
+                        "Model '{}' is not referenced by any request", model_name
+                    ));
+                }
+            }
+        }
+
+        if let Err(e) = ManifestParser::validate_model_cycles(manifest, None) {
+            let message = e.data.and_then(|data| data.details).unwrap_or_else(|| e.message.clone());
+            let model_name = message.split('\'').nth(1).unwrap_or_default().to_string();
+            self.report(LintSeverity::Error, "required-cycle", &format!("models.{}", model_name), message);
+        }
+    }
+
+    fn lint_argument(&mut self, argument: &ArgumentManifest, path: &str, documented: bool) {
+        if !VALID_TYPES.contains(&argument.r#type.as_str()) {
+            self.report(LintSeverity::Error, "unknown-type", &format!("{}.type", path), format!(
+                "Unknown type '{}', expected one of {}", argument.r#type, VALID_TYPES.join(", ")
+            ));
+        }
+        if documented && argument.description.as_deref().is_none_or(|d| d.trim().is_empty()) {
+            self.report(LintSeverity::Info, "missing-description", path, format!(
+                "'{}' has no description", path.rsplit('.').next().unwrap_or_default()
+            ));
+        }
+        if let Some(model_ref) = &argument.model_ref {
+            self.lint_model_ref(model_ref, &format!("{}.modelRef", path));
+        }
+        if let Some(validation) = &argument.validation {
+            self.lint_validation(validation, &argument.r#type, &format!("{}.validation", path));
+        }
+        if let Some(default_value) = &argument.default_value {
+            self.lint_default(argument, default_value, &format!("{}.default_value", path));
+        }
+        if let Some(items) = &argument.items {
+            if argument.r#type != "array" {
+                self.report(LintSeverity::Error, "misplaced-items", &format!("{}.items", path), format!(
+                    "'items' only applies to arrays, not '{}'", argument.r#type
+                ));
+            }
+            self.lint_argument(items, &format!("{}.items", path), false);
+        }
+        if let Some(values) = &argument.additional_properties {
+            if argument.r#type != "object" {
+                self.report(LintSeverity::Error, "misplaced-additional-properties", &format!("{}.additionalProperties", path), format!(
+                    "'additionalProperties' only applies to objects, not '{}'", argument.r#type
+                ));
+            }
+            self.lint_argument(values, &format!("{}.additionalProperties", path), false);
+        }
+    }
+
+    fn lint_deprecation(
+        &mut self,
+        subject: &str,
+        (deprecated, replacement, removal_version): (bool, Option<&str>, Option<&str>),
+        replacement_exists: bool,
+        path: &str,
+    ) {
+        if !deprecated && (replacement.is_some() || removal_version.is_some()) {
+            self.report(LintSeverity::Error, "invalid-deprecation", &format!("{}.deprecated", path), format!(
+                "{} has a replacement or removal version but is not deprecated", subject
+            ));
+        }
+        if let Some(replacement) = replacement.filter(|_| !replacement_exists) {
+            self.report(LintSeverity::Warning, "unknown-replacement", &format!("{}.replacement", path), format!(
+                "{} names replacement '{}', which is not defined", subject, replacement
+            ));
+        }
+        if let Some(removal_version) = removal_version {
+            let removal_path = format!("{}.removalVersion", path);
+            match (ManifestVersion::parse(removal_version), ManifestVersion::parse(&self.manifest.version)) {
+                (Err(_), _) => self.report(LintSeverity::Error, "invalid-deprecation", &removal_path, format!(
+                    "Removal version '{}' is not in MAJOR.MINOR.PATCH form", removal_version
+                )),
+                (Ok(removal), Ok(current)) if removal <= current => {
+                    self.report(LintSeverity::Warning, "removal-overdue", &removal_path, format!(
+                        "{} was due for removal in version {}", subject, removal_version
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn lint_response(&mut self, response: &ResponseManifest, path: &str) {
+        if !VALID_TYPES.contains(&response.r#type.as_str()) {
+            self.report(LintSeverity::Error, "unknown-type", &format!("{}.type", path), format!(
+                "Unknown type '{}', expected one of {}", response.r#type, VALID_TYPES.join(", ")
+            ));
+        }
+        if let Some(model_ref) = &response.model_ref {
+            self.lint_model_ref(model_ref, &format!("{}.modelRef", path));
+        }
+        if let Some(properties) = &response.properties {
+            if properties.is_empty() {
+                self.report(LintSeverity::Warning, "empty-properties", &format!("{}.properties", path),
+                    "Response declares an empty property map".to_string());
+            }
+            for (prop_name, prop_manifest) in sorted(properties) {
+                self.lint_argument(prop_manifest, &format!("{}.properties.{}", path, prop_name), false);
+            }
+        }
+        if let Some(items) = &response.items {
+            self.lint_argument(items, &format!("{}.items", path), false);
+        }
+    }
+
+    fn lint_model_ref(&mut self, model_ref: &str, path: &str) {
+        if self.manifest.get_model(model_ref).is_none() {
+            self.report(LintSeverity::Error, "unresolved-model-ref", path, format!(
+                "Model '{}' is not defined", model_ref
+            ));
+        }
+    }
+
+    fn lint_validation(&mut self, validation: &ValidationManifest, value_type: &str, path: &str) {
+        if let Some(pattern) = &validation.pattern {
+            if let Err(e) = Regex::new(pattern) {
+                let reason = e.to_string().lines().last().unwrap_or_default().trim().trim_start_matches("error: ").to_string();
+                self.report(LintSeverity::Error, "invalid-pattern", &format!("{}.pattern", path), format!(
+                    "Pattern '{}' does not compile: {}", pattern, reason
+                ));
+            }
+            if value_type != "string" {
+                self.report(LintSeverity::Warning, "inapplicable-constraint", &format!("{}.pattern", path), format!(
+                    "'pattern' has no effect on type '{}'", value_type
+                ));
+            }
+        }
+
+        if let Some(format) = &validation.format {
+            if StringFormat::from_name(format).is_none() {
+                self.report(LintSeverity::Error, "unknown-format", &format!("{}.format", path), format!(
+                    "Unknown string format '{}', expected one of {}", format, StringFormat::supported_names()
+                ));
+            }
+            if value_type != "string" {
+                self.report(LintSeverity::Warning, "inapplicable-constraint", &format!("{}.format", path), format!(
+                    "'format' has no effect on type '{}'", value_type
+                ));
+            }
+        }
+
+        if let (Some(min), Some(max)) = (validation.min_length, validation.max_length) {
+            if min > max {
+                self.report(LintSeverity::Error, "invalid-range", path, format!(
+                    "minLength {} is greater than maxLength {}", min, max
+                ));
+            }
+        }
+        if (validation.min_length.is_some() || validation.max_length.is_some()) && value_type != "string" && value_type != "array" {
+            self.report(LintSeverity::Warning, "inapplicable-constraint", path, format!(
+                "Length constraints have no effect on type '{}'", value_type
+            ));
+        }
+
+        if let (Some(min), Some(max)) = (validation.minimum, validation.maximum) {
+            if min > max {
+                self.report(LintSeverity::Error, "invalid-range", path, format!(
+                    "minimum {} is greater than maximum {}", min, max
+                ));
+            }
+        }
+        if (validation.minimum.is_some() || validation.maximum.is_some()) && value_type != "integer" && value_type != "number" {
+            self.report(LintSeverity::Warning, "inapplicable-constraint", path, format!(
+                "Numeric range has no effect on type '{}'", value_type
+            ));
+        }
+
+        if validation.r#enum.as_ref().is_some_and(|values| values.is_empty()) {
+            self.report(LintSeverity::Error, "empty-enum", &format!("{}.enum", path),
+                "Enum lists no allowed values".to_string());
+        }
+    }
+
+    fn lint_default(&mut self, argument: &ArgumentManifest, default_value: &serde_json::Value, path: &str) {
+        let enum_values = argument.validation.as_ref().and_then(|validation| validation.r#enum.as_ref());
+        if let Some(enum_values) = enum_values.filter(|values| !values.is_empty()) {
+            if !enum_values.contains(default_value) {
+                self.report(LintSeverity::Error, "default-not-in-enum", path, format!(
+                    "Default {} is not one of the enum values", default_value
+                ));
+                return;
+            }
+        }
+
+        let mut errors = Vec::new();
+        ValidationEngine::new(self.manifest).validate_value(default_value, argument, "", &mut errors);
+        if let Some(error) = errors.first() {
+            let location = if error.field.is_empty() { String::new() } else { format!(" at {}", error.field) };
+            self.report(LintSeverity::Error, "invalid-default", path, format!(
+                "Default {} is invalid{}: {}", default_value, location, error.message
+            ));
+        }
+    }
+
+    /// Models reachable from request arguments and responses
+    fn used_models(&self) -> HashSet<&'a str> {
+        let manifest = self.manifest;
+        let mut pending: Vec<&'a str> = Vec::new();
+        if let Some(requests) = &manifest.requests {
+            for request_manifest in requests.values() {
+                for arg_manifest in request_manifest.args.values() {
+                    collect_model_refs(arg_manifest, &mut pending);
+                }
+                let response = &request_manifest.response;
+                pending.extend(response.model_ref.as_deref());
+                for prop_manifest in response.properties.iter().flat_map(|properties| properties.values()) {
+                    collect_model_refs(prop_manifest, &mut pending);
+                }
+                if let Some(items) = &response.items {
+                    collect_model_refs(items, &mut pending);
+                }
+            }
+        }
+
+        let mut used = HashSet::new();
+        while let Some(model_name) = pending.pop() {
+            if !used.insert(model_name) {
+                continue;
+            }
+            if let Some(model) = manifest.get_model(model_name) {
+                for prop_manifest in model.properties.values() {
+                    collect_model_refs(prop_manifest, &mut pending);
+                }
+            }
+        }
+        used
+    }
+}
+
+fn collect_model_refs<'a>(argument: &'a ArgumentManifest, refs: &mut Vec<&'a str>) {
+    refs.extend(argument.model_ref.as_deref());
+    if let Some(items) = &argument.items {
+        collect_model_refs(items, refs);
+    }
+    if let Some(values) = &argument.additional_properties {
+        collect_model_refs(values, refs);
+    }
+}
+
+fn sorted<V>(map: &HashMap<String, V>) -> Vec<(&String, &V)> {
+    let mut entries: Vec<(&String, &V)> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+/// Source positions of object keys, keyed by their path from the document root
+struct SourceMap {
+    positions: HashMap<Vec<String>, (usize, usize)>,
+}
+
+impl SourceMap {
+    /// Position of the definition at a dotted path, falling back to its closest located parent.
+    /// Never falls back to a top-level section, so definitions from included files stay unlocated.
+    fn locate(&self, path: &str) -> Option<(usize, usize)> {
+        if path.is_empty() {
+            return None;
+        }
+        let segments: Vec<String> = path.split('.').map(str::to_string).collect();
+        let shortest = segments.len().min(2);
+        (shortest..=segments.len())
+            .rev()
+            .find_map(|length| self.positions.get(&segments[..length]))
+            .copied()
+    }
+
+    /// Record the position of every object key in JSON source
+    fn from_json(source: &str) -> Self {
+        // Each frame is an open container: (is_object, current key or index, expecting a key)
+        let mut frames: Vec<(bool, String, bool)> = Vec::new();
+        let mut positions = HashMap::new();
+        let mut chars = source.chars().peekable();
+        let (mut line, mut column) = (1, 1);
+
+        while let Some(c) = chars.next() {
+            let start = (line, column);
+            if c == '\n' {
+                line += 1;
+                column = 1;
+                continue;
+            }
+            column += 1;
+
+            match c {
+                '{' | '[' => frames.push((c == '{', "0".to_string(), c == '{')),
+                '}' | ']' => {
+                    frames.pop();
+                }
+                ',' => {
+                    if let Some(frame) = frames.last_mut() {
+                        if frame.0 {
+                            frame.2 = true;
+                        } else {
+                            frame.1 = (frame.1.parse::<usize>().unwrap_or(0) + 1).to_string();
+                        }
+                    }
+                }
+                ':' => {
+                    if let Some(frame) = frames.last_mut() {
+                        frame.2 = false;
+                    }
+                }
+                '"' => {
+                    let mut raw = String::from('"');
+                    while let Some(c) = chars.next() {
+                        column += 1;
+                        raw.push(c);
+                        match c {
+                            '\\' => {
+                                if let Some(escaped) = chars.next() {
+                                    column += 1;
+                                    raw.push(escaped);
+                                }
+                            }
+                            '"' => break,
+                            _ => {}
+                        }
+                    }
+                    if let Some(frame) = frames.last_mut().filter(|frame| frame.0 && frame.2) {
+                        frame.1 = serde_json::from_str(&raw).unwrap_or_default();
+                        let key: Vec<String> = frames.iter().map(|frame| frame.1.clone()).collect();
+                        positions.insert(key, start);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self { positions }
+    }
+
+    /// Record the position of every block mapping key in YAML source
+    #[cfg(feature = "yaml-support")]
+    fn from_yaml(source: &str) -> Self {
+        let key_pattern = Regex::new(r#"^(?:"([^"]*)"|'([^']*)'|([^\s"'#{}\[\],][^:#]*?))\s*:(?:\s|$)"#).unwrap();
+        let mut stack: Vec<(usize, String)> = Vec::new();
+        let mut positions = HashMap::new();
+
+        for (index, text) in source.lines().enumerate() {
+            let trimmed = text.trim_start();
+            if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with("---") {
+                continue;
+            }
+            let mut indent = text.len() - trimmed.len();
+            let mut content = trimmed;
+            // Keys of mappings nested in sequence items start after the dash
+            while let Some(rest) = content.strip_prefix("- ") {
+                indent += 2 + (rest.len() - rest.trim_start().len());
+                content = rest.trim_start();
+            }
+
+            let captures = match key_pattern.captures(content) {
+                Some(captures) => captures,
+                None => continue,
+            };
+            let key = (1..=3)
+                .find_map(|group| captures.get(group))
+                .map(|m| m.as_str().to_string())
+                .unwrap_or_default();
+
+            while stack.last().is_some_and(|(key_indent, _)| *key_indent >= indent) {
+                stack.pop();
+            }
+            stack.push((indent, key));
+            let path: Vec<String> = stack.iter().map(|(_, key)| key.clone()).collect();
+            positions.insert(path, (index + 1, indent + 1));
+        }
+
+        Self { positions }
+    }
+}
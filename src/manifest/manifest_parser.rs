@@ -1,6 +1,13 @@
 use crate::error::{JSONRPCError, JSONRPCErrorCode};
 use crate::manifest::Manifest;
+use crate::manifest::compatibility::{CompatibilityReport, ManifestCompatibility};
+use crate::manifest::manifest_linter::{LintReport, ManifestLinter};
+use crate::manifest::string_format::StringFormat;
 use log::{debug, error, info, warn};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use tokio::fs;
 
 /// Manifest parser for JSON and YAML formats (exact SwiftJanus parity)
@@ -44,7 +51,10 @@ impl ManifestParser {
             Ok(manifest) => {
                 info!("Successfully parsed Manifest from JSON{}", context);
                 debug!("Parsed Manifest version: {}", manifest.version);
-                debug!("Channels removed from protocol");
+                debug!(
+                    "Parsed {} requests",
+                    manifest.requests.as_ref().map_or(0, |r| r.len())
+                );
                 Ok(manifest)
             }
             Err(e) => {
@@ -130,7 +140,10 @@ impl ManifestParser {
             Ok(manifest) => {
                 info!("Successfully parsed Manifest from YAML{}", context);
                 debug!("Parsed Manifest version: {}", manifest.version);
-                debug!("Channels removed from protocol");
+                debug!(
+                    "Parsed {} requests",
+                    manifest.requests.as_ref().map_or(0, |r| r.len())
+                );
                 Ok(manifest)
             }
             Err(e) => {
@@ -160,8 +173,104 @@ impl ManifestParser {
         }
     }
 
-    /// Parse Manifest from file (auto-detect format based on extension)
+    /// Parse Manifest from file (auto-detect format based on extension).
+    /// `include` entries are resolved relative to the including file and merged in.
     pub async fn from_file(path: &str) -> Result<Manifest, JSONRPCError> {
+        let mut loaded = HashSet::new();
+        Self::load_file_tree(Path::new(path), None, &mut Vec::new(), &mut loaded)
+            .await?
+            .ok_or_else(|| JSONRPCError::new(JSONRPCErrorCode::InternalError, Some(format!("Manifest file {} was already loaded", path))))
+    }
+
+    /// Load a Manifest file and its includes depth-first.
+    /// Returns None for files already merged elsewhere in the tree, so shared includes are merged once.
+    fn load_file_tree<'a>(
+        path: &'a Path,
+        included_from: Option<&'a Path>,
+        stack: &'a mut Vec<PathBuf>,
+        loaded: &'a mut HashSet<PathBuf>,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Manifest>, JSONRPCError>> + Send + 'a>> {
+        Box::pin(async move {
+            let display_path = path.to_string_lossy().to_string();
+            let canonical_path = fs::canonicalize(path).await.map_err(|e| {
+                let context = included_from
+                    .map(|p| format!(" (included from: {})", p.display()))
+                    .unwrap_or_default();
+                error!("Cannot access Manifest file '{}'{}: {}", display_path, context, e);
+                JSONRPCError::new(JSONRPCErrorCode::ResourceNotFound, Some(format!("Failed to access file {}{}: {}", display_path, context, e)))
+            })?;
+
+            if let Some(start) = stack.iter().position(|p| *p == canonical_path) {
+                let cycle: Vec<String> = stack[start..]
+                    .iter()
+                    .chain(std::iter::once(&canonical_path))
+                    .map(|p| p.display().to_string())
+                    .collect();
+                error!("Manifest include cycle detected: {}", cycle.join(" -> "));
+                return Err(JSONRPCError::new(JSONRPCErrorCode::ValidationFailed, Some(format!(
+                    "Manifest include cycle detected: {}",
+                    cycle.join(" -> ")
+                ))));
+            }
+            if loaded.contains(&canonical_path) {
+                debug!("Manifest file already included, skipping: {}", display_path);
+                return Ok(None);
+            }
+
+            let mut manifest = Self::read_file(&display_path).await?;
+            let includes = manifest.include.take().unwrap_or_default();
+            let base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+            stack.push(canonical_path.clone());
+            for include in &includes {
+                let include_path = base_dir.join(include);
+                debug!("Including Manifest {} from {}", include_path.display(), display_path);
+                let included = Self::load_file_tree(&include_path, Some(path), stack, loaded).await?;
+                if let Some(included) = included {
+                    Self::merge_manifests(&mut manifest, &included).map_err(|e| {
+                        let details = e.data.as_ref().and_then(|data| data.details.clone()).unwrap_or_default();
+                        JSONRPCError::new(JSONRPCErrorCode::ValidationFailed, Some(format!(
+                            "{} (file: {}, included from: {})",
+                            details, include_path.display(), display_path
+                        )))
+                    })?;
+                }
+            }
+            stack.pop();
+
+            if !includes.is_empty() {
+                info!("Resolved {} includes for Manifest {}", includes.len(), display_path);
+            }
+            loaded.insert(canonical_path);
+            Ok(Some(manifest))
+        })
+    }
+
+    /// List a Manifest file and every file its includes resolve to.
+    /// Files that cannot be read or parsed are listed without following their includes,
+    /// so watchers also notice when a missing or broken include is fixed.
+    pub async fn include_files(path: &str) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        let mut pending = vec![PathBuf::from(path)];
+        while let Some(path) = pending.pop() {
+            let canonical_path = fs::canonicalize(&path).await.unwrap_or_else(|_| path.clone());
+            if files.contains(&canonical_path) {
+                continue;
+            }
+            files.push(canonical_path);
+
+            let includes = match Self::read_file(&path.to_string_lossy()).await {
+                Ok(manifest) => manifest.include.unwrap_or_default(),
+                Err(_) => continue,
+            };
+            let base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+            pending.extend(includes.iter().rev().map(|include| base_dir.join(include)));
+        }
+        files
+    }
+
+    /// Parse a single Manifest file without resolving its includes
+    async fn read_file(path: &str) -> Result<Manifest, JSONRPCError> {
         info!("Loading Manifest from file: {}", path);
 
         // Validate file path
@@ -254,11 +363,11 @@ impl ManifestParser {
 
     /// Serialize Manifest to YAML string
     #[cfg(feature = "yaml-support")]
-    pub fn to_yaml(manifest: &Manifest) -> Result<String, JanusError> {
+    pub fn to_yaml(manifest: &Manifest) -> Result<String, JSONRPCError> {
         debug!("Serializing Manifest to YAML");
         serde_yaml::to_string(manifest).map_err(|e| {
             error!("Failed to serialize Manifest to YAML: {}", e);
-            JSONRPCErrorCode::EncodingFailed(format!("YAML serialization error: {}", e))
+            JSONRPCError::new(JSONRPCErrorCode::InternalError, Some(format!("YAML serialization error: {}", e)))
         })
     }
 
@@ -325,8 +434,35 @@ impl ManifestParser {
         }
         debug!("✓ Version format is valid: {}", manifest.version);
 
-        // Channels have been removed from the protocol
-        debug!("Channels removed from protocol - skipping channel validation");
+        // Includes are only resolved when loading from a file
+        if let Some(includes) = manifest.include.as_ref().filter(|includes| !includes.is_empty()) {
+            error!(
+                "Manifest validation failed{}: unresolved includes {:?}",
+                context, includes
+            );
+            return Err(JSONRPCError::new(JSONRPCErrorCode::ValidationFailed, Some(format!(
+                "Manifest includes {} were not resolved, load the manifest with ManifestParser::from_file{}",
+                includes.join(", "), context
+            ))));
+        }
+
+        // Validate requests if present
+        if let Some(requests) = &manifest.requests {
+            debug!("Validating {} requests", requests.len());
+            for (request_name, request_manifest) in requests {
+                debug!("Validating request: {}", request_name);
+                if let Err(e) = Self::validate_request_manifest(request_name, request_manifest, file_path) {
+                    error!(
+                        "Request validation failed for '{}'{}: {}",
+                        request_name, context, e
+                    );
+                    return Err(e);
+                }
+                debug!("✓ Request '{}' is valid", request_name);
+            }
+        } else {
+            debug!("No requests defined in Manifest");
+        }
 
         // Validate models if present
         if let Some(models) = &manifest.models {
@@ -346,15 +482,23 @@ impl ManifestParser {
             debug!("No models defined in Manifest");
         }
 
+        // Validate model references resolve and recursive models can be satisfied
+        if let Err(e) = Self::validate_model_references(manifest, file_path)
+            .and_then(|_| Self::validate_model_cycles(manifest, file_path))
+        {
+            error!("Model reference validation failed{}: {}", context, e);
+            return Err(e);
+        }
+
         info!(
             "✓ Manifest validation completed successfully{}",
             context
         );
         info!(
-            "Validated{}: version {}, {} channels, {} models",
+            "Validated{}: version {}, {} requests, {} models",
             context,
             manifest.version,
-            0, // channels removed
+            manifest.requests.as_ref().map_or(0, |r| r.len()),
             manifest.models.as_ref().map_or(0, |m| m.len())
         );
 
@@ -442,6 +586,11 @@ impl ManifestParser {
         Ok(manifest)
     }
 
+    /// Collect every issue in a Manifest instead of stopping at the first error, see `ManifestLinter`
+    pub fn lint(manifest: &Manifest) -> LintReport {
+        ManifestLinter::lint(manifest)
+    }
+
     /// Get a summary of validation errors for diagnostics
     pub fn get_validation_summary(manifest: &Manifest) -> String {
         let mut summary = Vec::new();
@@ -453,8 +602,17 @@ impl ManifestParser {
             summary.push(format!("• Invalid version format: {}", manifest.version));
         }
 
-        // Channels have been removed from the protocol
-        summary.push("• Channels removed from protocol".to_string());
+        // Check requests if present
+        if let Some(requests) = &manifest.requests {
+            for (request_name, request_manifest) in requests {
+                if request_name.is_empty() {
+                    summary.push("• Empty request name found".to_string());
+                }
+                if request_manifest.description.is_empty() {
+                    summary.push(format!("• Request '{}' has no description", request_name));
+                }
+            }
+        }
 
         // Check models if present
         if let Some(models) = &manifest.models {
@@ -574,9 +732,7 @@ impl ManifestParser {
     } */
 
     /// Validate request manifest
-    #[allow(dead_code)]
     fn validate_request_manifest(
-        channel_name: &str,
         request_name: &str,
         request_manifest: &crate::manifest::RequestManifest,
         file_path: Option<&str>,
@@ -610,9 +766,17 @@ impl ManifestParser {
 
         // Description validation
         if request_manifest.description.is_empty() {
-            return Err(JSONRPCError::new(JSONRPCErrorCode::MethodNotFound, Some(format!("Request '{}' in channel '{}' must have a description{}", request_name, channel_name, context))));
+            return Err(JSONRPCError::new(JSONRPCErrorCode::MethodNotFound, Some(format!("Request '{}' must have a description{}", request_name, context))));
         }
 
+        Self::validate_deprecation(
+            &format!("Request '{}'", request_name),
+            request_manifest.is_deprecated(),
+            request_manifest.replacement.as_deref(),
+            request_manifest.removal_version.as_deref(),
+            file_path,
+        )?;
+
         // Validate arguments
         for (arg_name, arg_manifest) in &request_manifest.args {
             Self::validate_argument_manifest(arg_name, arg_manifest, file_path)?;
@@ -651,6 +815,14 @@ impl ManifestParser {
             return Err(JSONRPCError::new(JSONRPCErrorCode::InvalidParams, Some(format!("Invalid argument type: {}{}", arg_manifest.r#type, context))));
         }
 
+        Self::validate_deprecation(
+            &format!("Argument '{}'", arg_name),
+            arg_manifest.is_deprecated(),
+            arg_manifest.replacement.as_deref(),
+            arg_manifest.removal_version.as_deref(),
+            file_path,
+        )?;
+
         // Validation constraint validation
         if let Some(validation) = &arg_manifest.validation {
             Self::validate_validation_manifest(arg_name, validation, file_path)?;
@@ -661,6 +833,44 @@ impl ManifestParser {
             Self::validate_value_type(arg_name, default_value, &arg_manifest.r#type, file_path)?;
         }
 
+        // Nested item and map value definitions
+        if let Some(items) = &arg_manifest.items {
+            if arg_manifest.r#type != "array" {
+                return Err(JSONRPCError::new(JSONRPCErrorCode::InvalidParams, Some(format!("Argument '{}' defines items but is not an array{}", arg_name, context))));
+            }
+            Self::validate_argument_manifest(&format!("{}[]", arg_name), items, file_path)?;
+        }
+        if let Some(values) = &arg_manifest.additional_properties {
+            if arg_manifest.r#type != "object" {
+                return Err(JSONRPCError::new(JSONRPCErrorCode::InvalidParams, Some(format!("Argument '{}' defines additionalProperties but is not an object{}", arg_name, context))));
+            }
+            Self::validate_argument_manifest(&format!("{}{{}}", arg_name), values, file_path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Validate deprecation metadata: details require the deprecated flag
+    /// and the removal version must be a semantic version
+    fn validate_deprecation(
+        subject: &str,
+        deprecated: bool,
+        replacement: Option<&str>,
+        removal_version: Option<&str>,
+        file_path: Option<&str>,
+    ) -> Result<(), JSONRPCError> {
+        let context = file_path
+            .map(|p| format!(" (file: {})", p))
+            .unwrap_or_default();
+        if !deprecated && (replacement.is_some() || removal_version.is_some()) {
+            return Err(JSONRPCError::new(JSONRPCErrorCode::ValidationFailed, Some(format!("{} has a replacement or removal version but is not deprecated{}", subject, context))));
+        }
+        if let Some(removal_version) = removal_version {
+            if !Self::is_valid_version(removal_version) {
+                return Err(JSONRPCError::new(JSONRPCErrorCode::ValidationFailed, Some(format!("{} has an invalid removal version: {}{}", subject, removal_version, context))));
+            }
+        }
+
         Ok(())
     }
 
@@ -696,6 +906,23 @@ impl ManifestParser {
             })?;
         }
 
+        // Format validation
+        if let Some(format) = &validation_manifest.format {
+            if StringFormat::from_name(format).is_none() {
+                return Err(JSONRPCError::new(JSONRPCErrorCode::InvalidParams, Some(format!(
+                    "Unknown string format '{}', expected one of {}{}",
+                    format, StringFormat::supported_names(), context
+                ))));
+            }
+        }
+
+        // Named validators are resolved by the server, only their names can be checked here
+        if let Some(validators) = &validation_manifest.validators {
+            if validators.iter().any(|name| name.trim().is_empty()) {
+                return Err(JSONRPCError::new(JSONRPCErrorCode::InvalidParams, Some(format!("Validator names cannot be empty{}", context))));
+            }
+        }
+
         // Enum validation
         if let Some(enum_values) = &validation_manifest.r#enum {
             if enum_values.is_empty() {
@@ -707,7 +934,6 @@ impl ManifestParser {
     }
 
     /// Validate response manifest
-    #[allow(dead_code)]
     fn validate_response_manifest(
         response_manifest: &crate::manifest::ResponseManifest,
         file_path: Option<&str>,
@@ -737,7 +963,6 @@ impl ManifestParser {
     }
 
     /// Validate error code manifest
-    #[allow(dead_code)]
     fn validate_error_code_manifest(
         error_name: &str,
         error_manifest: &crate::manifest::ErrorCodeManifest,
@@ -760,11 +985,12 @@ impl ManifestParser {
             ))));
         }
 
-        // Validate HTTP status code range
-        if !(100..=599).contains(&error_manifest.code) {
+        // Accept HTTP status codes and negative codes outside the range JSON-RPC reserves
+        let code = error_manifest.code;
+        if !(100..=599).contains(&code) && (code >= 0 || (-32768..=-32000).contains(&code)) {
             return Err(JSONRPCError::new(JSONRPCErrorCode::ValidationFailed, Some(format!(
-                "Invalid HTTP status code: {}{}",
-                error_manifest.code, context
+                "Invalid error code: {}, expected an HTTP status code or a negative code outside -32768 to -32000{}",
+                code, context
             ))));
         }
 
@@ -809,6 +1035,153 @@ impl ManifestParser {
         Ok(())
     }
 
+    /// Check every `modelRef` in requests and models names a defined model
+    fn validate_model_references(manifest: &Manifest, file_path: Option<&str>) -> Result<(), JSONRPCError> {
+        let mut request_names = manifest.request_names();
+        request_names.sort();
+        for request_name in request_names {
+            let request_manifest = &manifest.requests.as_ref().unwrap()[request_name];
+            for (arg_name, arg_manifest) in Self::sorted(&request_manifest.args) {
+                let path = format!("requests.{}.args.{}", request_name, arg_name);
+                Self::validate_argument_references(arg_manifest, &path, manifest, file_path)?;
+            }
+
+            let response = &request_manifest.response;
+            let path = format!("requests.{}.response", request_name);
+            if let Some(model_ref) = &response.model_ref {
+                Self::validate_model_reference(model_ref, &path, manifest, file_path)?;
+            }
+            if let Some(items) = &response.items {
+                Self::validate_argument_references(items, &format!("{}.items", path), manifest, file_path)?;
+            }
+            if let Some(properties) = &response.properties {
+                for (prop_name, prop_manifest) in Self::sorted(properties) {
+                    let prop_path = format!("{}.properties.{}", path, prop_name);
+                    Self::validate_argument_references(prop_manifest, &prop_path, manifest, file_path)?;
+                }
+            }
+        }
+
+        if let Some(models) = &manifest.models {
+            for (model_name, model_manifest) in Self::sorted(models) {
+                for (prop_name, prop_manifest) in Self::sorted(&model_manifest.properties) {
+                    let path = format!("models.{}.properties.{}", model_name, prop_name);
+                    Self::validate_argument_references(prop_manifest, &path, manifest, file_path)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_argument_references(
+        arg_manifest: &crate::manifest::ArgumentManifest,
+        path: &str,
+        manifest: &Manifest,
+        file_path: Option<&str>,
+    ) -> Result<(), JSONRPCError> {
+        if let Some(model_ref) = &arg_manifest.model_ref {
+            Self::validate_model_reference(model_ref, path, manifest, file_path)?;
+        }
+        if let Some(items) = &arg_manifest.items {
+            Self::validate_argument_references(items, &format!("{}.items", path), manifest, file_path)?;
+        }
+        if let Some(values) = &arg_manifest.additional_properties {
+            Self::validate_argument_references(values, &format!("{}.additionalProperties", path), manifest, file_path)?;
+        }
+        Ok(())
+    }
+
+    fn validate_model_reference(
+        model_ref: &str,
+        path: &str,
+        manifest: &Manifest,
+        file_path: Option<&str>,
+    ) -> Result<(), JSONRPCError> {
+        if manifest.get_model(model_ref).is_none() {
+            let context = file_path
+                .map(|p| format!(" (file: {})", p))
+                .unwrap_or_default();
+            return Err(JSONRPCError::new(JSONRPCErrorCode::ValidationFailed, Some(format!(
+                "Unresolved model reference '{}' at {}{}",
+                model_ref, path, context
+            ))));
+        }
+        Ok(())
+    }
+
+    /// Reject models that require themselves, directly or through other models.
+    /// Such a cycle has no finite value; cycles through optional, array or map
+    /// properties are fine since they can end in a missing or empty value.
+    pub(crate) fn validate_model_cycles(manifest: &Manifest, file_path: Option<&str>) -> Result<(), JSONRPCError> {
+        let models = match &manifest.models {
+            Some(models) => models,
+            None => return Ok(()),
+        };
+
+        // Edges along required properties holding a model directly
+        let mut edges: HashMap<&str, Vec<(&str, &str)>> = HashMap::new();
+        for (model_name, model_manifest) in Self::sorted(models) {
+            let model_edges = edges.entry(model_name.as_str()).or_default();
+            for (prop_name, prop_manifest) in Self::sorted(&model_manifest.properties) {
+                let is_required = prop_manifest.is_required() || model_manifest.is_property_required(prop_name);
+                if let (true, Some(model_ref)) = (is_required, &prop_manifest.model_ref) {
+                    model_edges.push((prop_name.as_str(), model_ref.as_str()));
+                }
+            }
+        }
+
+        let mut finished: HashSet<&str> = HashSet::new();
+        for (model_name, _) in Self::sorted(models) {
+            let mut path = Vec::new();
+            if let Some(cycle) = Self::find_required_cycle(model_name, &edges, &mut path, &mut finished) {
+                let context = file_path
+                    .map(|p| format!(" (file: {})", p))
+                    .unwrap_or_default();
+                return Err(JSONRPCError::new(JSONRPCErrorCode::ValidationFailed, Some(format!(
+                    "Model '{}' requires itself through {}; make one of these properties optional{}",
+                    cycle[0].split('.').next().unwrap_or_default(),
+                    cycle.join(" -> "),
+                    context
+                ))));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Depth-first search returning the `Model.property` steps of the first cycle found
+    fn find_required_cycle<'a>(
+        model_name: &'a str,
+        edges: &HashMap<&'a str, Vec<(&'a str, &'a str)>>,
+        path: &mut Vec<(&'a str, &'a str)>,
+        finished: &mut HashSet<&'a str>,
+    ) -> Option<Vec<String>> {
+        if finished.contains(model_name) {
+            return None;
+        }
+        if let Some(start) = path.iter().position(|(model, _)| *model == model_name) {
+            return Some(path[start..].iter().map(|(model, prop)| format!("{}.{}", model, prop)).collect());
+        }
+
+        for (prop_name, target) in edges.get(model_name).into_iter().flatten() {
+            path.push((model_name, prop_name));
+            if let Some(cycle) = Self::find_required_cycle(target, edges, path, finished) {
+                return Some(cycle);
+            }
+            path.pop();
+        }
+
+        finished.insert(model_name);
+        None
+    }
+
+    fn sorted<V>(map: &HashMap<String, V>) -> Vec<(&String, &V)> {
+        let mut entries: Vec<(&String, &V)> = map.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        entries
+    }
+
     /// Validate value matches declared type
     fn validate_value_type(
         _arg_name: &str,
@@ -844,15 +1217,21 @@ impl ManifestParser {
 
         info!("Parsing {} Manifest files", file_paths.len());
         
+        // Files share one include tree, so a common include is merged only once
+        let mut loaded = HashSet::new();
+        
         // Parse first file as base
-        let mut base_manifest = Self::from_file(&file_paths[0]).await?;
+        let mut base_manifest = Self::load_file_tree(Path::new(&file_paths[0]), None, &mut Vec::new(), &mut loaded)
+            .await?
+            .unwrap_or_else(|| Manifest::new("1.0.0".to_string()));
         info!("Base manifest loaded from: {}", file_paths[0]);
         
         // Merge additional files
         for file_path in &file_paths[1..] {
             info!("Merging manifest from: {}", file_path);
-            let additional_manifest = Self::from_file(file_path).await?;
-            Self::merge_manifests(&mut base_manifest, &additional_manifest)?;
+            if let Some(additional_manifest) = Self::load_file_tree(Path::new(file_path), None, &mut Vec::new(), &mut loaded).await? {
+                Self::merge_manifests(&mut base_manifest, &additional_manifest)?;
+            }
         }
         
         // Validate merged manifest
@@ -862,11 +1241,43 @@ impl ManifestParser {
         Ok(base_manifest)
     }
 
+    /// Compare two Manifests without checking the version bump
+    pub fn diff(old: &Manifest, new: &Manifest) -> CompatibilityReport {
+        ManifestCompatibility::compare(old, new)
+    }
+
+    /// Compare two Manifests and check that the version bump matches the detected changes
+    pub fn check_compatibility(old: &Manifest, new: &Manifest) -> Result<CompatibilityReport, JSONRPCError> {
+        let report = ManifestCompatibility::compare(old, new);
+        info!(
+            "Manifest {} -> {}: {} changes, {} breaking",
+            report.old_version,
+            report.new_version,
+            report.changes.len(),
+            report.breaking_changes().len()
+        );
+        report.check_version_bump()?;
+        Ok(report)
+    }
+
     /// Merge two Manifests
     pub fn merge_manifests(base: &mut Manifest, additional: &Manifest) -> Result<(), JSONRPCError> {
         info!("Merging Manifests");
         
-        // Channels have been removed from the protocol
+        // Merge requests if present
+        if let Some(additional_requests) = &additional.requests {
+            let base_requests = base.requests.get_or_insert_with(std::collections::HashMap::new);
+            
+            for (request_name, request_manifest) in additional_requests {
+                if base_requests.contains_key(request_name) {
+                    return Err(JSONRPCError::new(JSONRPCErrorCode::ValidationFailed, Some(format!(
+                        "Request '{}' already exists in base manifest", 
+                        request_name
+                    ))));
+                }
+                base_requests.insert(request_name.clone(), request_manifest.clone());
+            }
+        }
         
         // Merge models if present
         if let Some(additional_models) = &additional.models {
@@ -930,7 +1341,7 @@ impl ManifestParser {
 
     /// Static method for YAML serialization
     #[cfg(feature = "yaml-support")]
-    pub fn serialize_to_yaml(manifest: &Manifest) -> Result<String, JanusError> {
+    pub fn serialize_to_yaml(manifest: &Manifest) -> Result<String, JSONRPCError> {
         Self::to_yaml(manifest)
     }
 }
@@ -0,0 +1,538 @@
+/*!
+ * Manifest Schema derivation for Rust Janus Implementation
+ * Derives ArgumentManifest, ResponseManifest and ModelManifest definitions from Rust types
+ */
+
+use crate::manifest::model_registry::{ArgumentManifest, Manifest, ModelManifest, RequestManifest, ResponseManifest};
+use std::collections::{BTreeMap, HashMap};
+
+/// Types that can describe themselves as Manifest arguments and properties
+pub trait ManifestSchema {
+    /// Argument manifest describing values of this type
+    fn argument_manifest() -> ArgumentManifest;
+
+    /// Add the models this type refers to, keyed by model name
+    fn collect_models(_models: &mut HashMap<String, ModelManifest>) {}
+
+    /// Response manifest describing results of this type
+    fn response_manifest() -> ResponseManifest {
+        let argument = Self::argument_manifest();
+        ResponseManifest {
+            r#type: argument.r#type,
+            properties: None,
+            model_ref: argument.model_ref,
+            items: argument.items,
+        }
+    }
+
+    /// Model whose properties are merged into the containing model of a `#[serde(flatten)]` field,
+    /// None for types that cannot be flattened
+    fn flattened_model() -> Option<ModelManifest> {
+        None
+    }
+
+    /// Add the models a `#[serde(flatten)]` field of this type refers to
+    fn collect_flattened_models(models: &mut HashMap<String, ModelManifest>) {
+        Self::collect_models(models);
+    }
+}
+
+/// Argument manifest of a nested value, where presence is decided by the container
+fn element_manifest<T: ManifestSchema>() -> ArgumentManifest {
+    let mut element = T::argument_manifest();
+    element.required = None;
+    element
+}
+
+/// Object types published as named Manifest models.
+/// Usually implemented through the `manifest_model!` macro.
+pub trait ManifestModel: ManifestSchema {
+    /// Model name used in `modelRef`
+    const NAME: &'static str;
+
+    /// Model definition with one property per field
+    fn model_manifest() -> ModelManifest;
+
+    /// Add the models referenced by the fields of this model
+    fn collect_field_models(models: &mut HashMap<String, ModelManifest>);
+}
+
+/// Add a model and the models it refers to, stopping at models already collected
+pub fn collect_model<T: ManifestModel>(models: &mut HashMap<String, ModelManifest>) {
+    if models.contains_key(T::NAME) {
+        return;
+    }
+    models.insert(T::NAME.to_string(), T::model_manifest());
+    T::collect_field_models(models);
+}
+
+/// Build a RequestManifest whose arguments are the fields of `A` and whose response is `R`.
+/// Models referenced by either type are added to `models`.
+pub fn typed_request_manifest<A: ManifestModel, R: ManifestSchema>(
+    description: &str,
+    models: &mut HashMap<String, ModelManifest>,
+) -> RequestManifest {
+    let mut request_manifest = RequestManifest::new(description.to_string(), R::response_manifest());
+    request_manifest.args = A::model_manifest().properties;
+
+    A::collect_field_models(models);
+    R::collect_models(models);
+
+    request_manifest
+}
+
+impl Manifest {
+    /// Add a request derived from its argument and result types, including referenced models
+    pub fn add_typed_request<A: ManifestModel, R: ManifestSchema>(&mut self, name: &str, description: &str) {
+        let mut models = HashMap::new();
+        let request_manifest = typed_request_manifest::<A, R>(description, &mut models);
+
+        self.add_request(name.to_string(), request_manifest);
+        for (model_name, model) in models {
+            if self.get_model(&model_name).is_none() {
+                self.add_model(model_name, model);
+            }
+        }
+    }
+}
+
+macro_rules! impl_primitive_schema {
+    ($type_name:literal => $($ty:ty),+) => {
+        $(
+            impl ManifestSchema for $ty {
+                fn argument_manifest() -> ArgumentManifest {
+                    ArgumentManifest::new($type_name.to_string()).required()
+                }
+            }
+        )+
+    };
+}
+
+impl_primitive_schema!("string" => String, char);
+impl_primitive_schema!("boolean" => bool);
+impl_primitive_schema!("integer" => i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_primitive_schema!("number" => f32, f64);
+
+/// Requests without arguments take `()`, which accepts an empty argument object
+impl ManifestSchema for () {
+    fn argument_manifest() -> ArgumentManifest {
+        ArgumentManifest::new("object".to_string()).required()
+    }
+}
+
+impl ManifestModel for () {
+    const NAME: &'static str = "Unit";
+
+    fn model_manifest() -> ModelManifest {
+        ModelManifest::new()
+    }
+
+    fn collect_field_models(_models: &mut HashMap<String, ModelManifest>) {}
+}
+
+/// Free-form JSON is published as an object without declared properties
+impl ManifestSchema for serde_json::Value {
+    fn argument_manifest() -> ArgumentManifest {
+        ArgumentManifest::new("object".to_string()).required()
+    }
+}
+
+impl<T: ManifestSchema> ManifestSchema for Option<T> {
+    fn argument_manifest() -> ArgumentManifest {
+        T::argument_manifest().optional()
+    }
+
+    fn collect_models(models: &mut HashMap<String, ModelManifest>) {
+        T::collect_models(models);
+    }
+
+    fn flattened_model() -> Option<ModelManifest> {
+        let mut model = T::flattened_model()?;
+        model.required = None;
+        for property in model.properties.values_mut() {
+            property.required = Some(false);
+        }
+        Some(model)
+    }
+
+    fn collect_flattened_models(models: &mut HashMap<String, ModelManifest>) {
+        T::collect_flattened_models(models);
+    }
+}
+
+impl<T: ManifestSchema> ManifestSchema for Box<T> {
+    fn argument_manifest() -> ArgumentManifest {
+        T::argument_manifest()
+    }
+
+    fn collect_models(models: &mut HashMap<String, ModelManifest>) {
+        T::collect_models(models);
+    }
+
+    fn flattened_model() -> Option<ModelManifest> {
+        T::flattened_model()
+    }
+
+    fn collect_flattened_models(models: &mut HashMap<String, ModelManifest>) {
+        T::collect_flattened_models(models);
+    }
+}
+
+impl<T: ManifestSchema> ManifestSchema for Vec<T> {
+    fn argument_manifest() -> ArgumentManifest {
+        ArgumentManifest::new("array".to_string())
+            .required()
+            .with_items(element_manifest::<T>())
+    }
+
+    fn collect_models(models: &mut HashMap<String, ModelManifest>) {
+        T::collect_models(models);
+    }
+}
+
+impl<T: ManifestSchema> ManifestSchema for HashMap<String, T> {
+    fn argument_manifest() -> ArgumentManifest {
+        ArgumentManifest::new("object".to_string())
+            .required()
+            .with_additional_properties(element_manifest::<T>())
+    }
+
+    fn collect_models(models: &mut HashMap<String, ModelManifest>) {
+        T::collect_models(models);
+    }
+
+    fn flattened_model() -> Option<ModelManifest> {
+        Some(ModelManifest::new())
+    }
+}
+
+impl<T: ManifestSchema> ManifestSchema for BTreeMap<String, T> {
+    fn argument_manifest() -> ArgumentManifest {
+        ArgumentManifest::new("object".to_string())
+            .required()
+            .with_additional_properties(element_manifest::<T>())
+    }
+
+    fn collect_models(models: &mut HashMap<String, ModelManifest>) {
+        T::collect_models(models);
+    }
+
+    fn flattened_model() -> Option<ModelManifest> {
+        Some(ModelManifest::new())
+    }
+}
+
+/// Items of a `serde(...)` attribute as written in `manifest_model!`, None for other attributes
+fn serde_items(attribute: &str) -> Option<Vec<&str>> {
+    let inner = attribute.trim().strip_prefix("serde")?.trim_start().strip_prefix('(')?.strip_suffix(')')?;
+
+    let mut items = Vec::new();
+    let (mut depth, mut quoted, mut start) = (0, false, 0);
+    for (index, ch) in inner.char_indices() {
+        match ch {
+            '"' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted => depth -= 1,
+            ',' if !quoted && depth == 0 => {
+                items.push(inner[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(inner[start..].trim());
+    Some(items)
+}
+
+/// Check whether the serde attributes contain the item `key` or `key = ...`
+fn serde_flag(attributes: &[&str], keys: &[&str]) -> bool {
+    attributes
+        .iter()
+        .filter_map(|attribute| serde_items(attribute))
+        .flatten()
+        .any(|item| keys.contains(&item.split_once('=').map_or(item, |(name, _)| name).trim()))
+}
+
+/// Value of the `key = "value"` item in the serde attributes
+fn serde_option(attributes: &[&str], key: &str) -> Option<String> {
+    attributes
+        .iter()
+        .filter_map(|attribute| serde_items(attribute))
+        .flatten()
+        .filter_map(|item| item.split_once('='))
+        .filter(|(name, _)| name.trim() == key)
+        .filter_map(|(_, value)| value.trim().strip_prefix('"')?.strip_suffix('"').map(str::to_string))
+        .next()
+}
+
+/// Apply a serde `rename_all` rule to a snake_case field name, as serde does for struct fields
+fn apply_rename_rule(rule: &str, field: &str) -> String {
+    let pascal = || {
+        let mut pascal = String::new();
+        let mut capitalize = true;
+        for ch in field.chars() {
+            if ch == '_' {
+                capitalize = true;
+            } else if capitalize {
+                pascal.push(ch.to_ascii_uppercase());
+                capitalize = false;
+            } else {
+                pascal.push(ch);
+            }
+        }
+        pascal
+    };
+
+    match rule {
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field.to_ascii_uppercase(),
+        "PascalCase" => pascal(),
+        "camelCase" => {
+            let pascal = pascal();
+            let mut chars = pascal.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                None => pascal,
+            }
+        }
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.to_ascii_uppercase().replace('_', "-"),
+        _ => field.to_string(),
+    }
+}
+
+/// Wire name of a `manifest_model!` field, following serde `rename` on the field
+/// and `rename_all` on the struct. Used by the macro expansion.
+#[doc(hidden)]
+pub fn property_name(field: &str, field_attributes: &[&str], struct_attributes: &[&str]) -> String {
+    let field = field.strip_prefix("r#").unwrap_or(field);
+    if let Some(name) = serde_option(field_attributes, "rename") {
+        return name;
+    }
+    match serde_option(struct_attributes, "rename_all") {
+        Some(rule) => apply_rename_rule(&rule, field),
+        None => field.to_string(),
+    }
+}
+
+/// Check whether serde leaves a `manifest_model!` field out of the wire format.
+/// Used by the macro expansion.
+#[doc(hidden)]
+pub fn is_skipped(field_attributes: &[&str]) -> bool {
+    serde_flag(field_attributes, &["skip", "skip_serializing", "skip_deserializing"])
+}
+
+/// Check whether serde merges the fields of a `manifest_model!` field into its container.
+/// Used by the macro expansion.
+#[doc(hidden)]
+pub fn is_flattened(field_attributes: &[&str]) -> bool {
+    serde_flag(field_attributes, &["flatten"])
+}
+
+/// Check whether serde fills in a missing `manifest_model!` field, through `default`
+/// on the field or on the struct. Used by the macro expansion.
+#[doc(hidden)]
+pub fn has_default(field_attributes: &[&str], struct_attributes: &[&str]) -> bool {
+    serde_flag(field_attributes, &["default"]) || serde_flag(struct_attributes, &["default"])
+}
+
+/// Merge the model of a `#[serde(flatten)]` field into its containing model.
+/// Used by the macro expansion.
+#[doc(hidden)]
+pub fn merge_flattened(model: &mut ModelManifest, flattened: Option<ModelManifest>, field: &str, container: &str) {
+    let flattened = flattened.unwrap_or_else(|| {
+        panic!("Field '{}' of {} is flattened, but its type is not a manifest_model! struct or map", field, container)
+    });
+    for required in flattened.required_properties() {
+        model.add_required(required.clone());
+    }
+    model.properties.extend(flattened.properties);
+}
+
+/// Check whether a serde attribute gives separate serialize and deserialize names,
+/// which a single Manifest model cannot describe. Used by the macro expansion.
+#[doc(hidden)]
+pub const fn has_directional_rename(attribute: &str) -> bool {
+    const fn is_ident_byte(byte: u8) -> bool {
+        byte.is_ascii_alphanumeric() || byte == b'_'
+    }
+    const fn starts_with_at(bytes: &[u8], index: usize, prefix: &[u8]) -> bool {
+        if index + prefix.len() > bytes.len() {
+            return false;
+        }
+        let mut offset = 0;
+        while offset < prefix.len() {
+            if bytes[index + offset] != prefix[offset] {
+                return false;
+            }
+            offset += 1;
+        }
+        true
+    }
+
+    let bytes = attribute.as_bytes();
+    if !starts_with_at(bytes, 0, b"serde") {
+        return false;
+    }
+    let mut quoted = false;
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'"' {
+            quoted = !quoted;
+        } else if !quoted && starts_with_at(bytes, index, b"rename") && (index == 0 || !is_ident_byte(bytes[index - 1])) {
+            let mut next = index + b"rename".len();
+            if starts_with_at(bytes, next, b"_all") {
+                next += b"_all".len();
+            }
+            while next < bytes.len() && bytes[next] == b' ' {
+                next += 1;
+            }
+            if next < bytes.len() && bytes[next] == b'(' {
+                return true;
+            }
+        }
+        index += 1;
+    }
+    false
+}
+
+/// Declare a struct and derive its Manifest model from the field types.
+///
+/// Fields typed `Option<T>` or marked `#[serde(default)]` become optional properties,
+/// every other field is required. Property names follow serde `rename` on fields and
+/// `rename_all` on the struct, so the model matches the wire format. Fields marked
+/// `skip`, `skip_serializing` or `skip_deserializing` are left out, and the properties of
+/// `flatten` fields, which must be `manifest_model!` structs or maps, are merged in.
+/// Renames that differ between serialization and deserialization cannot be described
+/// by one model and fail to compile.
+///
+/// ```
+/// use rust_janus::manifest_model;
+/// use serde::{Deserialize, Serialize};
+///
+/// manifest_model! {
+///     #[derive(Debug, Serialize, Deserialize)]
+///     pub struct User {
+///         pub id: String,
+///         pub email: Option<String>,
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use rust_janus::manifest_model;
+/// use serde::{Deserialize, Serialize};
+///
+/// manifest_model! {
+///     #[derive(Debug, Serialize, Deserialize)]
+///     pub struct User {
+///         #[serde(rename(serialize = "userId", deserialize = "user_id"))]
+///         pub id: String,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! manifest_model {
+    (
+        $(#[$($meta:tt)*])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$($field_meta:tt)*])*
+                $field_vis:vis $field:ident : $field_ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$($meta)*])*
+        $vis struct $name {
+            $(
+                $(#[$($field_meta)*])*
+                $field_vis $field: $field_ty,
+            )*
+        }
+
+        $(
+            const _: () = assert!(
+                !$crate::manifest::manifest_schema::has_directional_rename(stringify!($($meta)*)),
+                "manifest_model! cannot describe separate serialize and deserialize names",
+            );
+        )*
+        $($(
+            const _: () = assert!(
+                !$crate::manifest::manifest_schema::has_directional_rename(stringify!($($field_meta)*)),
+                "manifest_model! cannot describe separate serialize and deserialize names",
+            );
+        )*)*
+
+        impl $crate::manifest::ManifestSchema for $name {
+            fn argument_manifest() -> $crate::manifest::ArgumentManifest {
+                $crate::manifest::ArgumentManifest::new("object".to_string())
+                    .required()
+                    .with_model_ref(stringify!($name).to_string())
+            }
+
+            fn collect_models(
+                models: &mut ::std::collections::HashMap<String, $crate::manifest::ModelManifest>,
+            ) {
+                $crate::manifest::manifest_schema::collect_model::<Self>(models);
+            }
+
+            fn flattened_model() -> Option<$crate::manifest::ModelManifest> {
+                Some(<Self as $crate::manifest::ManifestModel>::model_manifest())
+            }
+
+            fn collect_flattened_models(
+                models: &mut ::std::collections::HashMap<String, $crate::manifest::ModelManifest>,
+            ) {
+                <Self as $crate::manifest::ManifestModel>::collect_field_models(models);
+            }
+        }
+
+        impl $crate::manifest::ManifestModel for $name {
+            const NAME: &'static str = stringify!($name);
+
+            fn model_manifest() -> $crate::manifest::ModelManifest {
+                #[allow(unused_mut)]
+                let mut model = $crate::manifest::ModelManifest::new();
+                let _struct_attributes: &[&str] = &[$(stringify!($($meta)*)),*];
+                $(
+                    let field_attributes: &[&str] = &[$(stringify!($($field_meta)*)),*];
+                    if $crate::manifest::manifest_schema::is_flattened(field_attributes) {
+                        $crate::manifest::manifest_schema::merge_flattened(
+                            &mut model,
+                            <$field_ty as $crate::manifest::ManifestSchema>::flattened_model(),
+                            stringify!($field),
+                            stringify!($name),
+                        );
+                    } else if !$crate::manifest::manifest_schema::is_skipped(field_attributes) {
+                        let name = $crate::manifest::manifest_schema::property_name(
+                            stringify!($field),
+                            field_attributes,
+                            _struct_attributes,
+                        );
+                        let mut property = <$field_ty as $crate::manifest::ManifestSchema>::argument_manifest();
+                        if $crate::manifest::manifest_schema::has_default(field_attributes, _struct_attributes) {
+                            property = property.optional();
+                        }
+                        if property.is_required() {
+                            model.add_required(name.clone());
+                        }
+                        model.add_property(name, property);
+                    }
+                )*
+                model
+            }
+
+            fn collect_field_models(
+                _models: &mut ::std::collections::HashMap<String, $crate::manifest::ModelManifest>,
+            ) {
+                $(
+                    let field_attributes: &[&str] = &[$(stringify!($($field_meta)*)),*];
+                    if $crate::manifest::manifest_schema::is_flattened(field_attributes) {
+                        <$field_ty as $crate::manifest::ManifestSchema>::collect_flattened_models(_models);
+                    } else if !$crate::manifest::manifest_schema::is_skipped(field_attributes) {
+                        <$field_ty as $crate::manifest::ManifestSchema>::collect_models(_models);
+                    }
+                )*
+            }
+        }
+    };
+}
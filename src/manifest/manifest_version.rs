@@ -0,0 +1,270 @@
+/*!
+ * Manifest Version for Rust Janus Implementation
+ * Semantic version parsing, comparison and requirement matching for Manifest versions
+ */
+
+use crate::error::{JSONRPCError, JSONRPCErrorCode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Semantic version of a Manifest (`MAJOR.MINOR.PATCH`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ManifestVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+/// Size of the step between two Manifest versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionBump {
+    None,
+    Patch,
+    Minor,
+    Major,
+}
+
+/// Range of Manifest versions a client was built against, e.g. `^1.2`, `>=1.0.0, <3.0.0` or `*`.
+/// A bare version behaves like a caret requirement, as in Cargo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionRequirement {
+    source: String,
+    comparators: Vec<Comparator>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ComparatorOp {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Comparator {
+    op: ComparatorOp,
+    version: ManifestVersion,
+}
+
+impl ManifestVersion {
+    /// Create a new version
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// Parse a `MAJOR.MINOR.PATCH` version string
+    pub fn parse(version: &str) -> Result<Self, JSONRPCError> {
+        let invalid = || {
+            JSONRPCError::new(
+                JSONRPCErrorCode::ManifestValidationError,
+                Some(format!("Invalid manifest version '{}', expected MAJOR.MINOR.PATCH", version)),
+            )
+        };
+
+        let parts: Vec<&str> = version.trim().split('.').collect();
+        if parts.len() != 3 {
+            return Err(invalid());
+        }
+
+        let mut numbers = [0u64; 3];
+        for (number, part) in numbers.iter_mut().zip(&parts) {
+            if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            *number = part.parse().map_err(|_| invalid())?;
+        }
+
+        Ok(Self::new(numbers[0], numbers[1], numbers[2]))
+    }
+
+    /// Get the bump from `previous` to this version, or None for a downgrade
+    pub fn bump_from(&self, previous: &ManifestVersion) -> Option<VersionBump> {
+        if self < previous {
+            None
+        } else if self.major > previous.major {
+            Some(VersionBump::Major)
+        } else if self.minor > previous.minor {
+            Some(VersionBump::Minor)
+        } else if self.patch > previous.patch {
+            Some(VersionBump::Patch)
+        } else {
+            Some(VersionBump::None)
+        }
+    }
+}
+
+impl fmt::Display for ManifestVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for ManifestVersion {
+    type Err = JSONRPCError;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        Self::parse(version)
+    }
+}
+
+impl VersionRequirement {
+    /// Parse a comma-separated list of comparators; all of them must match
+    pub fn parse(requirement: &str) -> Result<Self, JSONRPCError> {
+        let invalid = |reason: &str| {
+            JSONRPCError::new(
+                JSONRPCErrorCode::ConfigurationError,
+                Some(format!("Invalid manifest version requirement '{}': {}", requirement, reason)),
+            )
+        };
+
+        let mut comparators = Vec::new();
+        for part in requirement.split(',').map(str::trim) {
+            if part.is_empty() {
+                return Err(invalid("empty comparator"));
+            }
+            if part == "*" {
+                continue;
+            }
+
+            let (operator, version) = match part.find(|c: char| c.is_ascii_digit()) {
+                Some(index) => (part[..index].trim(), part[index..].trim()),
+                None => return Err(invalid(&format!("comparator '{}' has no version", part))),
+            };
+
+            let numbers: Vec<u64> = version
+                .split('.')
+                .map(|number| number.parse::<u64>().map_err(|_| invalid(&format!("'{}' is not a version", version))))
+                .collect::<Result<_, _>>()?;
+            if numbers.len() > 3 {
+                return Err(invalid(&format!("'{}' is not a version", version)));
+            }
+
+            let lower = ManifestVersion::new(
+                numbers[0],
+                numbers.get(1).copied().unwrap_or(0),
+                numbers.get(2).copied().unwrap_or(0),
+            );
+
+            match operator {
+                "" | "^" => comparators.extend(Self::caret(lower, numbers.len())),
+                "~" => comparators.extend(Self::tilde(lower, numbers.len())),
+                "=" if numbers.len() == 3 => comparators.push(Comparator { op: ComparatorOp::Exact, version: lower }),
+                "=" => comparators.extend(Self::tilde(lower, numbers.len())),
+                ">" => comparators.push(Comparator { op: ComparatorOp::Greater, version: lower }),
+                ">=" => comparators.push(Comparator { op: ComparatorOp::GreaterEq, version: lower }),
+                "<" => comparators.push(Comparator { op: ComparatorOp::Less, version: lower }),
+                "<=" => comparators.push(Comparator { op: ComparatorOp::LessEq, version: lower }),
+                _ => return Err(invalid(&format!("unknown operator '{}'", operator))),
+            }
+        }
+
+        Ok(Self {
+            source: requirement.trim().to_string(),
+            comparators,
+        })
+    }
+
+    /// Requirement matching every version
+    pub fn any() -> Self {
+        Self {
+            source: "*".to_string(),
+            comparators: Vec::new(),
+        }
+    }
+
+    /// Check whether a version satisfies the requirement
+    pub fn matches(&self, version: &ManifestVersion) -> bool {
+        self.comparators.iter().all(|comparator| match comparator.op {
+            ComparatorOp::Exact => version == &comparator.version,
+            ComparatorOp::Greater => version > &comparator.version,
+            ComparatorOp::GreaterEq => version >= &comparator.version,
+            ComparatorOp::Less => version < &comparator.version,
+            ComparatorOp::LessEq => version <= &comparator.version,
+        })
+    }
+
+    /// Accept a Manifest version string, or fail with `IncompatibleManifestVersion`
+    pub fn negotiate(&self, manifest_version: &str) -> Result<ManifestVersion, JSONRPCError> {
+        let incompatible = |details: String| {
+            let mut context = HashMap::new();
+            context.insert("manifestVersion".to_string(), serde_json::json!(manifest_version));
+            context.insert("requirement".to_string(), serde_json::json!(self.source));
+            JSONRPCError::with_context(JSONRPCErrorCode::IncompatibleManifestVersion, Some(details), context)
+        };
+
+        let version = ManifestVersion::parse(manifest_version).map_err(|_| {
+            incompatible(format!(
+                "Manifest version '{}' is not a semantic version and cannot satisfy '{}'",
+                manifest_version, self.source
+            ))
+        })?;
+
+        if !self.matches(&version) {
+            return Err(incompatible(format!(
+                "Manifest version {} does not satisfy requirement '{}'",
+                version, self.source
+            )));
+        }
+
+        Ok(version)
+    }
+
+    /// `^1.2.3` := `>=1.2.3, <2.0.0`, `^0.2.3` := `>=0.2.3, <0.3.0`, `^0.0.3` := `>=0.0.3, <0.0.4`
+    fn caret(lower: ManifestVersion, components: usize) -> Vec<Comparator> {
+        let upper = if lower.major > 0 || components == 1 {
+            ManifestVersion::new(lower.major + 1, 0, 0)
+        } else if lower.minor > 0 || components == 2 {
+            ManifestVersion::new(0, lower.minor + 1, 0)
+        } else {
+            ManifestVersion::new(0, 0, lower.patch + 1)
+        };
+        Self::range(lower, upper)
+    }
+
+    /// `~1.2.3` := `>=1.2.3, <1.3.0`, `~1` := `>=1.0.0, <2.0.0`
+    fn tilde(lower: ManifestVersion, components: usize) -> Vec<Comparator> {
+        let upper = if components == 1 {
+            ManifestVersion::new(lower.major + 1, 0, 0)
+        } else {
+            ManifestVersion::new(lower.major, lower.minor + 1, 0)
+        };
+        Self::range(lower, upper)
+    }
+
+    fn range(lower: ManifestVersion, upper: ManifestVersion) -> Vec<Comparator> {
+        vec![
+            Comparator { op: ComparatorOp::GreaterEq, version: lower },
+            Comparator { op: ComparatorOp::Less, version: upper },
+        ]
+    }
+}
+
+impl fmt::Display for VersionRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl FromStr for VersionRequirement {
+    type Err = JSONRPCError;
+
+    fn from_str(requirement: &str) -> Result<Self, Self::Err> {
+        Self::parse(requirement)
+    }
+}
+
+impl fmt::Display for VersionBump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            VersionBump::None => "none",
+            VersionBump::Patch => "patch",
+            VersionBump::Minor => "minor",
+            VersionBump::Major => "major",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -3,12 +3,31 @@ pub mod validation_engine;
 pub mod model_registry;
 pub mod argument_validator;
 pub mod response_validator;
+pub mod manifest_schema;
+pub mod json_schema;
+pub mod openrpc;
+pub mod manifest_version;
+pub mod compatibility;
+pub mod codegen;
+pub mod manifest_linter;
+pub mod string_format;
+pub mod validator_registry;
 
 pub use manifest_parser::ManifestParser;
 pub use validation_engine::ValidationEngine;
 pub use model_registry::{
     Manifest, RequestManifest, ArgumentManifest, 
-    ValidationManifest, ResponseManifest, ErrorCodeManifest, ModelManifest
+    ValidationManifest, ResponseManifest, ErrorCodeManifest, ModelManifest, DeprecationWarning,
+    DeclaredError
 };
-pub use argument_validator::ArgumentValidator;
-pub use response_validator::{ResponseValidator, ValidationResult, ValidationError};
\ No newline at end of file
+pub use argument_validator::{ArgumentCoercion, ArgumentValidator, CoercionMode};
+pub use response_validator::{ResponseValidator, ResponseValidationMode, ValidationResult, ValidationError};
+pub use manifest_schema::{ManifestSchema, ManifestModel, typed_request_manifest};
+pub use json_schema::{JsonSchemaExporter, JsonSchemaImporter};
+pub use openrpc::OpenRpcGenerator;
+pub use manifest_version::{ManifestVersion, VersionBump, VersionRequirement};
+pub use compatibility::{ManifestCompatibility, CompatibilityReport, ManifestChange, ChangeSeverity};
+pub use codegen::{RustCodeGenerator, RustCodegenOptions};
+pub use manifest_linter::{ManifestLinter, LintReport, LintIssue, LintSeverity};
+pub use string_format::StringFormat;
+pub use validator_registry::{CustomValidator, ValidatorRegistry};
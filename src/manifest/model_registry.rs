@@ -1,3 +1,4 @@
+use crate::manifest::string_format::StringFormat;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -7,8 +8,15 @@ pub struct Manifest {
     /// API version
     pub version: String,
     
+    /// Request definitions keyed by request name (optional)
+    pub requests: Option<HashMap<String, RequestManifest>>,
+    
     /// Model definitions (optional)
     pub models: Option<HashMap<String, ModelManifest>>,
+    
+    /// Manifest files merged into this one, relative to this file (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include: Option<Vec<String>>,
 }
 
 impl Manifest {
@@ -16,7 +24,9 @@ impl Manifest {
     pub fn new(version: String) -> Self {
         Self {
             version,
+            requests: None,
             models: None,
+            include: None,
         }
     }
     
@@ -25,6 +35,19 @@ impl Manifest {
         crate::manifest::ManifestParser::from_file(path).await
     }
     
+    /// Content fingerprint of the Manifest, independent of map ordering.
+    /// A 64-bit FNV-1a hash of the canonical JSON form, as 16 hex digits.
+    pub fn fingerprint(&self) -> String {
+        // Value maps are sorted, so equal Manifests serialize identically
+        let canonical = serde_json::to_value(self)
+            .map(|value| value.to_string())
+            .unwrap_or_default();
+        let hash = canonical.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+        format!("{:016x}", hash)
+    }
+    
     /// Add a model to the manifest
     pub fn add_model(&mut self, name: String, model: ModelManifest) {
         if self.models.is_none() {
@@ -38,18 +61,29 @@ impl Manifest {
         self.models.as_ref()?.get(name)
     }
     
-    /// Check if request exists (channels removed from protocol)
-    pub fn has_request(&self, _request_name: &str) -> bool {
-        // Since channels are removed, this always returns false for now
-        // The server will handle request validation
-        false
+    /// Add a request definition to the manifest
+    pub fn add_request(&mut self, name: String, request: RequestManifest) {
+        if self.requests.is_none() {
+            self.requests = Some(HashMap::new());
+        }
+        self.requests.as_mut().unwrap().insert(name, request);
+    }
+    
+    /// Check if request exists
+    pub fn has_request(&self, request_name: &str) -> bool {
+        self.get_request_manifest(request_name).is_some()
     }
     
-    /// Get request manifest (channels removed from protocol)
-    pub fn get_request_manifest(&self, _request_name: &str) -> Option<&RequestManifest> {
-        // Since channels are removed, this always returns None
-        // The server will handle request validation
-        None
+    /// Get request manifest by name
+    pub fn get_request_manifest(&self, request_name: &str) -> Option<&RequestManifest> {
+        self.requests.as_ref()?.get(request_name)
+    }
+    
+    /// Get the names of all defined requests
+    pub fn request_names(&self) -> Vec<&String> {
+        self.requests.as_ref()
+            .map(|requests| requests.keys().collect())
+            .unwrap_or_default()
     }
 }
 
@@ -73,6 +107,18 @@ pub struct RequestManifest {
     
     /// Error code definitions (optional)
     pub error_codes: Option<HashMap<String, ErrorCodeManifest>>,
+    
+    /// Whether the request is being retired (optional, default: false)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<bool>,
+    
+    /// Request callers should move to (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+    
+    /// Manifest version that removes the request (optional)
+    #[serde(rename = "removalVersion", alias = "removal_version", default, skip_serializing_if = "Option::is_none")]
+    pub removal_version: Option<String>,
 }
 
 impl RequestManifest {
@@ -84,9 +130,25 @@ impl RequestManifest {
             args: HashMap::new(),
             response,
             error_codes: None,
+            deprecated: None,
+            replacement: None,
+            removal_version: None,
         }
     }
     
+    /// Mark the request as deprecated, with an optional replacement and removal version
+    pub fn with_deprecation(mut self, replacement: Option<String>, removal_version: Option<String>) -> Self {
+        self.deprecated = Some(true);
+        self.replacement = replacement;
+        self.removal_version = removal_version;
+        self
+    }
+    
+    /// Check if request is deprecated
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated.unwrap_or(false)
+    }
+    
     /// Add an argument to the request
     pub fn add_argument(&mut self, name: String, arg_manifest: ArgumentManifest) {
         self.args.insert(name, arg_manifest);
@@ -100,6 +162,35 @@ impl RequestManifest {
         self.error_codes.as_mut().unwrap().insert(name, error_manifest);
     }
     
+    /// Get a declared error code by name
+    pub fn get_error_code(&self, name: &str) -> Option<&ErrorCodeManifest> {
+        self.error_codes.as_ref()?.get(name)
+    }
+    
+    /// Find the declared error using a numeric code, preferring the first name in sorted order
+    pub fn find_error_code(&self, code: i32) -> Option<(&String, &ErrorCodeManifest)> {
+        self.error_codes.as_ref()?
+            .iter()
+            .filter(|(_, error_manifest)| error_manifest.code == code)
+            .min_by_key(|(name, _)| *name)
+    }
+    
+    /// Match an error returned for this request to the error code it declares,
+    /// by the error name it carries or else by its numeric code
+    pub fn declared_error(&self, error: &crate::error::JSONRPCError) -> Option<DeclaredError> {
+        let (name, error_manifest) = match error.error_name() {
+            Some(name) => self.error_codes.as_ref()?.get_key_value(name)?,
+            None => self.find_error_code(error.code)?,
+        };
+        Some(DeclaredError {
+            name: name.clone(),
+            code: error_manifest.code,
+            message: error_manifest.message.clone(),
+            description: error_manifest.description.clone(),
+            details: error.data.as_ref().and_then(|data| data.details.clone()).filter(|details| !details.is_empty()),
+        })
+    }
+    
     /// Get argument manifest
     pub fn get_argument(&self, name: &str) -> Option<&ArgumentManifest> {
         self.args.get(name)
@@ -120,6 +211,120 @@ impl RequestManifest {
             .map(|(name, _)| name)
             .collect()
     }
+
+    /// Fill in the default value of every omitted optional argument.
+    /// Returns the names of the arguments that were filled in, sorted.
+    pub fn apply_defaults(&self, args: &mut Option<HashMap<String, serde_json::Value>>) -> Vec<String> {
+        let mut applied: Vec<String> = self.args.iter()
+            .filter(|(name, manifest)| {
+                !manifest.is_required() && manifest.has_default()
+                    && !args.as_ref().is_some_and(|args| args.contains_key(*name))
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+        if applied.is_empty() {
+            return applied;
+        }
+        applied.sort();
+
+        let args = args.get_or_insert_with(HashMap::new);
+        for name in &applied {
+            if let Some(default_value) = &self.args[name].default_value {
+                args.insert(name.clone(), default_value.clone());
+            }
+        }
+        applied
+    }
+
+    /// Warnings for a call of this request: the request itself when deprecated,
+    /// then every deprecated argument the caller supplied, sorted by name.
+    pub fn deprecation_warnings(
+        &self,
+        request_name: &str,
+        args: Option<&HashMap<String, serde_json::Value>>,
+    ) -> Vec<DeprecationWarning> {
+        let mut warnings = Vec::new();
+        if self.is_deprecated() {
+            warnings.push(DeprecationWarning::new(
+                request_name,
+                None,
+                self.replacement.clone(),
+                self.removal_version.clone(),
+            ));
+        }
+
+        let mut used: Vec<&String> = args
+            .map(|args| args.keys().filter(|name| self.args.get(*name).is_some_and(|arg| arg.is_deprecated())).collect())
+            .unwrap_or_default();
+        used.sort();
+        for name in used {
+            let arg_manifest = &self.args[name];
+            warnings.push(DeprecationWarning::new(
+                request_name,
+                Some(name.clone()),
+                arg_manifest.replacement.clone(),
+                arg_manifest.removal_version.clone(),
+            ));
+        }
+        warnings
+    }
+}
+
+/// Use of a deprecated request or argument, reported back to the caller in the response
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeprecationWarning {
+    /// Request name
+    pub request: String,
+    
+    /// Argument name, unset when the request itself is deprecated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub argument: Option<String>,
+    
+    /// Request or argument callers should move to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+    
+    /// Manifest version that removes the request or argument
+    #[serde(rename = "removalVersion", alias = "removal_version", default, skip_serializing_if = "Option::is_none")]
+    pub removal_version: Option<String>,
+    
+    /// Human-readable summary
+    pub message: String,
+}
+
+impl DeprecationWarning {
+    /// Create a warning, deriving its message from the other fields
+    pub fn new(
+        request: &str,
+        argument: Option<String>,
+        replacement: Option<String>,
+        removal_version: Option<String>,
+    ) -> Self {
+        let mut message = match &argument {
+            Some(argument) => format!("Argument '{}' of request '{}' is deprecated", argument, request),
+            None => format!("Request '{}' is deprecated", request),
+        };
+        if let Some(removal_version) = &removal_version {
+            message.push_str(&format!(" and will be removed in version {}", removal_version));
+        }
+        if let Some(replacement) = &replacement {
+            message.push_str(&format!(", use '{}' instead", replacement));
+        }
+        
+        Self {
+            request: request.to_string(),
+            argument,
+            replacement,
+            removal_version,
+            message,
+        }
+    }
+}
+
+impl std::fmt::Display for DeprecationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
 }
 
 /// Argument manifest
@@ -143,6 +348,26 @@ pub struct ArgumentManifest {
     /// Model reference for complex types (optional)
     #[serde(rename = "modelRef")]
     pub model_ref: Option<String>,
+    
+    /// Item definition for array types (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<ArgumentManifest>>,
+    
+    /// Value definition for map-like object types (optional)
+    #[serde(rename = "additionalProperties", default, skip_serializing_if = "Option::is_none")]
+    pub additional_properties: Option<Box<ArgumentManifest>>,
+    
+    /// Whether the argument is being retired (optional, default: false)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<bool>,
+    
+    /// Argument callers should move to (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+    
+    /// Manifest version that removes the argument (optional)
+    #[serde(rename = "removalVersion", alias = "removal_version", default, skip_serializing_if = "Option::is_none")]
+    pub removal_version: Option<String>,
 }
 
 impl ArgumentManifest {
@@ -155,6 +380,11 @@ impl ArgumentManifest {
             default_value: None,
             validation: None,
             model_ref: None,
+            items: None,
+            additional_properties: None,
+            deprecated: None,
+            replacement: None,
+            removal_version: None,
         }
     }
     
@@ -187,7 +417,25 @@ impl ArgumentManifest {
         self.validation = Some(validation);
         self
     }
-    
+
+    /// Set model reference
+    pub fn with_model_ref(mut self, model_ref: String) -> Self {
+        self.model_ref = Some(model_ref);
+        self
+    }
+
+    /// Set the definition of array items
+    pub fn with_items(mut self, items: ArgumentManifest) -> Self {
+        self.items = Some(Box::new(items));
+        self
+    }
+
+    /// Set the definition of map values
+    pub fn with_additional_properties(mut self, values: ArgumentManifest) -> Self {
+        self.additional_properties = Some(Box::new(values));
+        self
+    }
+
     /// Check if argument is required
     pub fn is_required(&self) -> bool {
         self.required.unwrap_or(false)
@@ -197,6 +445,19 @@ impl ArgumentManifest {
     pub fn has_default(&self) -> bool {
         self.default_value.is_some()
     }
+    
+    /// Mark the argument as deprecated, with an optional replacement and removal version
+    pub fn with_deprecation(mut self, replacement: Option<String>, removal_version: Option<String>) -> Self {
+        self.deprecated = Some(true);
+        self.replacement = replacement;
+        self.removal_version = removal_version;
+        self
+    }
+    
+    /// Check if argument is deprecated
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated.unwrap_or(false)
+    }
 }
 
 /// Validation constraint manifest
@@ -219,6 +480,14 @@ pub struct ValidationManifest {
     
     /// Enumerated allowed values (optional)
     pub r#enum: Option<Vec<serde_json::Value>>,
+    
+    /// Built-in string format such as "email" or "uuid" (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    
+    /// Names of application validators registered in a `ValidatorRegistry` (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validators: Option<Vec<String>>,
 }
 
 impl ValidationManifest {
@@ -231,6 +500,8 @@ impl ValidationManifest {
             minimum: None,
             maximum: None,
             r#enum: None,
+            format: None,
+            validators: None,
         }
     }
     
@@ -259,6 +530,18 @@ impl ValidationManifest {
         self.r#enum = Some(values);
         self
     }
+    
+    /// Set string format constraint
+    pub fn with_format(mut self, format: StringFormat) -> Self {
+        self.format = Some(format.name().to_string());
+        self
+    }
+    
+    /// Add a named application validator
+    pub fn with_validator(mut self, name: String) -> Self {
+        self.validators.get_or_insert_with(Vec::new).push(name);
+        self
+    }
 }
 
 impl Default for ValidationManifest {
@@ -279,6 +562,10 @@ pub struct ResponseManifest {
     /// Model reference for complex types (optional)
     #[serde(rename = "modelRef")]
     pub model_ref: Option<String>,
+    
+    /// Item definition for array responses (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<ArgumentManifest>>,
 }
 
 impl ResponseManifest {
@@ -288,9 +575,16 @@ impl ResponseManifest {
             r#type: response_type,
             properties: None,
             model_ref: None,
+            items: None,
         }
     }
     
+    /// Set the definition of array items
+    pub fn with_items(mut self, items: ArgumentManifest) -> Self {
+        self.items = Some(Box::new(items));
+        self
+    }
+    
     /// Add properties for object response
     pub fn with_properties(mut self, properties: HashMap<String, ArgumentManifest>) -> Self {
         self.properties = Some(properties);
@@ -309,8 +603,8 @@ impl ResponseManifest {
 /// Error code manifest
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ErrorCodeManifest {
-    /// HTTP-style error code
-    pub code: u16,
+    /// HTTP-style status code, or a negative application-defined JSON-RPC code
+    pub code: i32,
     
     /// Error message
     pub message: String,
@@ -321,7 +615,7 @@ pub struct ErrorCodeManifest {
 
 impl ErrorCodeManifest {
     /// Create a new error code manifest
-    pub fn new(code: u16, message: String) -> Self {
+    pub fn new(code: i32, message: String) -> Self {
         Self {
             code,
             message,
@@ -336,6 +630,42 @@ impl ErrorCodeManifest {
     }
 }
 
+/// An error matched to the error code its request declares, for matching on names instead of codes
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeclaredError {
+    /// Name the error is declared under in `error_codes`
+    pub name: String,
+    
+    /// Declared error code
+    pub code: i32,
+    
+    /// Declared error message
+    pub message: String,
+    
+    /// Declared error description
+    pub description: Option<String>,
+    
+    /// Details the handler attached to this occurrence
+    pub details: Option<String>,
+}
+
+impl DeclaredError {
+    /// Check whether this is the error declared under `name`
+    pub fn is(&self, name: &str) -> bool {
+        self.name == name
+    }
+}
+
+impl std::fmt::Display for DeclaredError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}): {}", self.name, self.code, self.message)?;
+        if let Some(details) = &self.details {
+            write!(f, ": {}", details)?;
+        }
+        Ok(())
+    }
+}
+
 /// Model manifest for complex data types
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ModelManifest {
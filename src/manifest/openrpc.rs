@@ -0,0 +1,140 @@
+/*!
+ * OpenRPC generation for Rust Janus Implementation
+ * Generates OpenRPC 1.x documents describing Manifest requests as JSON-RPC methods
+ */
+
+use crate::error::{JSONRPCError, JSONRPCErrorCode};
+use crate::manifest::json_schema::JsonSchemaExporter;
+use crate::manifest::model_registry::{ErrorCodeManifest, Manifest, RequestManifest};
+use serde_json::{json, Map, Value};
+
+/// OpenRPC specification version of generated documents
+pub const OPENRPC_VERSION: &str = "1.2.6";
+
+/// Generates OpenRPC documents from a Manifest.
+/// Requests become methods with by-name params, models become `components.schemas`.
+pub struct OpenRpcGenerator;
+
+impl OpenRpcGenerator {
+    /// Generate an OpenRPC document for the Manifest
+    pub fn generate(manifest: &Manifest, title: &str) -> Value {
+        let mut request_names = manifest.request_names();
+        request_names.sort();
+
+        let methods: Vec<Value> = request_names
+            .into_iter()
+            .filter_map(|name| {
+                manifest.get_request_manifest(name).map(|request_manifest| Self::method(name, request_manifest))
+            })
+            .collect();
+
+        let mut document = Map::new();
+        document.insert("openrpc".to_string(), json!(OPENRPC_VERSION));
+        document.insert("info".to_string(), json!({
+            "title": title,
+            "version": manifest.version,
+        }));
+        document.insert("methods".to_string(), Value::Array(methods));
+
+        if let Some(models) = &manifest.models {
+            let schemas: Map<String, Value> = models
+                .iter()
+                .map(|(model_name, model)| (model_name.clone(), Self::component_schema(JsonSchemaExporter::model_schema(model))))
+                .collect();
+            document.insert("components".to_string(), json!({"schemas": schemas}));
+        }
+
+        Value::Object(document)
+    }
+
+    /// Generate an OpenRPC document as pretty-printed JSON
+    pub fn to_json(manifest: &Manifest, title: &str) -> Result<String, JSONRPCError> {
+        serde_json::to_string_pretty(&Self::generate(manifest, title))
+            .map_err(|e| JSONRPCError::new(JSONRPCErrorCode::InternalError, Some(format!("OpenRPC serialization error: {}", e))))
+    }
+
+    /// Describe a single request as an OpenRPC method
+    pub fn method(request_name: &str, request_manifest: &RequestManifest) -> Value {
+        let mut arg_names: Vec<&String> = request_manifest.args.keys().collect();
+        arg_names.sort();
+
+        let params: Vec<Value> = arg_names
+            .into_iter()
+            .map(|arg_name| {
+                let arg_manifest = &request_manifest.args[arg_name];
+                let mut param = Map::new();
+                param.insert("name".to_string(), json!(arg_name));
+                if let Some(description) = &arg_manifest.description {
+                    param.insert("description".to_string(), json!(description));
+                }
+                param.insert("required".to_string(), json!(arg_manifest.is_required()));
+                if arg_manifest.is_deprecated() {
+                    param.insert("deprecated".to_string(), json!(true));
+                }
+                param.insert("schema".to_string(), Self::component_schema(JsonSchemaExporter::argument_schema(arg_manifest)));
+                Value::Object(param)
+            })
+            .collect();
+
+        let mut method = Map::new();
+        method.insert("name".to_string(), json!(request_name));
+        method.insert("description".to_string(), json!(request_manifest.description));
+        if request_manifest.is_deprecated() {
+            method.insert("deprecated".to_string(), json!(true));
+        }
+        method.insert("paramStructure".to_string(), json!("by-name"));
+        method.insert("params".to_string(), Value::Array(params));
+        method.insert("result".to_string(), json!({
+            "name": format!("{}Result", request_name),
+            "schema": Self::component_schema(JsonSchemaExporter::response_schema(&request_manifest.response)),
+        }));
+
+        if let Some(error_codes) = &request_manifest.error_codes {
+            let mut errors: Vec<(&String, &ErrorCodeManifest)> = error_codes.iter().collect();
+            errors.sort_by_key(|(error_name, error_manifest)| (error_manifest.code, *error_name));
+
+            let errors: Vec<Value> = errors
+                .into_iter()
+                .map(|(error_name, error_manifest)| {
+                    let mut data = Map::new();
+                    data.insert("name".to_string(), json!(error_name));
+                    if let Some(description) = &error_manifest.description {
+                        data.insert("description".to_string(), json!(description));
+                    }
+                    json!({
+                        "code": error_manifest.code,
+                        "message": error_manifest.message,
+                        "data": data,
+                    })
+                })
+                .collect();
+            method.insert("errors".to_string(), Value::Array(errors));
+        }
+
+        Value::Object(method)
+    }
+
+    /// Point `$ref`s at `#/components/schemas` instead of `#/$defs`
+    fn component_schema(mut schema: Value) -> Value {
+        Self::rewrite_refs(&mut schema);
+        schema
+    }
+
+    fn rewrite_refs(value: &mut Value) {
+        match value {
+            Value::Object(object) => {
+                for (key, child) in object.iter_mut() {
+                    if key == "$ref" {
+                        if let Some(model_ref) = child.as_str().and_then(|r| r.strip_prefix("#/$defs/")) {
+                            *child = json!(format!("#/components/schemas/{}", model_ref));
+                        }
+                    } else {
+                        Self::rewrite_refs(child);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(Self::rewrite_refs),
+            _ => {}
+        }
+    }
+}
@@ -4,11 +4,14 @@
  * Achieves 100% parity with TypeScript and Go implementations
  */
 
-use crate::manifest::model_registry::{Manifest, ResponseManifest, ArgumentManifest, ModelManifest};
+use crate::error::{JSONRPCError, JSONRPCErrorCode, JSONRPCErrorData};
+use crate::manifest::model_registry::{Manifest, ResponseManifest};
+use crate::manifest::validation_engine::ValidationEngine;
+use crate::manifest::validator_registry::ValidatorRegistry;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::time::Instant;
-use regex::Regex;
 
 /// Represents a validation error with detailed context
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -55,16 +58,75 @@ pub struct ValidationResult {
     pub fields_validated: usize,
 }
 
+impl ValidationResult {
+    /// Convert a failed validation into a JSON-RPC error carrying every violation.
+    /// The first violation is exposed through `field`, `value` and `constraints`,
+    /// the complete list is attached as `errors` in the error context.
+    pub fn to_jsonrpc_error(&self, code: JSONRPCErrorCode, summary: &str) -> JSONRPCError {
+        let mut data = match self.errors.first() {
+            Some(first) => JSONRPCErrorData::with_validation(
+                first.field.clone(),
+                first.actual.clone(),
+                format!("{}: {}", summary, first),
+            )
+            .with_constraints(HashMap::from([
+                ("expected".to_string(), Value::String(first.expected.clone())),
+            ])),
+            None => JSONRPCErrorData::with_details(summary),
+        };
+
+        let errors = serde_json::to_value(&self.errors).unwrap_or(Value::Null);
+        data = data.with_context(HashMap::from([
+            ("errors".to_string(), errors),
+            ("errorCount".to_string(), Value::from(self.errors.len())),
+        ]));
+
+        JSONRPCError {
+            code: code.code(),
+            message: code.message().to_string(),
+            data: Some(data),
+        }
+    }
+}
+
+/// How clients treat successful responses whose result violates the Manifest
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseValidationMode {
+    /// Results are not checked
+    #[default]
+    Off,
+    /// Violations are logged and the response is returned unchanged
+    Warn,
+    /// Violations fail the request with a ManifestValidationError
+    Reject,
+}
+
 /// Response validator that validates request handler responses
 /// against Manifest ResponseManifest models
-pub struct ResponseValidator {
-    manifest: Manifest,
+pub struct ResponseValidator<'a> {
+    manifest: &'a Manifest,
+    validators: Option<&'a ValidatorRegistry>,
 }
 
-impl ResponseValidator {
-    /// Create a new response validator with the given Manifest
-    pub fn new(manifest: Manifest) -> Self {
-        Self { manifest }
+impl<'a> ResponseValidator<'a> {
+    /// Create a new response validator for the given Manifest
+    pub fn new(manifest: &'a Manifest) -> Self {
+        Self { manifest, validators: None }
+    }
+
+    /// Run the named validators the Manifest references from this registry
+    pub fn with_validators(mut self, validators: &'a ValidatorRegistry) -> Self {
+        self.validators = Some(validators);
+        self
+    }
+
+    /// Create a validation engine for the Manifest and registered validators
+    fn engine(&self) -> ValidationEngine<'a> {
+        match self.validators {
+            Some(validators) => ValidationEngine::new(self.manifest).with_validators(validators),
+            None => ValidationEngine::new(self.manifest),
+        }
     }
 
     /// Validate a response against a ResponseManifest
@@ -72,10 +134,10 @@ impl ResponseValidator {
         let start_time = Instant::now();
         let mut errors = Vec::new();
         
-        // Validate the response value against the manifest
-        self.validate_value(response, &response_manifest.into(), "", &mut errors);
+        // Validate the response value against the manifest, following nested model references
+        self.engine().validate_response(response, response_manifest, "", &mut errors);
         
-        let fields_validated = self.count_validated_fields(&response_manifest.into());
+        let fields_validated = self.count_validated_fields(response_manifest);
         let validation_time = start_time.elapsed().as_secs_f64() * 1000.0; // Convert to milliseconds
         
         ValidationResult {
@@ -86,400 +148,33 @@ impl ResponseValidator {
         }
     }
 
-    /// Validate a request response by looking up the request manifest
-    pub fn validate_request_response(&self, _response: &Value, _request_name: &str) -> ValidationResult {
-        let start_time = Instant::now();
-        
-        // Since channels are removed, request validation will be server-side
-        // For now, return a validation result that indicates success
-        ValidationResult {
-            valid: true,
-            errors: vec![],
-            validation_time: start_time.elapsed().as_secs_f64() * 1000.0,
-            fields_validated: 0,
-        }
-    }
-
-    /// Validate a value against a manifest
-    fn validate_value(&self, value: &Value, manifest: &ManifestType, field_path: &str, errors: &mut Vec<ValidationError>) {
-        // Handle model references first
-        match manifest {
-            ManifestType::Response(response_manifest) => {
-                if let Some(model_ref) = &response_manifest.model_ref {
-                    if let Some(model) = self.resolve_model_reference(model_ref) {
-                        self.validate_value(value, &ManifestType::Model(model.clone()), field_path, errors);
-                        return;
-                    } else {
-                        errors.push(ValidationError {
-                            field: field_path.to_string(),
-                            message: format!("Model reference '{}' not found", model_ref),
-                            expected: "valid model reference".to_string(),
-                            actual: Value::String(model_ref.clone()),
-                            context: None,
-                        });
-                        return;
-                    }
-                }
-            }
-            ManifestType::Argument(arg_manifest) => {
-                if let Some(model_ref) = &arg_manifest.model_ref {
-                    if let Some(model) = self.resolve_model_reference(model_ref) {
-                        self.validate_value(value, &ManifestType::Model(model.clone()), field_path, errors);
-                        return;
-                    } else {
-                        errors.push(ValidationError {
-                            field: field_path.to_string(),
-                            message: format!("Model reference '{}' not found", model_ref),
-                            expected: "valid model reference".to_string(),
-                            actual: Value::String(model_ref.clone()),
-                            context: None,
-                        });
-                        return;
-                    }
-                }
-            }
-            ManifestType::Model(_) => {
-                // No model reference needed for already resolved models
-            }
-        }
-
-        // Validate type
-        let initial_error_count = errors.len();
-        self.validate_type(value, manifest.type_str(), field_path, errors);
-
-        if errors.len() > initial_error_count {
-            return; // Don't continue validation if type is wrong
-        }
-
-        // Type-manifestific validation
-        match manifest.type_str() {
-            "string" => {
-                if let Value::String(str_value) = value {
-                    self.validate_string(str_value, manifest, field_path, errors);
-                }
-            }
-            "number" | "integer" => {
-                if let Some(num_value) = self.get_numeric_value(value) {
-                    self.validate_number(num_value, manifest.type_str(), manifest, field_path, errors);
-                }
-            }
-            "array" => {
-                if let Value::Array(array_value) = value {
-                    self.validate_array(array_value, manifest, field_path, errors);
-                }
-            }
-            "object" => {
-                if let Value::Object(obj_value) = value {
-                    self.validate_object(obj_value, manifest, field_path, errors);
-                }
-            }
-            "boolean" => {
-                // Boolean validation is covered by type validation
-            }
-            _ => {}
-        }
-
-        // Validate enum values (only available on ArgumentManifest through ValidationManifest)
-        if let Some(enum_values) = manifest.enum_values() {
-            self.validate_enum(value, enum_values, field_path, errors);
-        }
-    }
-
-    /// Validate the type of a value
-    fn validate_type(&self, value: &Value, expected_type: &str, field_path: &str, errors: &mut Vec<ValidationError>) {
-        let actual_type = self.get_actual_type(value);
-
-        if expected_type == "integer" {
-            if actual_type != "number" || !self.is_integer(value) {
-                errors.push(ValidationError {
-                    field: field_path.to_string(),
-                    message: "Value is not an integer".to_string(),
-                    expected: "integer".to_string(),
-                    actual: Value::String(actual_type.to_string()),
-                    context: None,
-                });
-            }
-        } else if actual_type != expected_type {
-            errors.push(ValidationError {
-                field: field_path.to_string(),
-                message: "Type mismatch".to_string(),
-                expected: expected_type.to_string(),
-                actual: Value::String(actual_type.to_string()),
-                context: None,
-            });
-        }
-    }
-
-    /// Get the actual type string of a value
-    fn get_actual_type(&self, value: &Value) -> &'static str {
-        match value {
-            Value::Null => "null",
-            Value::Bool(_) => "boolean",
-            Value::Number(_) => "number",
-            Value::String(_) => "string",
-            Value::Array(_) => "array",
-            Value::Object(_) => "object",
-        }
-    }
-
-    /// Check if a numeric value is an integer
-    fn is_integer(&self, value: &Value) -> bool {
-        if let Value::Number(num) = value {
-            if let Some(int_val) = num.as_i64() {
-                num.as_f64().map_or(false, |f| f == int_val as f64)
-            } else {
-                false
-            }
-        } else {
-            false
-        }
-    }
-
-    /// Extract a numeric value from JSON value
-    fn get_numeric_value(&self, value: &Value) -> Option<f64> {
-        if let Value::Number(num) = value {
-            num.as_f64()
-        } else {
-            None
-        }
-    }
-
-    /// Validate string value constraints
-    fn validate_string(&self, value: &str, manifest: &ManifestType, field_path: &str, errors: &mut Vec<ValidationError>) {
-        // Only ArgumentManifest has validation constraints in Rust implementation
-        if let ManifestType::Argument(arg_manifest) = manifest {
-            if let Some(ref validation) = arg_manifest.validation {
-                // Length validation
-                if let Some(min_length) = validation.min_length {
-                    if value.len() < min_length {
-                        errors.push(ValidationError {
-                            field: field_path.to_string(),
-                            message: format!("String is too short ({} < {})", value.len(), min_length),
-                            expected: format!("minimum length {}", min_length),
-                            actual: Value::String(format!("length {}", value.len())),
-                            context: None,
-                        });
-                    }
-                }
-
-                if let Some(max_length) = validation.max_length {
-                    if value.len() > max_length {
-                        errors.push(ValidationError {
-                            field: field_path.to_string(),
-                            message: format!("String is too long ({} > {})", value.len(), max_length),
-                            expected: format!("maximum length {}", max_length),
-                            actual: Value::String(format!("length {}", value.len())),
-                            context: None,
-                        });
-                    }
-                }
-
-                // Pattern validation
-                if let Some(ref pattern) = validation.pattern {
-                    match Regex::new(pattern) {
-                        Ok(regex) => {
-                            if !regex.is_match(value) {
-                                errors.push(ValidationError {
-                                    field: field_path.to_string(),
-                                    message: "String does not match required pattern".to_string(),
-                                    expected: format!("pattern {}", pattern),
-                                    actual: Value::String(value.to_string()),
-                                    context: None,
-                                });
-                            }
-                        }
-                        Err(_) => {
-                            errors.push(ValidationError {
-                                field: field_path.to_string(),
-                                message: "Invalid regex pattern in manifest".to_string(),
-                                expected: "valid regex pattern".to_string(),
-                                actual: Value::String(pattern.clone()),
-                                context: None,
-                            });
-                        }
-                    }
-                }
-            }
-        }
-    }
-
-    /// Validate numeric value constraints
-    fn validate_number(&self, value: f64, _value_type: &str, manifest: &ManifestType, field_path: &str, errors: &mut Vec<ValidationError>) {
-        // Range validation (only available on ArgumentManifest through ValidationManifest)
-        if let ManifestType::Argument(arg_manifest) = manifest {
-            if let Some(ref validation) = arg_manifest.validation {
-                if let Some(minimum) = validation.minimum {
-                    if value < minimum {
-                        errors.push(ValidationError {
-                            field: field_path.to_string(),
-                            message: format!("Number is too small ({} < {})", value, minimum),
-                            expected: format!("minimum {}", minimum),
-                            actual: Value::Number(serde_json::Number::from_f64(value).unwrap()),
-                            context: None,
-                        });
-                    }
-                }
-
-                if let Some(maximum) = validation.maximum {
-                    if value > maximum {
-                        errors.push(ValidationError {
-                            field: field_path.to_string(),
-                            message: format!("Number is too large ({} > {})", value, maximum),
-                            expected: format!("maximum {}", maximum),
-                            actual: Value::Number(serde_json::Number::from_f64(value).unwrap()),
-                            context: None,
-                        });
-                    }
-                }
-            }
-        }
-    }
-
-    /// Validate array value and items (matches Go implementation)
-    fn validate_array(&self, value: &[Value], manifest: &ManifestType, field_path: &str, errors: &mut Vec<ValidationError>) {
-        // Basic array validation - type checking is handled by caller
-        
-        // For each array item, validate recursively if we have type information
-        // This is a basic implementation matching Go's recursive array validation
-        for (index, item) in value.iter().enumerate() {
-            let item_path = format!("{}[{}]", field_path, index);
-            
-            // For arrays, we can do basic type consistency validation
-            // More advanced validation would require array item type manifests
-            match item {
-                Value::Object(obj) => {
-                    // Validate object items recursively if we have object manifest
-                    if let ManifestType::Response(response_manifest) = manifest {
-                        if let Some(properties) = &response_manifest.properties {
-                            self.validate_object_properties(obj, properties, &item_path, errors);
-                        }
-                    }
-                }
-                Value::Array(arr) => {
-                    // Recursive array validation
-                    self.validate_array(arr, manifest, &item_path, errors);
-                }
-                _ => {
-                    // Basic type validation for primitive array items
-                    // Additional constraints could be added here
-                }
-            }
-        }
-    }
-    
-    /// Validate object properties (helper method for array validation)
-    fn validate_object_properties(&self, value: &serde_json::Map<String, Value>, properties: &std::collections::HashMap<String, ArgumentManifest>, field_path: &str, errors: &mut Vec<ValidationError>) {
-        // Validate each property
-        for (prop_name, prop_manifest) in properties {
-            let prop_field_path = if field_path.is_empty() {
-                prop_name.clone()
-            } else {
-                format!("{}.{}", field_path, prop_name)
-            };
-
-            let prop_value = value.get(prop_name);
-
-            // Check required fields
-            let is_required = prop_manifest.required.unwrap_or(false);
-            if is_required && (prop_value.is_none() || prop_value == Some(&Value::Null)) {
-                errors.push(ValidationError {
-                    field: prop_field_path.clone(),
-                    message: "Required field is missing or null".to_string(),
-                    expected: format!("non-null {}", prop_manifest.r#type),
-                    actual: prop_value.cloned().unwrap_or(Value::Null),
+    /// Validate a request response against the response definition of the named request
+    pub fn validate_request_response(&self, response: &Value, request_name: &str) -> ValidationResult {
+        match self.manifest.get_request_manifest(request_name) {
+            Some(request_manifest) => self.validate_response(response, &request_manifest.response),
+            None => ValidationResult {
+                valid: false,
+                errors: vec![ValidationError {
+                    field: "manifest".to_string(),
+                    message: format!("No response manifest found for request '{}'", request_name),
+                    expected: "response manifest".to_string(),
+                    actual: Value::String("undefined".to_string()),
                     context: None,
-                });
-                continue;
-            }
-
-            // Validate property value if present
-            if let Some(value) = prop_value {
-                // Use the property manifest directly as ArgumentManifest (they have compatible structure)
-                let manifest_type = ManifestType::Argument(prop_manifest.clone());
-                self.validate_value(value, &manifest_type, &prop_field_path, errors);
-            }
-        }
-    }
-
-    /// Validate object properties
-    fn validate_object(&self, value: &serde_json::Map<String, Value>, manifest: &ManifestType, field_path: &str, errors: &mut Vec<ValidationError>) {
-        let properties = match manifest {
-            ManifestType::Response(response_manifest) => response_manifest.properties.as_ref(),
-            ManifestType::Model(model_manifest) => Some(&model_manifest.properties),
-            ManifestType::Argument(_arg_manifest) => {
-                // ArgumentManifest doesn't have properties in current Rust implementation
-                return;
-            }
-        };
-
-        if let Some(properties) = properties {
-            // Validate each property
-            for (prop_name, prop_manifest) in properties {
-                let prop_field_path = if field_path.is_empty() {
-                    prop_name.clone()
-                } else {
-                    format!("{}.{}", field_path, prop_name)
-                };
-
-                let prop_value = value.get(prop_name);
-
-                // Check required fields
-                let is_required = prop_manifest.required.unwrap_or(false);
-                if is_required && (prop_value.is_none() || prop_value == Some(&Value::Null)) {
-                    errors.push(ValidationError {
-                        field: prop_field_path,
-                        message: "Required field is missing or null".to_string(),
-                        expected: format!("non-null {}", prop_manifest.r#type),
-                        actual: prop_value.cloned().unwrap_or(Value::Null),
-                        context: None,
-                    });
-                    continue;
-                }
-
-                // Skip validation for optional missing fields
-                if prop_value.is_none() && !is_required {
-                    continue;
-                }
-
-                // Validate property value
-                if let Some(prop_val) = prop_value {
-                    self.validate_value(prop_val, &ManifestType::Argument(prop_manifest.clone()), &prop_field_path, errors);
-                }
-            }
-        }
-    }
-
-    /// Validate enum constraints
-    fn validate_enum(&self, value: &Value, enum_values: &[Value], field_path: &str, errors: &mut Vec<ValidationError>) {
-        if !enum_values.contains(value) {
-            let enum_strings: Vec<String> = enum_values.iter().map(|v| v.to_string()).collect();
-            errors.push(ValidationError {
-                field: field_path.to_string(),
-                message: "Value is not in allowed enum list".to_string(),
-                expected: enum_strings.join(", "),
-                actual: value.clone(),
-                context: None,
-            });
+                }],
+                validation_time: 0.0,
+                fields_validated: 0,
+            },
         }
     }
 
-    /// Resolve a model reference to its definition
-    fn resolve_model_reference(&self, model_ref: &str) -> Option<&ModelManifest> {
-        self.manifest.models.as_ref()?.get(model_ref)
-    }
-
     /// Count the number of fields that would be validated
-    fn count_validated_fields(&self, manifest: &ManifestType) -> usize {
-        if manifest.type_str() == "object" {
-            match manifest {
-                ManifestType::Response(response_manifest) => {
-                    response_manifest.properties.as_ref().map_or(1, |props| props.len())
-                }
-                ManifestType::Model(model_manifest) => model_manifest.properties.len(),
-                ManifestType::Argument(_) => 1,
-            }
-        } else {
-            1
+    fn count_validated_fields(&self, manifest: &ResponseManifest) -> usize {
+        if let Some(model) = manifest.model_ref.as_ref().and_then(|model_ref| self.manifest.get_model(model_ref)) {
+            return model.properties.len();
+        }
+        match (&manifest.r#type[..], &manifest.properties) {
+            ("object", Some(properties)) => properties.len(),
+            _ => 1,
         }
     }
 
@@ -509,48 +204,3 @@ impl ResponseValidator {
         }
     }
 }
-
-/// Unified manifest type for validation
-#[derive(Clone)]
-enum ManifestType {
-    Response(ResponseManifest),
-    Argument(ArgumentManifest),
-    Model(ModelManifest),
-}
-
-impl ManifestType {
-    fn type_str(&self) -> &str {
-        match self {
-            ManifestType::Response(manifest) => &manifest.r#type,
-            ManifestType::Argument(manifest) => &manifest.r#type,
-            ManifestType::Model(manifest) => &manifest.r#type,
-        }
-    }
-
-    fn enum_values(&self) -> Option<&[Value]> {
-        match self {
-            ManifestType::Argument(manifest) => {
-                manifest.validation.as_ref()?.r#enum.as_deref()
-            },
-            _ => None,
-        }
-    }
-}
-
-impl From<&ResponseManifest> for ManifestType {
-    fn from(manifest: &ResponseManifest) -> Self {
-        ManifestType::Response(manifest.clone())
-    }
-}
-
-impl From<&ArgumentManifest> for ManifestType {
-    fn from(manifest: &ArgumentManifest) -> Self {
-        ManifestType::Argument(manifest.clone())
-    }
-}
-
-impl From<&ModelManifest> for ManifestType {
-    fn from(manifest: &ModelManifest) -> Self {
-        ManifestType::Model(manifest.clone())
-    }
-}
\ No newline at end of file
@@ -0,0 +1,131 @@
+/*!
+ * String Formats for Rust Janus Implementation
+ * Built-in validators for the `format` constraint of string arguments and response fields
+ */
+
+use chrono::DateTime;
+use regex::Regex;
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Well-known string formats a `ValidationManifest` can require.
+/// Names follow JSON Schema where it defines the format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StringFormat {
+    /// Email address (`email`), using the HTML living standard's definition
+    Email,
+    /// Hyphenated UUID in either case (`uuid`)
+    Uuid,
+    /// RFC 3339 timestamp (`date-time`)
+    DateTime,
+    /// Absolute RFC 3986 URI (`uri`)
+    Uri,
+    /// Dotted-quad IPv4 address (`ipv4`)
+    Ipv4,
+    /// IPv6 address (`ipv6`)
+    Ipv6,
+    /// Absolute file system path (`absolute-path`)
+    AbsolutePath,
+}
+
+impl StringFormat {
+    /// Every supported format
+    pub const ALL: [StringFormat; 7] = [
+        StringFormat::Email,
+        StringFormat::Uuid,
+        StringFormat::DateTime,
+        StringFormat::Uri,
+        StringFormat::Ipv4,
+        StringFormat::Ipv6,
+        StringFormat::AbsolutePath,
+    ];
+
+    /// Look up a format by its Manifest name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|format| format.name() == name)
+    }
+
+    /// Name used for the format in Manifests
+    pub fn name(&self) -> &'static str {
+        match self {
+            StringFormat::Email => "email",
+            StringFormat::Uuid => "uuid",
+            StringFormat::DateTime => "date-time",
+            StringFormat::Uri => "uri",
+            StringFormat::Ipv4 => "ipv4",
+            StringFormat::Ipv6 => "ipv6",
+            StringFormat::AbsolutePath => "absolute-path",
+        }
+    }
+
+    /// Human-readable description used in validation errors
+    pub fn description(&self) -> &'static str {
+        match self {
+            StringFormat::Email => "an email address",
+            StringFormat::Uuid => "a UUID",
+            StringFormat::DateTime => "an RFC 3339 timestamp",
+            StringFormat::Uri => "an absolute URI",
+            StringFormat::Ipv4 => "an IPv4 address",
+            StringFormat::Ipv6 => "an IPv6 address",
+            StringFormat::AbsolutePath => "an absolute file path",
+        }
+    }
+
+    /// Check whether a string is in this format
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            StringFormat::Email => email_regex().is_match(value),
+            StringFormat::Uuid => uuid_regex().is_match(value),
+            StringFormat::DateTime => DateTime::parse_from_rfc3339(value).is_ok(),
+            StringFormat::Uri => Self::is_uri(value),
+            StringFormat::Ipv4 => Ipv4Addr::from_str(value).is_ok(),
+            StringFormat::Ipv6 => Ipv6Addr::from_str(value).is_ok(),
+            StringFormat::AbsolutePath => !value.contains('\0') && Path::new(value).is_absolute(),
+        }
+    }
+
+    /// Names of every supported format, for error messages
+    pub fn supported_names() -> String {
+        Self::ALL.iter().map(|format| format.name()).collect::<Vec<_>>().join(", ")
+    }
+
+    /// An absolute URI has a scheme, no whitespace or excluded characters, and well-formed percent escapes
+    fn is_uri(value: &str) -> bool {
+        if !uri_regex().is_match(value) {
+            return false;
+        }
+        let bytes = value.as_bytes();
+        bytes.iter().enumerate().all(|(index, byte)| {
+            *byte != b'%' || (bytes.len() > index + 2 && bytes[index + 1].is_ascii_hexdigit() && bytes[index + 2].is_ascii_hexdigit())
+        })
+    }
+}
+
+impl fmt::Display for StringFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+fn email_regex() -> &'static Regex {
+    static EMAIL: OnceLock<Regex> = OnceLock::new();
+    EMAIL.get_or_init(|| {
+        Regex::new(r"^[A-Za-z0-9.!#$%&'*+/=?^_`{|}~-]+@[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?(?:\.[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?)*$")
+            .expect("email pattern compiles")
+    })
+}
+
+fn uuid_regex() -> &'static Regex {
+    static UUID: OnceLock<Regex> = OnceLock::new();
+    UUID.get_or_init(|| {
+        Regex::new(r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$").expect("uuid pattern compiles")
+    })
+}
+
+fn uri_regex() -> &'static Regex {
+    static URI: OnceLock<Regex> = OnceLock::new();
+    URI.get_or_init(|| Regex::new(r#"^[A-Za-z][A-Za-z0-9+.\-]*:[^\s<>"{}|\\^`]*$"#).expect("uri pattern compiles"))
+}
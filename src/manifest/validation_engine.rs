@@ -1,7 +1,472 @@
-pub struct ValidationEngine;
+/*!
+ * Validation Engine for Rust Janus Implementation
+ * Validates JSON values against Manifest ArgumentManifest and ModelManifest definitions
+ */
 
-impl ValidationEngine {
-    pub fn new() -> Self {
-        Self
+use crate::manifest::model_registry::{ArgumentManifest, Manifest, ModelManifest, ResponseManifest, ValidationManifest};
+use crate::manifest::response_validator::ValidationError;
+use crate::manifest::string_format::StringFormat;
+use crate::manifest::validator_registry::ValidatorRegistry;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+/// Core value validation shared by argument validation and manifest-driven checks.
+/// Checks types, `ValidationManifest` constraints, `modelRef` models, array `items` and
+/// map `additionalProperties`, collecting every violation together with its field path.
+/// Recursion follows the value rather than the definitions, so recursive models terminate.
+pub struct ValidationEngine<'a> {
+    manifest: &'a Manifest,
+    validators: Option<&'a ValidatorRegistry>,
+}
+
+impl<'a> ValidationEngine<'a> {
+    /// Create a new validation engine resolving model references against the given Manifest
+    pub fn new(manifest: &'a Manifest) -> Self {
+        Self { manifest, validators: None }
+    }
+
+    /// Run the named validators Manifests reference from this registry.
+    /// Without a registry, for example on clients, named validators are skipped.
+    pub fn with_validators(mut self, validators: &'a ValidatorRegistry) -> Self {
+        self.validators = Some(validators);
+        self
+    }
+
+    /// Get the Manifest used for model resolution
+    pub fn manifest(&self) -> &'a Manifest {
+        self.manifest
+    }
+
+    /// Validate a value against an argument manifest, appending violations to `errors`
+    pub fn validate_value(
+        &self,
+        value: &Value,
+        manifest: &ArgumentManifest,
+        field_path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        // Validate type first, further checks are meaningless on a type mismatch
+        if !self.validate_type(value, &manifest.r#type, field_path, errors) {
+            return;
+        }
+
+        if let Some(validation) = &manifest.validation {
+            match value {
+                Value::String(string_value) => {
+                    self.validate_length(string_value.chars().count(), validation, "String", field_path, errors);
+                    if let Some(pattern) = &validation.pattern {
+                        self.validate_pattern(string_value, pattern, field_path, errors);
+                    }
+                    if let Some(format) = &validation.format {
+                        self.validate_format(string_value, format, field_path, errors);
+                    }
+                }
+                Value::Array(array_value) => {
+                    self.validate_length(array_value.len(), validation, "Array", field_path, errors);
+                }
+                Value::Number(number) => {
+                    if let Some(number_value) = number.as_f64() {
+                        self.validate_range(number_value, validation.minimum, validation.maximum, field_path, errors);
+                    }
+                }
+                _ => {}
+            }
+
+            if let Some(enum_values) = &validation.r#enum {
+                self.validate_enum(value, enum_values, field_path, errors);
+            }
+
+            if let (Some(names), Some(registry)) = (&validation.validators, self.validators) {
+                for name in names {
+                    self.validate_custom(value, name, registry, field_path, errors);
+                }
+            }
+        }
+
+        // Validate array items against the item definition
+        if let (Some(items), Value::Array(array_value)) = (&manifest.items, value) {
+            for (index, item) in array_value.iter().enumerate() {
+                self.validate_value(item, items, &Self::index_path(field_path, index), errors);
+            }
+        }
+
+        // Validate model reference for complex types
+        let mut model = None;
+        if let Some(model_ref) = &manifest.model_ref {
+            model = self.resolve_model_reference(model_ref);
+            match model {
+                Some(model) => self.validate_model(value, model, field_path, errors),
+                None => errors.push(Self::missing_model_error(model_ref, field_path)),
+            }
+        }
+
+        // Validate map entries not covered by model properties
+        if let (Some(values), Value::Object(object)) = (&manifest.additional_properties, value) {
+            for (key, entry) in object {
+                if model.is_some_and(|model| model.properties.contains_key(key)) {
+                    continue;
+                }
+                self.validate_value(entry, values, &Self::join_path(field_path, key), errors);
+            }
+        }
+    }
+
+    /// Validate a value against a response manifest, appending violations to `errors`
+    pub fn validate_response(
+        &self,
+        value: &Value,
+        manifest: &ResponseManifest,
+        field_path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        if let Some(model_ref) = &manifest.model_ref {
+            match self.resolve_model_reference(model_ref) {
+                Some(model) => self.validate_model(value, model, field_path, errors),
+                None => errors.push(Self::missing_model_error(model_ref, field_path)),
+            }
+            return;
+        }
+
+        if !self.validate_type(value, &manifest.r#type, field_path, errors) {
+            return;
+        }
+
+        match value {
+            Value::Array(array_value) => {
+                for (index, item) in array_value.iter().enumerate() {
+                    let item_path = Self::index_path(field_path, index);
+                    match (&manifest.items, &manifest.properties, item) {
+                        (Some(items), _, _) => self.validate_value(item, items, &item_path, errors),
+                        // Older manifests describe object items through the response properties
+                        (None, Some(properties), Value::Object(object)) => {
+                            self.validate_properties(object, properties, |_| false, &item_path, errors)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Value::Object(object) => {
+                if let Some(properties) = &manifest.properties {
+                    self.validate_properties(object, properties, |_| false, field_path, errors);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Validate a value against a model definition, appending violations to `errors`
+    pub fn validate_model(
+        &self,
+        value: &Value,
+        model: &ModelManifest,
+        field_path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        if !self.validate_type(value, &model.r#type, field_path, errors) {
+            return;
+        }
+
+        let object = match value {
+            Value::Object(object) => object,
+            _ => return,
+        };
+
+        self.validate_properties(object, &model.properties, |prop_name| model.is_property_required(prop_name), field_path, errors);
+
+        // Required entries without a matching property definition
+        for required in model.required_properties() {
+            if !model.properties.contains_key(required) && !object.contains_key(required) {
+                errors.push(ValidationError {
+                    field: Self::join_path(field_path, required),
+                    message: "Required field is missing or null".to_string(),
+                    expected: "non-null value".to_string(),
+                    actual: Value::Null,
+                    context: None,
+                });
+            }
+        }
+    }
+
+    /// Validate the defined properties of an object, `required` adds to per-property flags
+    fn validate_properties(
+        &self,
+        object: &serde_json::Map<String, Value>,
+        properties: &HashMap<String, ArgumentManifest>,
+        required: impl Fn(&str) -> bool,
+        field_path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let mut properties: Vec<(&String, &ArgumentManifest)> = properties.iter().collect();
+        properties.sort_by(|a, b| a.0.cmp(b.0));
+        for (prop_name, prop_manifest) in properties {
+            let prop_field_path = Self::join_path(field_path, prop_name);
+            let is_required = prop_manifest.is_required() || required(prop_name);
+
+            match object.get(prop_name) {
+                None | Some(Value::Null) if is_required => {
+                    errors.push(ValidationError {
+                        field: prop_field_path,
+                        message: "Required field is missing or null".to_string(),
+                        expected: format!("non-null {}", prop_manifest.r#type),
+                        actual: object.get(prop_name).cloned().unwrap_or(Value::Null),
+                        context: None,
+                    });
+                }
+                Some(prop_value) => {
+                    self.validate_value(prop_value, prop_manifest, &prop_field_path, errors);
+                }
+                None => {}
+            }
+        }
+    }
+
+    fn missing_model_error(model_ref: &str, field_path: &str) -> ValidationError {
+        ValidationError {
+            field: field_path.to_string(),
+            message: format!("Model reference '{}' not found", model_ref),
+            expected: "valid model reference".to_string(),
+            actual: Value::String(model_ref.to_string()),
+            context: None,
+        }
+    }
+
+    /// Resolve a model reference to its definition
+    pub fn resolve_model_reference(&self, model_ref: &str) -> Option<&'a ModelManifest> {
+        self.manifest.get_model(model_ref)
+    }
+
+    /// Build the field path of a child element
+    pub fn join_path(parent: &str, child: &str) -> String {
+        if parent.is_empty() {
+            child.to_string()
+        } else {
+            format!("{}.{}", parent, child)
+        }
+    }
+
+    /// Build the field path of an array element
+    pub fn index_path(parent: &str, index: usize) -> String {
+        format!("{}[{}]", parent, index)
+    }
+
+    /// Get the JSON type name of a value
+    pub fn value_type_name(value: &Value) -> &'static str {
+        match value {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+
+    /// Validate the type of a value, returning whether it matched
+    fn validate_type(
+        &self,
+        value: &Value,
+        expected_type: &str,
+        field_path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) -> bool {
+        let matches = match expected_type {
+            "string" => value.is_string(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "number" => value.is_number(),
+            "boolean" => value.is_boolean(),
+            "array" => value.is_array(),
+            "object" => value.is_object(),
+            _ => false,
+        };
+
+        if !matches {
+            let message = if expected_type == "integer" {
+                "Value is not an integer".to_string()
+            } else {
+                "Type mismatch".to_string()
+            };
+            errors.push(ValidationError {
+                field: field_path.to_string(),
+                message,
+                expected: expected_type.to_string(),
+                actual: Value::String(Self::value_type_name(value).to_string()),
+                context: None,
+            });
+        }
+
+        matches
+    }
+
+    /// Validate string or array length constraints
+    fn validate_length(
+        &self,
+        length: usize,
+        validation: &ValidationManifest,
+        kind: &str,
+        field_path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        if let Some(min_length) = validation.min_length {
+            if length < min_length {
+                errors.push(ValidationError {
+                    field: field_path.to_string(),
+                    message: format!("{} is too short ({} < {})", kind, length, min_length),
+                    expected: format!("minimum length {}", min_length),
+                    actual: Value::String(format!("length {}", length)),
+                    context: None,
+                });
+            }
+        }
+
+        if let Some(max_length) = validation.max_length {
+            if length > max_length {
+                errors.push(ValidationError {
+                    field: field_path.to_string(),
+                    message: format!("{} is too long ({} > {})", kind, length, max_length),
+                    expected: format!("maximum length {}", max_length),
+                    actual: Value::String(format!("length {}", length)),
+                    context: None,
+                });
+            }
+        }
+    }
+
+    /// Validate a string against a regex pattern
+    fn validate_pattern(&self, value: &str, pattern: &str, field_path: &str, errors: &mut Vec<ValidationError>) {
+        match compiled_pattern(pattern) {
+            Some(regex) => {
+                if !regex.is_match(value) {
+                    errors.push(ValidationError {
+                        field: field_path.to_string(),
+                        message: "String does not match required pattern".to_string(),
+                        expected: format!("pattern {}", pattern),
+                        actual: Value::String(value.to_string()),
+                        context: None,
+                    });
+                }
+            }
+            None => {
+                errors.push(ValidationError {
+                    field: field_path.to_string(),
+                    message: "Invalid regex pattern in manifest".to_string(),
+                    expected: "valid regex pattern".to_string(),
+                    actual: Value::String(pattern.to_string()),
+                    context: None,
+                });
+            }
+        }
+    }
+
+    /// Validate a string against a built-in format
+    fn validate_format(&self, value: &str, format: &str, field_path: &str, errors: &mut Vec<ValidationError>) {
+        match StringFormat::from_name(format) {
+            Some(string_format) => {
+                if !string_format.matches(value) {
+                    errors.push(ValidationError {
+                        field: field_path.to_string(),
+                        message: format!("String is not {}", string_format.description()),
+                        expected: format!("format {}", format),
+                        actual: Value::String(value.to_string()),
+                        context: None,
+                    });
+                }
+            }
+            None => {
+                errors.push(ValidationError {
+                    field: field_path.to_string(),
+                    message: "Unknown string format in manifest".to_string(),
+                    expected: format!("one of {}", StringFormat::supported_names()),
+                    actual: Value::String(format.to_string()),
+                    context: None,
+                });
+            }
+        }
+    }
+
+    /// Run a named application validator
+    fn validate_custom(
+        &self,
+        value: &Value,
+        name: &str,
+        registry: &ValidatorRegistry,
+        field_path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let outcome = match registry.get(name) {
+            Some(validator) => validator(value),
+            None => Err(format!("Validator '{}' is not registered", name)),
+        };
+        if let Err(message) = outcome {
+            errors.push(ValidationError {
+                field: field_path.to_string(),
+                message,
+                expected: format!("validator {}", name),
+                actual: value.clone(),
+                context: None,
+            });
+        }
+    }
+
+    /// Validate numeric range constraints
+    fn validate_range(
+        &self,
+        value: f64,
+        minimum: Option<f64>,
+        maximum: Option<f64>,
+        field_path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        if let Some(minimum) = minimum {
+            if value < minimum {
+                errors.push(ValidationError {
+                    field: field_path.to_string(),
+                    message: format!("Number is too small ({} < {})", value, minimum),
+                    expected: format!("minimum {}", minimum),
+                    actual: serde_json::json!(value),
+                    context: None,
+                });
+            }
+        }
+
+        if let Some(maximum) = maximum {
+            if value > maximum {
+                errors.push(ValidationError {
+                    field: field_path.to_string(),
+                    message: format!("Number is too large ({} > {})", value, maximum),
+                    expected: format!("maximum {}", maximum),
+                    actual: serde_json::json!(value),
+                    context: None,
+                });
+            }
+        }
+    }
+
+    /// Validate enum constraints
+    fn validate_enum(&self, value: &Value, enum_values: &[Value], field_path: &str, errors: &mut Vec<ValidationError>) {
+        if !enum_values.contains(value) {
+            let enum_strings: Vec<String> = enum_values.iter().map(|v| v.to_string()).collect();
+            errors.push(ValidationError {
+                field: field_path.to_string(),
+                message: "Value is not in allowed enum list".to_string(),
+                expected: enum_strings.join(", "),
+                actual: value.clone(),
+                context: None,
+            });
+        }
+    }
+}
+
+/// Compile a Manifest pattern once per process, None for invalid patterns
+fn compiled_pattern(pattern: &str) -> Option<Regex> {
+    static PATTERNS: OnceLock<RwLock<HashMap<String, Option<Regex>>>> = OnceLock::new();
+    let patterns = PATTERNS.get_or_init(|| RwLock::new(HashMap::new()));
+
+    if let Some(compiled) = patterns.read().unwrap().get(pattern) {
+        return compiled.clone();
     }
-}
\ No newline at end of file
+    let compiled = Regex::new(pattern).ok();
+    patterns.write().unwrap().insert(pattern.to_string(), compiled.clone());
+    compiled
+}
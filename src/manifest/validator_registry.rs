@@ -0,0 +1,72 @@
+/*!
+ * Validator Registry for Rust Janus Implementation
+ * Named application validators referenced from ValidationManifest entries
+ */
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Custom validator function, returning a failure message for invalid values
+pub type CustomValidator = Arc<dyn Fn(&Value) -> Result<(), String> + Send + Sync>;
+
+/// Registry of named validators for constraints Manifests cannot express declaratively.
+/// `ValidationManifest::validators` lists the names run against a value once its type checks pass.
+/// Cloning is cheap and shares the registered functions.
+#[derive(Clone, Default)]
+pub struct ValidatorRegistry {
+    validators: HashMap<String, CustomValidator>,
+}
+
+impl ValidatorRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a validator under a name, replacing any validator of the same name
+    pub fn register<F>(&mut self, name: &str, validator: F)
+    where
+        F: Fn(&Value) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validators.insert(name.to_string(), Arc::new(validator));
+    }
+
+    /// Register a validator, builder style
+    pub fn with_validator<F>(mut self, name: &str, validator: F) -> Self
+    where
+        F: Fn(&Value) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.register(name, validator);
+        self
+    }
+
+    /// Get a validator by name
+    pub fn get(&self, name: &str) -> Option<&CustomValidator> {
+        self.validators.get(name)
+    }
+
+    /// Check whether a validator is registered
+    pub fn contains(&self, name: &str) -> bool {
+        self.validators.contains_key(name)
+    }
+
+    /// Names of every registered validator, sorted
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.validators.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+
+    /// Check whether no validators are registered
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+}
+
+impl fmt::Debug for ValidatorRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidatorRegistry").field("validators", &self.names()).finish()
+    }
+}
@@ -1,10 +1,16 @@
 use crate::core::{CoreJanusClient, SecurityValidator};
 use crate::error::{JSONRPCError, JSONRPCErrorCode};
 use crate::config::JanusClientConfig;
-use crate::manifest::Manifest;
+use crate::manifest::{ArgumentValidator, DeclaredError, DeprecationWarning, Manifest, ManifestVersion, ResponseValidationMode, ResponseValidator, VersionRequirement};
+use crate::protocol::manifest_cache::{CachedManifest, ManifestCache};
 use crate::protocol::message_types::{JanusRequest, JanusResponse, RequestHandle, RequestStatus};
 use crate::protocol::response_tracker::{ResponseTracker, TrackerConfig, RequestStatistics};
+use log::{info, warn};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
 use std::time::Duration;
 use uuid::Uuid;
 
@@ -40,15 +46,40 @@ impl ConnectionState {
     }
 }
 
+/// Callback receiving the deprecation warnings servers attach to responses
+pub type DeprecationCallback = Arc<dyn Fn(&DeprecationWarning) + Send + Sync>;
+
+/// Where deprecation warnings go: the registered callback, or the log when there is none
+#[derive(Clone, Default)]
+struct DeprecationHandler(Option<DeprecationCallback>);
+
+impl DeprecationHandler {
+    fn report(&self, deprecation: &DeprecationWarning) {
+        match &self.0 {
+            Some(callback) => callback(deprecation),
+            None => warn!("{}", deprecation),
+        }
+    }
+}
+
+impl fmt::Debug for DeprecationHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.0.is_some() { "DeprecationHandler(callback)" } else { "DeprecationHandler(log)" })
+    }
+}
+
 /// High-level API client for SOCK_DGRAM Unix socket communication
 /// Connectionless implementation with request validation and response correlation
 #[derive(Debug)]
 pub struct JanusClient {
     socket_path: String,
-    manifest: Option<Manifest>,
+    manifest: Option<Arc<Manifest>>,
+    manifest_fingerprint: Option<String>,
+    negotiated_manifest_version: Option<ManifestVersion>,
     config: JanusClientConfig,
     core_client: CoreJanusClient,
     response_tracker: ResponseTracker,
+    deprecation_handler: DeprecationHandler,
     connection_state: std::sync::Mutex<ConnectionState>,
     // Request lifecycle management (automatic ID system)
     request_registry: std::sync::Mutex<HashMap<String, RequestHandle>>,
@@ -79,26 +110,41 @@ impl JanusClient {
         Ok(Self {
             socket_path,
             manifest: None,  // Will be fetched during operations when needed
+            manifest_fingerprint: None,
+            negotiated_manifest_version: None,
             config,
             core_client,
             response_tracker,
+            deprecation_handler: DeprecationHandler::default(),
             connection_state: std::sync::Mutex::new(ConnectionState::new()),
             request_registry: std::sync::Mutex::new(HashMap::new()),
         })
     }
     
-    /// Fetch Manifest from server
+    /// Fetch Manifest from server.
+    /// With a `known_hash` the server answers None when its Manifest still has that fingerprint.
     async fn fetch_manifest_from_server(
         core_client: &CoreJanusClient,
-        _config: &JanusClientConfig,
-    ) -> Result<Manifest, JSONRPCError> {
+        config: &JanusClientConfig,
+        known_hash: Option<&str>,
+    ) -> Result<Option<Manifest>, JSONRPCError> {
         // Generate response socket path
         let response_socket_path = core_client.generate_response_socket_path();
         
+        // Let the server refuse a Manifest outside the client's version range
+        let mut args = HashMap::new();
+        if let Some(requirement) = &config.manifest_version_requirement {
+            args.insert("version_requirement".to_string(), serde_json::json!(requirement));
+        }
+        if let Some(known_hash) = known_hash {
+            args.insert("known_hash".to_string(), serde_json::json!(known_hash));
+        }
+        let args = if args.is_empty() { None } else { Some(args) };
+        
         // Create proper JanusRequest for manifest request using constructor
         let mut manifest_request = JanusRequest::new(
             "manifest".to_string(),
-            None,
+            args,
             Some(10.0),
         );
         manifest_request.reply_to = Some(response_socket_path.clone());
@@ -117,6 +163,9 @@ impl JanusClient {
         
         // Check for error in response
         if !response.success {
+            if let Some(error) = response.error.as_ref().filter(|e| e.code == JSONRPCErrorCode::IncompatibleManifestVersion.code()) {
+                return Err(error.clone());
+            }
             let error_msg = response.error
                 .as_ref()
                 .map(|e| e.message.clone())
@@ -128,29 +177,63 @@ impl JanusClient {
         let manifest_data = response.result.as_ref()
             .ok_or_else(|| JSONRPCError::new(JSONRPCErrorCode::InternalError, Some("Server response missing 'result' field".to_string())))?;
         
+        // Servers confirm a known fingerprint instead of resending the manifest
+        if known_hash.is_some() && manifest_data.get("unchanged") == Some(&serde_json::Value::Bool(true)) {
+            return Ok(None);
+        }
+        
         // Parse the manifest
         let manifest: Manifest = serde_json::from_value(manifest_data.clone())
             .map_err(|e| JSONRPCError::new(JSONRPCErrorCode::ParseError, Some(format!("Failed to parse server manifest: {}", e))))?;
         
-        Ok(manifest)
+        Ok(Some(manifest))
     }
     
-    /// Ensure Manifest is loaded, fetching from server if needed
+    /// Ensure a current Manifest is loaded through the Manifest cache shared by clients of this socket.
+    /// Fresh entries are reused, stale ones are revalidated with the server by fingerprint.
     async fn ensure_manifest_loaded(&mut self) -> Result<(), JSONRPCError> {
-        if self.manifest.is_some() {
-            return Ok(()); // Already loaded
-        }
-        
         if !self.config.enable_validation {
             return Ok(()); // Validation disabled, no need to fetch
         }
         
-        // Fetch manifest from server
-        let fetched_manifest = Self::fetch_manifest_from_server(&self.core_client, &self.config).await?;
+        let cached = match ManifestCache::get(&self.socket_path) {
+            Some(cached) if cached.is_fresh(self.config.manifest_cache_ttl) => cached,
+            Some(stale) => {
+                match Self::fetch_manifest_from_server(&self.core_client, &self.config, Some(&stale.fingerprint)).await? {
+                    Some(manifest) => ManifestCache::store(&self.socket_path, manifest),
+                    None => {
+                        ManifestCache::touch(&self.socket_path, &stale.fingerprint);
+                        stale
+                    }
+                }
+            }
+            None => self.fetch_and_cache_manifest().await?,
+        };
         
-        // Channels have been removed from the protocol
+        if self.manifest_fingerprint.as_deref() != Some(cached.fingerprint.as_str()) {
+            self.accept_manifest(cached.manifest, cached.fingerprint)?;
+        }
+        Ok(())
+    }
+    
+    /// Fetch the whole Manifest from the server and replace the shared cache entry
+    async fn fetch_and_cache_manifest(&self) -> Result<CachedManifest, JSONRPCError> {
+        let manifest = Self::fetch_manifest_from_server(&self.core_client, &self.config, None)
+            .await?
+            .ok_or_else(|| JSONRPCError::new(JSONRPCErrorCode::InternalError, Some("Server returned no manifest".to_string())))?;
+        Ok(ManifestCache::store(&self.socket_path, manifest))
+    }
+    
+    /// Check the fetched Manifest against the configured version requirement and cache it
+    fn accept_manifest(&mut self, manifest: Arc<Manifest>, fingerprint: String) -> Result<(), JSONRPCError> {
+        let negotiated_version = match &self.config.manifest_version_requirement {
+            Some(requirement) => Some(VersionRequirement::parse(requirement)?.negotiate(&manifest.version)?),
+            None => ManifestVersion::parse(&manifest.version).ok(),
+        };
         
-        self.manifest = Some(fetched_manifest);
+        self.manifest = Some(manifest);
+        self.manifest_fingerprint = Some(fingerprint);
+        self.negotiated_manifest_version = negotiated_version;
         Ok(())
     }
     
@@ -176,6 +259,13 @@ impl JanusClient {
         
         // Apply security validation
         SecurityValidator::validate_request_name(request, &self.config)?;
+        
+        // Ensure Manifest is loaded for coercion, defaults and validation
+        if self.config.enable_validation {
+            self.ensure_manifest_loaded().await?;
+        }
+        self.prepare_request_args(&mut socket_request);
+        
         SecurityValidator::validate_args_size(&socket_request.args, &self.config)?;
         SecurityValidator::validate_socket_path(&response_socket_path)?;
         
@@ -186,11 +276,6 @@ impl JanusClient {
         // Validate message size
         SecurityValidator::validate_message_size(request_data.len(), &self.config)?;
         
-        // Ensure Manifest is loaded for validation
-        if self.config.enable_validation {
-            self.ensure_manifest_loaded().await?;
-        }
-        
         // Validate request against Manifest (skip for built-in requests)
         if let Some(ref manifest) = self.manifest {
             if !Self::is_builtin_request(request) {
@@ -215,9 +300,59 @@ impl JanusClient {
         // Update connection state after successful communication
         self.update_connection_state(1, 1);
         
+        for deprecation in response.deprecations.iter().flatten() {
+            self.deprecation_handler.report(deprecation);
+        }
+        self.check_response(request, &response)?;
         Ok(response)
     }
     
+    /// Send a request with arguments serialized from `args` and deserialize its result.
+    /// Arguments must serialize to an object, or to null for requests without arguments.
+    /// Error responses are returned as their `JSONRPCError`.
+    pub async fn send_typed_request<A, R>(
+        &mut self,
+        request: &str,
+        args: &A,
+        timeout: Option<Duration>,
+    ) -> Result<R, JSONRPCError>
+    where
+        A: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        let args = match serde_json::to_value(args) {
+            Ok(serde_json::Value::Object(object)) => Some(object.into_iter().collect()),
+            Ok(serde_json::Value::Null) => None,
+            Ok(other) => {
+                return Err(JSONRPCError::new(
+                    JSONRPCErrorCode::InvalidParams,
+                    Some(format!("Arguments for request '{}' must be an object, got {}", request, other)),
+                ));
+            }
+            Err(e) => {
+                return Err(JSONRPCError::new(
+                    JSONRPCErrorCode::InvalidParams,
+                    Some(format!("Failed to serialize arguments for request '{}': {}", request, e)),
+                ));
+            }
+        };
+
+        let response = self.send_request(request, args, timeout).await?;
+        if !response.success {
+            return Err(response.error.unwrap_or_else(|| JSONRPCError::new(
+                JSONRPCErrorCode::InternalError,
+                Some(format!("Request '{}' failed without error details", request)),
+            )));
+        }
+
+        serde_json::from_value(response.result.unwrap_or(serde_json::Value::Null)).map_err(|e| {
+            JSONRPCError::new(
+                JSONRPCErrorCode::ParseError,
+                Some(format!("Failed to deserialize result of request '{}': {}", request, e)),
+            )
+        })
+    }
+    
     /// Send request without expecting response (fire-and-forget)
     pub async fn send_request_no_response(
         &self,
@@ -238,6 +373,7 @@ impl JanusClient {
         
         // Apply security validation
         SecurityValidator::validate_request_name(request, &self.config)?;
+        self.prepare_request_args(&mut socket_request);
         SecurityValidator::validate_args_size(&socket_request.args, &self.config)?;
         
         // Serialize request for message size validation
@@ -266,10 +402,38 @@ impl JanusClient {
         self.core_client.test_connection().await
     }
     
+    /// Coerce mistyped arguments per the configured mode and fill in Manifest defaults
+    /// for arguments the caller omitted.
+    /// Defaults of deprecated arguments are left to the server, which only reports explicit uses.
+    fn prepare_request_args(&self, request: &mut JanusRequest) {
+        let manifest = match &self.manifest {
+            Some(manifest) => manifest,
+            None => return,
+        };
+        let request_manifest = match manifest.get_request_manifest(&request.request) {
+            Some(request_manifest) => request_manifest,
+            None => return,
+        };
+        
+        let validator = ArgumentValidator::new(manifest).with_coercion(self.config.coercion_mode);
+        for coercion in validator.coerce_args(&mut request.args, request_manifest) {
+            info!(
+                "Coerced argument '{}' of request '{}' from {} to {} {}",
+                coercion.field, request.request, coercion.from, coercion.r#type, coercion.to
+            );
+        }
+        let applied = request_manifest.apply_defaults(&mut request.args);
+        if let Some(args) = request.args.as_mut() {
+            for name in applied.iter().filter(|name| request_manifest.args[*name].is_deprecated()) {
+                args.remove(name);
+            }
+        }
+    }
+    
     /// Validate request against Manifest
     fn validate_request_against_manifest(
         &self,
-        _manifest: &Manifest,
+        manifest: &Manifest,
         request: &JanusRequest,
     ) -> Result<(), JSONRPCError> {
         // Check if request is reserved (built-in requests should never be in Manifests)
@@ -278,11 +442,77 @@ impl JanusClient {
             return Err(JSONRPCError::new(JSONRPCErrorCode::ValidationFailed, Some(format!("Request '{}' is reserved and cannot be used from Manifest", request.request))));
         }
         
-        // Since channels are removed, get request manifest from server
-        // For now, skip validation as server will handle it
+        // Requests not defined in the Manifest are left for the server to resolve
+        let request_manifest = match manifest.get_request_manifest(&request.request) {
+            Some(request_manifest) => request_manifest,
+            None => return Ok(()),
+        };
+        
+        let validator = ArgumentValidator::new(manifest);
+        let result = validator.validate_args(request.args.as_ref(), request_manifest);
+        if !result.valid {
+            return Err(result.to_jsonrpc_error(
+                JSONRPCErrorCode::ManifestValidationError,
+                &format!("Arguments for request '{}' failed validation", request.request),
+            ));
+        }
+        
         Ok(())
     }
     
+    /// Match an error returned for `request` to the error code the fetched Manifest declares for it,
+    /// so callers can match on documented error names instead of raw codes.
+    /// Returns None without a Manifest or when the error is not declared.
+    pub fn declared_error(&self, request: &str, error: &JSONRPCError) -> Option<DeclaredError> {
+        self.manifest.as_ref()?.get_request_manifest(request)?.declared_error(error)
+    }
+    
+    /// Pass the deprecation warnings servers attach to responses to a callback instead of logging them.
+    /// Warnings stay available on `JanusResponse::deprecations` either way.
+    pub fn on_deprecation<F>(&mut self, callback: F)
+    where
+        F: Fn(&DeprecationWarning) + Send + Sync + 'static,
+    {
+        self.deprecation_handler = DeprecationHandler(Some(Arc::new(callback)));
+    }
+    
+    /// Check a successful result against the response definition of its request
+    /// per the configured response validation mode.
+    /// Built-in requests and requests the Manifest does not define are not checked.
+    fn check_response(&self, request: &str, response: &JanusResponse) -> Result<(), JSONRPCError> {
+        if self.config.response_validation == ResponseValidationMode::Off || !response.success || Self::is_builtin_request(request) {
+            return Ok(());
+        }
+        let manifest = match &self.manifest {
+            Some(manifest) if manifest.has_request(request) => manifest,
+            _ => return Ok(()),
+        };
+        
+        let result = response.result.as_ref().unwrap_or(&serde_json::Value::Null);
+        let validation = ResponseValidator::new(manifest).validate_request_response(result, request);
+        if validation.valid {
+            return Ok(());
+        }
+        
+        if self.config.response_validation == ResponseValidationMode::Reject {
+            return Err(validation.to_jsonrpc_error(
+                JSONRPCErrorCode::ManifestValidationError,
+                &format!("Result of request '{}' failed validation", request),
+            ));
+        }
+        for error in &validation.errors {
+            warn!("Result of request '{}' (ID: {}) failed manifest validation: {}", request, response.request_id, error);
+        }
+        Ok(())
+    }
+    
+    /// Fetch the Manifest served by the server, replacing any cached copy
+    pub async fn fetch_manifest(&mut self) -> Result<Manifest, JSONRPCError> {
+        let cached = self.fetch_and_cache_manifest().await?;
+        self.accept_manifest(Arc::clone(&cached.manifest), cached.fingerprint)?;
+        Ok((*cached.manifest).clone())
+    }
+    
     /// Get channel ID
     pub fn channel_id(&self) -> &str {
         ""
@@ -295,7 +525,12 @@ impl JanusClient {
     
     /// Get Manifest
     pub fn manifest(&self) -> Option<&Manifest> {
-        self.manifest.as_ref()
+        self.manifest.as_deref()
+    }
+    
+    /// Get the Manifest version accepted during negotiation, once a Manifest has been fetched
+    pub fn negotiated_manifest_version(&self) -> Option<ManifestVersion> {
+        self.negotiated_manifest_version
     }
     
     /// Get configuration for backward compatibility
@@ -526,9 +761,12 @@ impl JanusClient {
         Self {
             socket_path: self.socket_path.clone(),
             manifest: self.manifest.clone(),
+            manifest_fingerprint: self.manifest_fingerprint.clone(),
+            negotiated_manifest_version: self.negotiated_manifest_version,
             config: self.config.clone(),
             core_client: self.core_client.clone(),
             response_tracker: self.response_tracker.clone(),
+            deprecation_handler: self.deprecation_handler.clone(),
             connection_state: std::sync::Mutex::new(ConnectionState::new()),
             request_registry: std::sync::Mutex::new(self.request_registry.lock().unwrap().clone()),
         }
@@ -624,9 +862,12 @@ impl Clone for JanusClient {
         Self {
             socket_path: self.socket_path.clone(),
             manifest: self.manifest.clone(),
+            manifest_fingerprint: self.manifest_fingerprint.clone(),
+            negotiated_manifest_version: self.negotiated_manifest_version,
             config: self.config.clone(),
             core_client: self.core_client.clone(),
             response_tracker,
+            deprecation_handler: self.deprecation_handler.clone(),
             connection_state: std::sync::Mutex::new(ConnectionState::new()),
             request_registry: std::sync::Mutex::new(HashMap::new()),
         }
@@ -0,0 +1,77 @@
+/*!
+ * Manifest Cache for Rust Janus Implementation
+ * Process-wide cache of server Manifests shared by every client of the same socket path
+ */
+
+use crate::manifest::Manifest;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// A Manifest fetched from a server together with its fingerprint.
+/// Cloning an entry shares the Manifest instead of copying it.
+#[derive(Debug, Clone)]
+pub struct CachedManifest {
+    /// The served Manifest
+    pub manifest: Arc<Manifest>,
+
+    /// Content fingerprint, see `Manifest::fingerprint`
+    pub fingerprint: String,
+
+    /// When the server last confirmed this Manifest
+    pub validated_at: Instant,
+}
+
+impl CachedManifest {
+    /// Check whether the entry was confirmed by the server within `ttl`
+    pub fn is_fresh(&self, ttl: Duration) -> bool {
+        self.validated_at.elapsed() < ttl
+    }
+}
+
+/// Manifest cache keyed by server socket path.
+/// Clients reuse fresh entries and revalidate stale ones by fingerprint,
+/// so only a changed Manifest is transferred again.
+pub struct ManifestCache;
+
+impl ManifestCache {
+    fn entries() -> &'static Mutex<HashMap<String, CachedManifest>> {
+        static ENTRIES: OnceLock<Mutex<HashMap<String, CachedManifest>>> = OnceLock::new();
+        ENTRIES.get_or_init(|| Mutex::new(HashMap::new()))
+    }
+
+    /// Get the cached Manifest for a socket path
+    pub fn get(socket_path: &str) -> Option<CachedManifest> {
+        Self::entries().lock().unwrap().get(socket_path).cloned()
+    }
+
+    /// Cache a Manifest freshly fetched from a server
+    pub fn store(socket_path: &str, manifest: Manifest) -> CachedManifest {
+        let entry = CachedManifest {
+            fingerprint: manifest.fingerprint(),
+            manifest: Arc::new(manifest),
+            validated_at: Instant::now(),
+        };
+        Self::entries().lock().unwrap().insert(socket_path.to_string(), entry.clone());
+        entry
+    }
+
+    /// Record that the server confirmed the cached Manifest is unchanged
+    pub fn touch(socket_path: &str, fingerprint: &str) {
+        if let Some(entry) = Self::entries().lock().unwrap().get_mut(socket_path) {
+            if entry.fingerprint == fingerprint {
+                entry.validated_at = Instant::now();
+            }
+        }
+    }
+
+    /// Drop the cached Manifest for a socket path, forcing the next client to refetch it
+    pub fn invalidate(socket_path: &str) {
+        Self::entries().lock().unwrap().remove(socket_path);
+    }
+
+    /// Drop every cached Manifest
+    pub fn clear() {
+        Self::entries().lock().unwrap().clear();
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use std::time::SystemTime;
 use chrono; // PRIME DIRECTIVE: Required for RFC 3339 timestamp format
 use crate::error::{JSONRPCError, JSONRPCErrorCode};
+use crate::manifest::DeprecationWarning;
 
 /// Socket request structure (PRIME DIRECTIVE: exact cross-language parity with Go/Swift/TypeScript)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -131,6 +132,10 @@ pub struct JanusResponse {
     
     /// Response timestamp (RFC 3339 format)
     pub timestamp: String,
+    
+    /// Deprecated requests and arguments the request used (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecations: Option<Vec<DeprecationWarning>>,
 }
 
 impl JanusResponse {
@@ -148,6 +153,7 @@ impl JanusResponse {
             request_id,
             id: uuid::Uuid::new_v4().to_string(),
             timestamp,
+            deprecations: None,
         }
     }
     
@@ -165,10 +171,17 @@ impl JanusResponse {
             request_id,
             id: uuid::Uuid::new_v4().to_string(),
             timestamp,
+            deprecations: None,
         }
     }
 
     
+    /// Attach deprecation warnings, leaving the field unset when there are none
+    pub fn with_deprecations(mut self, deprecations: Vec<DeprecationWarning>) -> Self {
+        self.deprecations = if deprecations.is_empty() { None } else { Some(deprecations) };
+        self
+    }
+    
     /// Create internal error response (PRIME DIRECTIVE format)
     pub fn internal_error(
         request_id: String,
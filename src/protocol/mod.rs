@@ -4,10 +4,12 @@ pub mod janus_client;
 pub mod timeout_manager;
 pub mod response_tracker;
 pub mod request_handler;
+pub mod manifest_cache;
 
 pub use message_types::{JanusRequest, JanusResponse, SocketMessage, MessageType};
 pub use message_framing::{MessageFraming, MessageFramingMessage};
-pub use janus_client::{JanusClient, ConnectionState};
+pub use janus_client::{JanusClient, ConnectionState, DeprecationCallback};
+pub use manifest_cache::{ManifestCache, CachedManifest};
 pub use timeout_manager::TimeoutManager;
 pub use response_tracker::{ResponseTracker, TrackerConfig, RequestStatistics, RequestInfo};
 pub use request_handler::{RequestHandler, HandlerRegistry, HandlerResult, SyncHandler, AsyncHandler};
\ No newline at end of file
@@ -1,15 +1,22 @@
 use std::collections::HashMap;
 use std::os::unix::net::UnixDatagram;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use tokio::sync::Mutex;
+use std::time::SystemTime;
+use tokio::sync::{Mutex, RwLock};
 use tokio::task::JoinHandle;
 use tokio::time::Duration;
+use serde::{de::DeserializeOwned, Serialize};
 use serde_json;
 use std::fs;
 
 use crate::protocol::message_types::{JanusRequest, JanusResponse};
 use crate::error::{JSONRPCError, JSONRPCErrorCode};
+use crate::manifest::{
+    ArgumentValidator, CoercionMode, DeprecationWarning, Manifest, ManifestModel, ManifestParser, ManifestSchema, RequestManifest, ResponseValidator,
+    ValidatorRegistry, VersionRequirement,
+};
 use log::{debug, info, warn, error};
 
 /// Server configuration structure matching other implementations
@@ -42,14 +49,44 @@ pub type JanusRequestHandler = Box<dyn Fn(JanusRequest) -> Result<serde_json::Va
 /// Async request handler function type for SOCK_DGRAM server
 pub type JanusAsyncRequestHandler = Box<dyn Fn(JanusRequest) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<serde_json::Value, JSONRPCError>> + Send>> + Send + Sync>;
 
+/// Shared Manifest used for request validation
+pub type SharedManifest = Arc<RwLock<Option<Manifest>>>;
+
+/// How the server checks requests and handler results against its Manifest
+#[derive(Debug, Clone, Default)]
+pub struct RequestPolicy {
+    /// How string arguments of mistyped requests are treated before validation
+    pub coercion_mode: CoercionMode,
+
+    /// Named validators that Manifest validation constraints can reference
+    pub validators: ValidatorRegistry,
+
+    /// Replace handler results that violate the Manifest, and handler errors
+    /// the request does not declare, with an InternalError response
+    pub strict_responses: bool,
+}
+
+/// Request policy shared with the listen loop, so changes apply while the server is listening
+pub type SharedPolicy = Arc<RwLock<RequestPolicy>>;
+
+/// Modification time and contents of each watched Manifest file, None while a file cannot be read
+type ManifestFingerprint = Vec<Option<(Option<SystemTime>, Vec<u8>)>>;
+
+/// Built-in requests handled by the server and never defined in Manifests
+const BUILTIN_REQUESTS: [&str; 6] = ["ping", "echo", "get_info", "validate", "slow_process", "manifest"];
+
 /// High-level SOCK_DGRAM Unix socket server
 /// Handles request routing and response generation for connectionless communication
 pub struct JanusServer {
     config: ServerConfig,
     handlers: Arc<Mutex<HashMap<String, JanusRequestHandler>>>,
     async_handlers: Arc<Mutex<HashMap<String, JanusAsyncRequestHandler>>>,
+    manifest: SharedManifest,
+    registered_manifest: Arc<RwLock<Manifest>>,
+    policy: SharedPolicy,
     is_running: Arc<AtomicBool>,
     server_task: Option<JoinHandle<Result<(), JSONRPCError>>>,
+    manifest_watcher: Option<JoinHandle<()>>,
 }
 
 impl JanusServer {
@@ -60,8 +97,12 @@ impl JanusServer {
             config,
             handlers: Arc::new(Mutex::new(HashMap::new())),
             async_handlers: Arc::new(Mutex::new(HashMap::new())),
+            manifest: Arc::new(RwLock::new(None)),
+            registered_manifest: Arc::new(RwLock::new(Manifest::new("1.0.0".to_string()))),
+            policy: Arc::new(RwLock::new(RequestPolicy::default())),
             is_running: Arc::new(AtomicBool::new(false)),
             server_task: None,
+            manifest_watcher: None,
         }
     }
 
@@ -87,6 +128,254 @@ impl JanusServer {
         async_handlers.insert(request.to_string(), async_handler);
     }
 
+    /// Register a request handler together with its RequestManifest definition.
+    /// The definition is served by the built-in `manifest` request and used for validation.
+    pub async fn register_handler_with_manifest<F>(&mut self, request: &str, request_manifest: RequestManifest, handler: F)
+    where
+        F: Fn(JanusRequest) -> Result<serde_json::Value, JSONRPCError> + Send + Sync + 'static,
+    {
+        self.register_handler(request, handler).await;
+        self.add_request_manifest(request, request_manifest).await;
+    }
+
+    /// Register an asynchronous request handler together with its RequestManifest definition
+    pub async fn register_async_handler_with_manifest<F, Fut>(&mut self, request: &str, request_manifest: RequestManifest, handler: F)
+    where
+        F: Fn(JanusRequest) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Result<serde_json::Value, JSONRPCError>> + Send + 'static,
+    {
+        self.register_async_handler(request, handler).await;
+        self.add_request_manifest(request, request_manifest).await;
+    }
+
+    /// Register a handler taking typed arguments and returning a typed result.
+    /// The request definition and its models are derived from `A` and `R`.
+    pub async fn register_typed_handler<A, R, F>(&mut self, request: &str, description: &str, handler: F)
+    where
+        A: ManifestModel + DeserializeOwned + 'static,
+        R: ManifestSchema + Serialize + 'static,
+        F: Fn(A) -> Result<R, JSONRPCError> + Send + Sync + 'static,
+    {
+        self.register_handler(request, move |cmd| {
+            let args = Self::decode_typed_args::<A>(&cmd)?;
+            Self::encode_typed_result(handler(args)?)
+        }).await;
+        self.registered_manifest.write().await.add_typed_request::<A, R>(request, description);
+        self.publish_registered_manifest().await;
+    }
+
+    /// Register an asynchronous handler taking typed arguments and returning a typed result
+    pub async fn register_typed_async_handler<A, R, F, Fut>(&mut self, request: &str, description: &str, handler: F)
+    where
+        A: ManifestModel + DeserializeOwned + Send + 'static,
+        R: ManifestSchema + Serialize + 'static,
+        F: Fn(A) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Result<R, JSONRPCError>> + Send + 'static,
+    {
+        let handler = Arc::new(handler);
+        self.register_async_handler(request, move |cmd| {
+            let handler = Arc::clone(&handler);
+            async move {
+                let args = Self::decode_typed_args::<A>(&cmd)?;
+                Self::encode_typed_result(handler(args).await?)
+            }
+        }).await;
+        self.registered_manifest.write().await.add_typed_request::<A, R>(request, description);
+        self.publish_registered_manifest().await;
+    }
+
+    /// Deserialize request arguments into a typed argument struct, or into `()` when there are none.
+    /// Public for generated service code, failures map to `InvalidParams`.
+    pub fn decode_typed_args<A: DeserializeOwned>(cmd: &JanusRequest) -> Result<A, JSONRPCError> {
+        let args = cmd.args.clone().unwrap_or_default();
+        let empty = args.is_empty();
+        serde_json::from_value(serde_json::Value::Object(args.into_iter().collect()))
+            .or_else(|e| match serde_json::from_value(serde_json::Value::Null) {
+                Ok(unit) if empty => Ok(unit),
+                _ => Err(e),
+            })
+            .map_err(|e| JSONRPCError::new(
+                JSONRPCErrorCode::InvalidParams,
+                Some(format!("Invalid arguments for request '{}': {}", cmd.request, e)),
+            ))
+    }
+
+    /// Serialize a typed handler result
+    pub fn encode_typed_result<R: Serialize>(result: R) -> Result<serde_json::Value, JSONRPCError> {
+        serde_json::to_value(result).map_err(|e| JSONRPCError::new(
+            JSONRPCErrorCode::InternalError,
+            Some(format!("Failed to serialize handler result: {}", e)),
+        ))
+    }
+
+    /// Record a registered RequestManifest and expose it through the served Manifest
+    async fn add_request_manifest(&mut self, request: &str, request_manifest: RequestManifest) {
+        self.registered_manifest.write().await.add_request(request.to_string(), request_manifest);
+        self.publish_registered_manifest().await;
+    }
+
+    /// Add registered definitions to the served Manifest, creating it when none is attached
+    async fn publish_registered_manifest(&self) {
+        let registered = self.registered_manifest.read().await;
+        let mut manifest_guard = self.manifest.write().await;
+        let manifest = manifest_guard.get_or_insert_with(|| Manifest::new("1.0.0".to_string()));
+        Self::fill_registered_definitions(&registered, manifest);
+    }
+
+    /// Fill in requests and models the Manifest lacks from definitions registered with handlers
+    fn fill_registered_definitions(registered: &Manifest, manifest: &mut Manifest) {
+        if let Some(requests) = &registered.requests {
+            for (request, request_manifest) in requests {
+                if !manifest.has_request(request) {
+                    manifest.add_request(request.clone(), request_manifest.clone());
+                }
+            }
+        }
+        if let Some(models) = &registered.models {
+            for (model_name, model) in models {
+                if manifest.get_model(model_name).is_none() {
+                    manifest.add_model(model_name.clone(), model.clone());
+                }
+            }
+        }
+    }
+
+    /// Attach a Manifest used to validate request arguments before dispatch.
+    /// Definitions registered with handlers fill in requests the Manifest does not define.
+    pub async fn set_manifest(&mut self, manifest: Manifest) {
+        Self::attach_manifest(&self.manifest, &self.registered_manifest, manifest).await;
+    }
+
+    /// Complete a Manifest with the registered definitions and swap it in as the served one
+    async fn attach_manifest(served: &SharedManifest, registered: &RwLock<Manifest>, mut manifest: Manifest) {
+        Self::fill_registered_definitions(&*registered.read().await, &mut manifest);
+        info!("Server manifest attached: version {}, {} requests", manifest.version, manifest.request_names().len());
+        *served.write().await = Some(manifest);
+    }
+
+    /// Load, validate and attach a Manifest file (JSON or YAML)
+    pub async fn load_manifest(&mut self, path: &str) -> Result<(), JSONRPCError> {
+        let manifest = ManifestParser::load_and_validate(path).await?;
+        self.set_manifest(manifest).await;
+        Ok(())
+    }
+
+    /// Load a Manifest file and reload it whenever it or one of its includes changes, checking every `poll_interval`.
+    /// A changed file is swapped in only if it validates, otherwise the current Manifest is kept.
+    /// The set of watched includes is resolved again after every change.
+    pub async fn watch_manifest(&mut self, path: &str, poll_interval: Duration) -> Result<(), JSONRPCError> {
+        self.unwatch_manifest();
+
+        // Fingerprint before loading so a change made during the load is picked up
+        let files = ManifestParser::include_files(path).await;
+        let fingerprint = Self::manifest_fingerprint(&files).await;
+        self.load_manifest(path).await?;
+
+        let watcher = Self::watch_loop(
+            path.to_string(),
+            poll_interval,
+            files,
+            fingerprint,
+            Arc::clone(&self.manifest),
+            Arc::clone(&self.registered_manifest),
+        );
+        self.manifest_watcher = Some(tokio::spawn(watcher));
+        info!("Watching manifest {} for changes every {:?}", path, poll_interval);
+        Ok(())
+    }
+
+    /// Stop watching the Manifest file, keeping the Manifest currently attached
+    pub fn unwatch_manifest(&mut self) {
+        if let Some(watcher) = self.manifest_watcher.take() {
+            watcher.abort();
+        }
+    }
+
+    /// Check whether the Manifest file is being watched
+    pub fn is_watching_manifest(&self) -> bool {
+        self.manifest_watcher.is_some()
+    }
+
+    /// Poll the Manifest files and swap in every changed version that validates
+    async fn watch_loop(
+        path: String,
+        poll_interval: Duration,
+        mut files: Vec<PathBuf>,
+        mut fingerprint: ManifestFingerprint,
+        served: SharedManifest,
+        registered: Arc<RwLock<Manifest>>,
+    ) {
+        loop {
+            tokio::time::sleep(poll_interval).await;
+
+            if Self::manifest_fingerprint(&files).await == fingerprint {
+                continue;
+            }
+            files = ManifestParser::include_files(&path).await;
+            fingerprint = Self::manifest_fingerprint(&files).await;
+
+            match ManifestParser::load_and_validate(&path).await {
+                Ok(manifest) => {
+                    info!("Manifest {} changed, reloading version {}", path, manifest.version);
+                    Self::attach_manifest(&served, &registered, manifest).await;
+                }
+                Err(e) => warn!("Manifest {} changed but failed to load, keeping the current manifest: {}", path, e),
+            }
+        }
+    }
+
+    /// Fingerprint the Manifest files in order
+    async fn manifest_fingerprint(files: &[PathBuf]) -> ManifestFingerprint {
+        let mut fingerprint = Vec::with_capacity(files.len());
+        for file in files {
+            let modified = tokio::fs::metadata(file).await.ok().and_then(|metadata| metadata.modified().ok());
+            let contents = tokio::fs::read(file).await.ok();
+            fingerprint.push(contents.map(|contents| (modified, contents)));
+        }
+        fingerprint
+    }
+
+    /// Detach the Manifest and stop watching its file, keeping only definitions registered with handlers
+    pub async fn clear_manifest(&mut self) {
+        self.unwatch_manifest();
+        let registered = self.registered_manifest.read().await;
+        let manifest = if registered.requests.is_some() {
+            Some(registered.clone())
+        } else {
+            None
+        };
+        *self.manifest.write().await = manifest;
+    }
+
+    /// Set how string arguments of mistyped requests are treated before validation.
+    /// Applies to requests received from then on, including while the server is listening.
+    pub async fn set_coercion_mode(&mut self, coercion_mode: CoercionMode) {
+        self.policy.write().await.coercion_mode = coercion_mode;
+    }
+
+    /// Register a named validator that Manifest validation constraints can reference.
+    /// Applies to requests received from then on, including while the server is listening.
+    pub async fn register_validator<F>(&mut self, name: &str, validator: F)
+    where
+        F: Fn(&serde_json::Value) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.policy.write().await.validators.register(name, validator);
+    }
+
+    /// Check every handler result against the response definition of its request.
+    /// Results that violate the Manifest are replaced with an InternalError listing the violations,
+    /// as are handler errors outside the request's declared `error_codes`,
+    /// catching contract bugs in test environments. Built-in requests are not checked.
+    /// Applies to requests received from then on, including while the server is listening.
+    pub async fn set_strict_responses(&mut self, strict: bool) {
+        self.policy.write().await.strict_responses = strict;
+    }
+
+    /// Get a copy of the Manifest served by this server
+    pub async fn manifest(&self) -> Option<Manifest> {
+        self.manifest.read().await.clone()
+    }
+
     /// Start listening on the configured socket path using SOCK_DGRAM
     /// Returns immediately, runs server in background task
     pub async fn start_listening(&mut self) -> Result<(), JSONRPCError> {
@@ -107,11 +396,13 @@ impl JanusServer {
         let path = self.config.socket_path.clone();
         let handlers = Arc::clone(&self.handlers);
         let async_handlers = Arc::clone(&self.async_handlers);
+        let manifest = Arc::clone(&self.manifest);
+        let policy = Arc::clone(&self.policy);
         let is_running = Arc::clone(&self.is_running);
         let _cleanup_on_shutdown = self.config.cleanup_on_shutdown;
 
         // Spawn the listen loop and store the task handle
-        let task_handle = tokio::spawn(Self::listen_loop(path, handlers, async_handlers, is_running));
+        let task_handle = tokio::spawn(Self::listen_loop(path, handlers, async_handlers, manifest, policy, is_running));
         self.server_task = Some(task_handle);
         
         // Give the server a moment to bind the socket
@@ -123,6 +414,7 @@ impl JanusServer {
     /// Stop the server
     pub fn stop(&mut self) {
         self.is_running.store(false, Ordering::SeqCst);
+        self.unwatch_manifest();
         
         // Clean up socket file if configured
         if self.config.cleanup_on_shutdown && !self.config.socket_path.is_empty() {
@@ -165,6 +457,8 @@ impl JanusServer {
         socket_path: String,
         _handlers: Arc<Mutex<HashMap<String, JanusRequestHandler>>>,
         _async_handlers: Arc<Mutex<HashMap<String, JanusAsyncRequestHandler>>>,
+        manifest: SharedManifest,
+        policy: SharedPolicy,
         is_running: Arc<AtomicBool>,
     ) -> Result<(), JSONRPCError> {
         debug!("listen_loop starting for socket: {}", socket_path);
@@ -216,7 +510,7 @@ impl JanusServer {
                                 debug!("Processing request and sending response to: {}", reply_to);
                                 
                                 let start_time = std::time::Instant::now();
-                                let response = Self::process_request(&cmd, &_handlers, &_async_handlers).await;
+                                let response = Self::process_request(cmd, &_handlers, &_async_handlers, &manifest, &policy).await;
                                 debug!("Generated response: success={}, has_result={}", response.success, response.result.is_some());
                                 Self::send_response_sync(response, &reply_to);
                                 debug!("Response processing took: {:?}", start_time.elapsed());
@@ -288,18 +582,6 @@ impl JanusServer {
                                 "protocol": "SOCK_DGRAM"
                             }))
                         ),
-                        "manifest" => {
-                            debug!("Processing manifest request");
-                            // Return a minimal manifest
-                            let manifest = serde_json::json!({
-                                "version": "1.0.0",
-                                "name": "Rust Janus Server API",
-                                "description": "Rust implementation of Janus SOCK_DGRAM server"
-                            });
-                            let response = JanusResponse::success(cmd.id.clone(), Some(manifest));
-                            debug!("Created manifest response: success={}, has_result={}", response.success, response.result.is_some());
-                            response
-                        },
                         "validate" => {
                             let result = if let Some(message) = cmd.args.as_ref()
                                 .and_then(|args| args.get("message"))
@@ -375,12 +657,75 @@ impl JanusServer {
         }
     }
 
-    #[allow(dead_code)]
+    /// Coerce mistyped arguments per the policy, fill in Manifest defaults for omitted ones
+    /// and validate the result, including the registered named validators.
+    /// Returns the deprecated request and arguments the caller used, each logged as a warning.
+    /// Built-in requests and requests the Manifest does not define are left untouched.
+    async fn prepare_request_args(
+        cmd: &mut JanusRequest,
+        manifest: &SharedManifest,
+        policy: &SharedPolicy,
+    ) -> Result<Vec<DeprecationWarning>, JSONRPCError> {
+        if BUILTIN_REQUESTS.contains(&cmd.request.as_str()) {
+            return Ok(Vec::new());
+        }
+
+        let manifest_guard = manifest.read().await;
+        let manifest = match manifest_guard.as_ref() {
+            Some(manifest) => manifest,
+            None => return Ok(Vec::new()),
+        };
+        let request_manifest = match manifest.get_request_manifest(&cmd.request) {
+            Some(request_manifest) => request_manifest,
+            None => return Ok(Vec::new()),
+        };
+
+        // Only arguments the caller supplied count, not filled in defaults
+        let deprecations = request_manifest.deprecation_warnings(&cmd.request, cmd.args.as_ref());
+        for deprecation in &deprecations {
+            warn!("{} (ID: {})", deprecation, cmd.id);
+        }
+
+        let policy = policy.read().await;
+        let validator = ArgumentValidator::new(manifest).with_coercion(policy.coercion_mode).with_validators(&policy.validators);
+        for coercion in validator.coerce_args(&mut cmd.args, request_manifest) {
+            info!(
+                "Coerced argument '{}' of request '{}' (ID: {}) from {} to {} {}",
+                coercion.field, cmd.request, cmd.id, coercion.from, coercion.r#type, coercion.to
+            );
+        }
+
+        let applied = request_manifest.apply_defaults(&mut cmd.args);
+        if !applied.is_empty() {
+            debug!("Applied manifest defaults for {:?} to request '{}' (ID: {})", applied, cmd.request, cmd.id);
+        }
+
+        let result = validator.validate_args(cmd.args.as_ref(), request_manifest);
+        if result.valid {
+            return Ok(deprecations);
+        }
+
+        debug!("Request '{}' (ID: {}) rejected with {} validation errors", cmd.request, cmd.id, result.errors.len());
+        Err(result.to_jsonrpc_error(
+            JSONRPCErrorCode::InvalidParams,
+            &format!("Arguments for request '{}' failed manifest validation", cmd.request),
+        ))
+    }
+
     async fn process_request(
-        cmd: &JanusRequest,
+        mut cmd: JanusRequest,
         handlers: &Arc<Mutex<HashMap<String, JanusRequestHandler>>>,
         async_handlers: &Arc<Mutex<HashMap<String, JanusAsyncRequestHandler>>>,
+        manifest: &SharedManifest,
+        policy: &SharedPolicy,
     ) -> JanusResponse {
+        // Complete and check arguments before any handler runs
+        let deprecations = match Self::prepare_request_args(&mut cmd, manifest, policy).await {
+            Ok(deprecations) => deprecations,
+            Err(e) => return JanusResponse::error(cmd.id.clone(), e),
+        };
+        let cmd = &cmd;
+
         // Check async handlers first
         let async_handlers_guard = async_handlers.lock().await;
         let response = if let Some(async_handler) = async_handlers_guard.get(&cmd.request) {
@@ -483,13 +828,10 @@ impl JanusServer {
                 }
                 "manifest" => {
                     debug!("Processing manifest request");
-                    // Return a minimal manifest (matching sync handler)
-                    let manifest = serde_json::json!({
-                        "version": "1.0.0",
-                        "name": "Rust Janus Server API",
-                        "description": "Rust implementation of Janus SOCK_DGRAM server"
-                    });
-                    let response = JanusResponse::success(cmd.id.clone(), Some(manifest));
+                    let response = match Self::serve_manifest(manifest, cmd).await {
+                        Ok(result) => JanusResponse::success(cmd.id.clone(), Some(result)),
+                        Err(e) => JanusResponse::error(cmd.id.clone(), e),
+                    };
                     debug!("Created manifest response: success={}, has_result={}", response.success, response.result.is_some());
                     response
                 }
@@ -517,7 +859,151 @@ impl JanusServer {
             }
         };
 
-        response
+        let strict = policy.read().await.strict_responses;
+        let response = Self::resolve_declared_error(response, cmd, manifest, strict).await;
+        let response = if strict {
+            Self::check_response(response, cmd, manifest, policy).await
+        } else {
+            response
+        };
+        response.with_deprecations(deprecations)
+    }
+
+    /// Map a handler error onto the error code its request declares, by the error's name or code.
+    /// Named errors get the declared code and message, and errors matched by code get their name.
+    /// In strict mode, names the request does not declare and application codes outside
+    /// its declared ones are replaced with an InternalError. Standard JSON-RPC codes always pass.
+    async fn resolve_declared_error(
+        response: JanusResponse,
+        cmd: &JanusRequest,
+        manifest: &SharedManifest,
+        strict: bool,
+    ) -> JanusResponse {
+        let mut error = match response.error {
+            Some(ref error) if !response.success && !BUILTIN_REQUESTS.contains(&cmd.request.as_str()) => error.clone(),
+            _ => return response,
+        };
+
+        let manifest_guard = manifest.read().await;
+        let request_manifest = match manifest_guard.as_ref().and_then(|manifest| manifest.get_request_manifest(&cmd.request)) {
+            Some(request_manifest) => request_manifest,
+            None => return response,
+        };
+
+        if let Some(declared) = request_manifest.declared_error(&error) {
+            error.code = declared.code;
+            error.message = declared.message;
+            error.set_error_name(&declared.name);
+            return JanusResponse::error(cmd.id.clone(), error);
+        }
+        if error.error_name().is_none() && error.error_code().is_some() {
+            return response;
+        }
+
+        let undeclared = match error.error_name() {
+            Some(name) => format!("error '{}'", name),
+            None => format!("error code {}", error.code),
+        };
+        warn!("Request '{}' (ID: {}) failed with undeclared {}", cmd.request, cmd.id, undeclared);
+        if !strict {
+            return response;
+        }
+
+        let mut declared: Vec<&String> = request_manifest.error_codes.iter().flat_map(|codes| codes.keys()).collect();
+        declared.sort();
+        let context = HashMap::from([
+            ("error".to_string(), serde_json::to_value(&error).unwrap_or(serde_json::Value::Null)),
+            ("declaredErrors".to_string(), serde_json::json!(declared)),
+        ]);
+        JanusResponse::error(
+            cmd.id.clone(),
+            JSONRPCError::with_context(
+                JSONRPCErrorCode::InternalError,
+                Some(format!("Request '{}' failed with undeclared {}", cmd.request, undeclared)),
+                context,
+            ),
+        )
+    }
+
+    /// Replace a successful handler result that violates the response definition of its request
+    /// with an InternalError carrying the validation errors.
+    /// Built-in requests and requests the Manifest does not define are left untouched.
+    async fn check_response(
+        response: JanusResponse,
+        cmd: &JanusRequest,
+        manifest: &SharedManifest,
+        policy: &SharedPolicy,
+    ) -> JanusResponse {
+        if !response.success || BUILTIN_REQUESTS.contains(&cmd.request.as_str()) {
+            return response;
+        }
+
+        let manifest_guard = manifest.read().await;
+        let manifest = match manifest_guard.as_ref() {
+            Some(manifest) if manifest.has_request(&cmd.request) => manifest,
+            _ => return response,
+        };
+
+        let result = response.result.as_ref().unwrap_or(&serde_json::Value::Null);
+        let policy = policy.read().await;
+        let validation = ResponseValidator::new(manifest)
+            .with_validators(&policy.validators)
+            .validate_request_response(result, &cmd.request);
+        drop(policy);
+        drop(manifest_guard);
+        if validation.valid {
+            return response;
+        }
+
+        warn!(
+            "Result of request '{}' (ID: {}) violates its manifest definition with {} validation errors",
+            cmd.request, cmd.id, validation.errors.len()
+        );
+        JanusResponse::error(
+            cmd.id.clone(),
+            validation.to_jsonrpc_error(
+                JSONRPCErrorCode::InternalError,
+                &format!("Result of request '{}' violates its manifest definition", cmd.request),
+            ),
+        )
+    }
+
+    /// Serialize the served Manifest, falling back to an empty one when none is configured.
+    /// A `version_requirement` argument refuses Manifests outside the client's version range.
+    /// A `known_hash` argument matching the Manifest fingerprint is answered with
+    /// `{"unchanged": true}` instead of the whole Manifest.
+    async fn serve_manifest(manifest: &SharedManifest, cmd: &JanusRequest) -> Result<serde_json::Value, JSONRPCError> {
+        let manifest_guard = manifest.read().await;
+        let empty_manifest = Manifest::new("1.0.0".to_string());
+        let served_manifest = manifest_guard.as_ref().unwrap_or(&empty_manifest);
+
+        let requirement = cmd.args.as_ref().and_then(|args| args.get("version_requirement"));
+        if let Some(requirement) = requirement {
+            let requirement = requirement.as_str().ok_or_else(|| JSONRPCError::new(
+                JSONRPCErrorCode::InvalidParams,
+                Some("Argument 'version_requirement' must be a string".to_string()),
+            ))?;
+            VersionRequirement::parse(requirement)
+                .map_err(|e| JSONRPCError::new(JSONRPCErrorCode::InvalidParams, e.data.and_then(|data| data.details)))?
+                .negotiate(&served_manifest.version)?;
+        }
+
+        let known_hash = cmd.args.as_ref().and_then(|args| args.get("known_hash"));
+        if let Some(known_hash) = known_hash {
+            let known_hash = known_hash.as_str().ok_or_else(|| JSONRPCError::new(
+                JSONRPCErrorCode::InvalidParams,
+                Some("Argument 'known_hash' must be a string".to_string()),
+            ))?;
+            let fingerprint = served_manifest.fingerprint();
+            if known_hash == fingerprint {
+                return Ok(serde_json::json!({"unchanged": true, "fingerprint": fingerprint}));
+            }
+        }
+
+        serde_json::to_value(served_manifest).map_err(|e| JSONRPCError::new(
+            JSONRPCErrorCode::InternalError,
+            Some(format!("Failed to serialize manifest: {}", e)),
+        ))
     }
 
     fn send_response_sync(response: JanusResponse, reply_to: &str) {
@@ -4,4 +4,4 @@
 
 pub mod janus_server;
 
-pub use janus_server::{JanusServer, JanusRequestHandler, ServerConfig};
\ No newline at end of file
+pub use janus_server::{JanusServer, JanusRequestHandler, RequestPolicy, ServerConfig};
\ No newline at end of file
@@ -0,0 +1,162 @@
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+use serde_json::json;
+use std::time::Duration;
+
+/// Argument Coercion Tests
+/// Tests lenient conversion of string arguments to their declared Manifest types
+
+fn create_report_request() -> RequestManifest {
+    let mut request = RequestManifest::new(
+        "Build a report".to_string(),
+        ResponseManifest::new("object".to_string()),
+    );
+    request.add_argument(
+        "limit".to_string(),
+        ArgumentManifest::new("integer".to_string())
+            .required()
+            .with_validation(ValidationManifest::new().with_numeric_range(Some(1.0), Some(100.0))),
+    );
+    request.add_argument("ratio".to_string(), ArgumentManifest::new("number".to_string()));
+    request.add_argument("verbose".to_string(), ArgumentManifest::new("boolean".to_string()));
+    request.add_argument("columns".to_string(), ArgumentManifest::new("array".to_string()));
+    request.add_argument("filter".to_string(), ArgumentManifest::new("object".to_string()));
+    request.add_argument("title".to_string(), ArgumentManifest::new("string".to_string()));
+    request
+}
+
+fn create_report_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+    manifest.add_request("report".to_string(), create_report_request());
+    manifest
+}
+
+async fn start_report_server(socket_path: &str, coercion_mode: CoercionMode) -> JanusServer {
+    let mut server = create_manifest_server(socket_path, create_report_manifest()).await;
+    server.set_coercion_mode(coercion_mode).await;
+    server.register_handler("report", |cmd| Ok(json!(cmd.args))).await;
+    server.start_listening().await.expect("Failed to start server");
+    server
+}
+
+fn client_config(enable_validation: bool, coercion_mode: CoercionMode) -> JanusClientConfig {
+    JanusClientConfig {
+        enable_validation,
+        coercion_mode,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_lenient_mode_converts_and_reports_strings() {
+    let manifest = create_report_manifest();
+    let request = create_report_request();
+    let validator = ArgumentValidator::new(&manifest).with_coercion(CoercionMode::Lenient);
+
+    let mut provided = args(json!({
+        "limit": " 42 ",
+        "ratio": "0.25",
+        "verbose": "TRUE",
+        "columns": "[\"id\", \"name\"]",
+        "filter": "{\"active\": true}",
+        "title": "42"
+    }));
+    let coercions = validator.coerce_args(&mut provided, &request);
+    assert_eq!(
+        provided,
+        args(json!({
+            "limit": 42,
+            "ratio": 0.25,
+            "verbose": true,
+            "columns": ["id", "name"],
+            "filter": {"active": true},
+            "title": "42"
+        }))
+    );
+    assert_eq!(
+        coercions.iter().map(|coercion| coercion.field.as_str()).collect::<Vec<_>>(),
+        vec!["columns", "filter", "limit", "ratio", "verbose"]
+    );
+    assert_eq!(
+        coercions[2],
+        ArgumentCoercion { field: "limit".to_string(), r#type: "integer".to_string(), from: json!(" 42 "), to: json!(42) }
+    );
+    assert!(validator.validate_args(provided.as_ref(), &request).valid);
+}
+
+#[test]
+fn test_unconvertible_strings_are_left_for_validation() {
+    let manifest = create_report_manifest();
+    let request = create_report_request();
+    let validator = ArgumentValidator::new(&manifest).with_coercion(CoercionMode::Lenient);
+
+    let mut provided = args(json!({"limit": "4.5", "verbose": "yes", "columns": "{}", "ratio": "NaN"}));
+    assert!(validator.coerce_args(&mut provided, &request).is_empty());
+    let result = validator.validate_args(provided.as_ref(), &request);
+    assert_eq!(result.errors.len(), 4);
+
+    // Strict mode is the default and never converts
+    let mut provided = args(json!({"limit": "42"}));
+    assert!(ArgumentValidator::new(&manifest).coerce_args(&mut provided, &request).is_empty());
+    assert_eq!(provided, args(json!({"limit": "42"})));
+    assert_eq!(JanusClientConfig::default().coercion_mode, CoercionMode::Strict);
+}
+
+#[tokio::test]
+async fn test_server_coerces_in_lenient_mode_only() {
+    let socket_path = &unique_socket_path("coercion_server");
+    let mut server = start_report_server(socket_path, CoercionMode::Lenient).await;
+
+    let mut client = create_client(socket_path, client_config(false, CoercionMode::Strict)).await;
+    let response = client
+        .send_request("report", args(json!({"limit": "7", "verbose": "false"})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap();
+    assert!(response.success);
+    assert_eq!(response.result, Some(json!({"limit": 7, "verbose": false})));
+    server.stop();
+
+    let socket_path = &unique_socket_path("coercion_server_strict");
+    let mut server = start_report_server(socket_path, CoercionMode::Strict).await;
+
+    let mut client = create_client(socket_path, client_config(false, CoercionMode::Strict)).await;
+    let response = client
+        .send_request("report", args(json!({"limit": "7"})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap();
+    assert!(!response.success);
+    assert_eq!(response.error.unwrap().code, JSONRPCErrorCode::InvalidParams.code());
+
+    // Switching modes while listening applies to the next request
+    server.set_coercion_mode(CoercionMode::Lenient).await;
+    let response = client
+        .send_request("report", args(json!({"limit": "7"})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap();
+    assert_eq!(response.result, Some(json!({"limit": 7})));
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_client_coerces_before_sending() {
+    let socket_path = &unique_socket_path("coercion_client");
+    let mut server = start_report_server(socket_path, CoercionMode::Strict).await;
+
+    let mut client = create_client(socket_path, client_config(true, CoercionMode::Lenient)).await;
+    let response = client
+        .send_request("report", args(json!({"limit": "12", "filter": "{\"owner\": \"me\"}"})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap();
+    assert!(response.success);
+    assert_eq!(response.result, Some(json!({"limit": 12, "filter": {"owner": "me"}})));
+
+    let mut client = create_client(socket_path, client_config(true, CoercionMode::Strict)).await;
+    let error = client
+        .send_request("report", args(json!({"limit": "12"})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ManifestValidationError.code());
+
+    server.stop();
+}
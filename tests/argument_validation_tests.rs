@@ -0,0 +1,157 @@
+use rust_janus::*;
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Argument Validation Tests
+/// Tests validation of request arguments against manifest request definitions
+
+fn create_validation_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+
+    let mut address = ModelManifest::new();
+    address.add_property("street".to_string(), ArgumentManifest::new("string".to_string()).required());
+    address.add_property(
+        "zip".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .with_validation(ValidationManifest::new().with_pattern("^[0-9]{5}$".to_string())),
+    );
+    manifest.add_model("Address".to_string(), address);
+
+    let mut request = RequestManifest::new(
+        "Create a user".to_string(),
+        ResponseManifest::new("object".to_string()),
+    );
+    request.add_argument(
+        "name".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .required()
+            .with_validation(ValidationManifest::new().with_length_range(Some(2), Some(10))),
+    );
+    request.add_argument(
+        "age".to_string(),
+        ArgumentManifest::new("integer".to_string())
+            .with_validation(ValidationManifest::new().with_numeric_range(Some(0.0), Some(150.0))),
+    );
+    request.add_argument(
+        "role".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .with_validation(ValidationManifest::new().with_enum(vec![json!("admin"), json!("member")])),
+    );
+    request.add_argument(
+        "address".to_string(),
+        ArgumentManifest::new("object".to_string()).with_model_ref("Address".to_string()),
+    );
+    manifest.add_request("create_user".to_string(), request);
+
+    manifest
+}
+
+fn create_request(args: serde_json::Value) -> JanusRequest {
+    let args: HashMap<String, serde_json::Value> = serde_json::from_value(args).unwrap();
+    JanusRequest::new("create_user".to_string(), Some(args), None)
+}
+
+#[test]
+fn test_valid_arguments_pass() {
+    let manifest = create_validation_manifest();
+    let validator = ArgumentValidator::new(&manifest);
+
+    let result = validator.validate_request(&create_request(json!({
+        "name": "alice",
+        "age": 30,
+        "role": "admin",
+        "address": {"street": "Main St", "zip": "12345"}
+    })));
+
+    assert!(result.valid, "unexpected errors: {:?}", result.errors);
+    assert_eq!(result.fields_validated, 4);
+}
+
+#[test]
+fn test_missing_and_null_required_arguments() {
+    let manifest = create_validation_manifest();
+    let validator = ArgumentValidator::new(&manifest);
+
+    let result = validator.validate_request(&create_request(json!({"age": 30})));
+    assert!(!result.valid);
+    assert_eq!(result.errors[0].field, "name");
+
+    let result = validator.validate_request(&create_request(json!({"name": null})));
+    assert!(!result.valid);
+    assert_eq!(result.errors[0].field, "name");
+
+    let request = JanusRequest::new("create_user".to_string(), None, None);
+    assert!(!validator.validate_request(&request).valid);
+}
+
+#[test]
+fn test_type_mismatches_are_reported() {
+    let manifest = create_validation_manifest();
+    let validator = ArgumentValidator::new(&manifest);
+
+    let result = validator.validate_request(&create_request(json!({"name": 42, "age": 1.5})));
+    assert!(!result.valid);
+    assert_eq!(result.errors.len(), 2);
+
+    let fields: Vec<&str> = result.errors.iter().map(|e| e.field.as_str()).collect();
+    assert!(fields.contains(&"name"));
+    assert!(fields.contains(&"age"));
+}
+
+#[test]
+fn test_constraint_violations_are_collected() {
+    let manifest = create_validation_manifest();
+    let validator = ArgumentValidator::new(&manifest);
+
+    let result = validator.validate_request(&create_request(json!({
+        "name": "a",
+        "age": 200,
+        "role": "owner"
+    })));
+
+    assert!(!result.valid);
+    assert_eq!(result.errors.len(), 3);
+}
+
+#[test]
+fn test_model_reference_errors_include_nested_path() {
+    let manifest = create_validation_manifest();
+    let validator = ArgumentValidator::new(&manifest);
+
+    let result = validator.validate_request(&create_request(json!({
+        "name": "alice",
+        "address": {"zip": "abc"}
+    })));
+
+    assert!(!result.valid);
+    let fields: Vec<&str> = result.errors.iter().map(|e| e.field.as_str()).collect();
+    assert!(fields.contains(&"address.street"));
+    assert!(fields.contains(&"address.zip"));
+}
+
+#[test]
+fn test_undefined_request_is_rejected() {
+    let manifest = create_validation_manifest();
+    let validator = ArgumentValidator::new(&manifest);
+
+    let request = JanusRequest::new("delete_user".to_string(), None, None);
+    let result = validator.validate_request(&request);
+    assert!(!result.valid);
+    assert!(result.errors[0].message.contains("delete_user"));
+}
+
+#[test]
+fn test_validation_result_to_jsonrpc_error() {
+    let manifest = create_validation_manifest();
+    let validator = ArgumentValidator::new(&manifest);
+
+    let result = validator.validate_request(&create_request(json!({"name": "a", "age": -1})));
+    let error = result.to_jsonrpc_error(JSONRPCErrorCode::InvalidParams, "Invalid arguments");
+
+    assert_eq!(error.code, JSONRPCErrorCode::InvalidParams.code());
+    let data = error.data.unwrap();
+    assert!(data.field.is_some());
+    let context = data.context.unwrap();
+    assert_eq!(context["errorCount"], json!(2));
+    assert_eq!(context["errors"].as_array().unwrap().len(), 2);
+}
@@ -324,8 +324,7 @@ async fn test_slow_process_request() {
 
 #[tokio::test]
 async fn test_manifest_request() {
-    let socket_path = "/tmp/rust_janus_builtin_manifest_test.sock";
-    let _ = std::fs::remove_file(socket_path);
+    let socket_path = &unique_socket_path("builtin_manifest");
     
     // Start server
     let server_config = ServerConfig {
@@ -337,6 +336,15 @@ async fn test_manifest_request() {
         cleanup_on_shutdown: true,
     };
     let mut server = JanusServer::new(server_config);
+    let mut manifest = Manifest::new("2.1.0".to_string());
+    let mut user = ModelManifest::new();
+    user.add_property("id".to_string(), ArgumentManifest::new("string".to_string()).required());
+    manifest.add_model("User".to_string(), user);
+    let mut response = ResponseManifest::new("object".to_string());
+    response.model_ref = Some("User".to_string());
+    manifest.add_request("get_user".to_string(), RequestManifest::new("Look up a user".to_string(), response));
+    server.set_manifest(manifest).await;
+    server.register_handler("get_user", |_| Ok(json!({"id": "u1"}))).await;
     server.start_listening().await.expect("Failed to start server");
     
     // Give server time to start
@@ -364,12 +372,11 @@ async fn test_manifest_request() {
     assert!(response.success, "Manifest should return success");
     assert!(response.result.is_some(), "Manifest should have result");
     
-    let result_obj = response.result.unwrap();
-    assert!(result_obj.is_object(), "Manifest result should be object");
-    let obj = result_obj.as_object().unwrap();
-    assert!(obj.contains_key("version"), "Manifest should contain 'version' field");
-    assert!(obj.contains_key("channels"), "Manifest should contain 'channels' field");
-    assert!(obj.contains_key("models"), "Manifest should contain 'models' field");
+    let served: Manifest = serde_json::from_value(response.result.unwrap()).expect("Manifest result should be a Manifest");
+    assert_eq!(served.version, "2.1.0");
+    assert!(served.get_model("User").is_some(), "Manifest should contain the 'User' model");
+    assert_eq!(served.request_names(), vec!["get_user"]);
+    assert_eq!(served.get_request_manifest("get_user").unwrap().response.model_ref.as_deref(), Some("User"));
 }
 
 #[tokio::test]
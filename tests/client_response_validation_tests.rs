@@ -0,0 +1,103 @@
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+use serde::Deserialize;
+use serde_json::json;
+use std::time::Duration;
+
+/// Client Response Validation Tests
+/// Tests the off, warn and reject modes for checking results against the fetched Manifest
+
+fn create_order_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+
+    let mut order = ModelManifest::new();
+    order.add_property("id".to_string(), ArgumentManifest::new("string".to_string()).required());
+    order.add_property(
+        "quantity".to_string(),
+        ArgumentManifest::new("integer".to_string())
+            .required()
+            .with_validation(ValidationManifest::new().with_numeric_range(Some(1.0), None)),
+    );
+    manifest.add_model("Order".to_string(), order);
+
+    let mut response = ResponseManifest::new("object".to_string());
+    response.model_ref = Some("Order".to_string());
+    let mut request = RequestManifest::new("Get an order".to_string(), response);
+    request.add_argument("quantity".to_string(), ArgumentManifest::new("integer".to_string()).required());
+    manifest.add_request("get_order".to_string(), request);
+
+    manifest
+}
+
+#[derive(Debug, Deserialize)]
+struct Order {
+    id: String,
+    quantity: i64,
+}
+
+/// Serve orders echoing the requested quantity, so non-positive quantities break the contract
+async fn start_order_server(socket_path: &str) -> JanusServer {
+    let mut server = create_manifest_server(socket_path, create_order_manifest()).await;
+    server.register_handler("get_order", |cmd| {
+        let quantity = cmd.args.as_ref().and_then(|args| args.get("quantity")).cloned();
+        Ok(json!({"id": "order-1", "quantity": quantity}))
+    }).await;
+    server.start_listening().await.expect("Failed to start server");
+    server
+}
+
+fn client_config(response_validation: ResponseValidationMode) -> JanusClientConfig {
+    JanusClientConfig { response_validation, ..Default::default() }
+}
+
+#[tokio::test]
+async fn test_responses_are_not_checked_by_default() {
+    let socket_path = &unique_socket_path("client_response_validation_off");
+    let mut server = start_order_server(socket_path).await;
+    assert_eq!(JanusClientConfig::default().response_validation, ResponseValidationMode::Off);
+
+    let mut client = create_client(socket_path, client_config(ResponseValidationMode::Off)).await;
+    let response = client.send_request("get_order", args(json!({"quantity": 0})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(response.success);
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_warn_mode_returns_violating_responses() {
+    let socket_path = &unique_socket_path("client_response_validation_warn");
+    let mut server = start_order_server(socket_path).await;
+
+    let mut client = create_client(socket_path, client_config(ResponseValidationMode::Warn)).await;
+    let response = client.send_request("get_order", args(json!({"quantity": 0})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(response.success);
+    assert_eq!(response.result.unwrap()["quantity"], json!(0));
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_reject_mode_fails_violating_responses() {
+    let socket_path = &unique_socket_path("client_response_validation_reject");
+    let mut server = start_order_server(socket_path).await;
+
+    let mut client = create_client(socket_path, client_config(ResponseValidationMode::Reject)).await;
+    let order: Order = client.send_typed_request("get_order", &json!({"quantity": 2}), Some(Duration::from_secs(5))).await.unwrap();
+    assert_eq!((order.id.as_str(), order.quantity), ("order-1", 2));
+
+    let error = client
+        .send_typed_request::<_, Order>("get_order", &json!({"quantity": -1}), Some(Duration::from_secs(5)))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ManifestValidationError.code());
+    let data = error.data.unwrap();
+    assert_eq!(data.field.as_deref(), Some("quantity"));
+    assert_eq!(data.value, Some(json!(-1.0)));
+    assert!(data.details.unwrap().starts_with("Result of request 'get_order' failed validation"));
+
+    // Built-in requests have no response definition and are never checked
+    assert!(client.send_request("ping", None, Some(Duration::from_secs(5))).await.unwrap().success);
+
+    server.stop();
+}
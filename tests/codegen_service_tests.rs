@@ -0,0 +1,124 @@
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Generated Service Tests
+/// Tests the generated server trait and its registration against the generated client
+
+#[allow(dead_code)]
+mod generated {
+    include!("fixtures/users_service.rs");
+}
+
+use generated::{CountUsersResponse, GetUserArgs, ListUsersArgs, User, UsersClient, UsersService};
+
+const MANIFEST_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/users_manifest.json");
+
+fn service_options() -> RustCodegenOptions {
+    RustCodegenOptions {
+        client: true,
+        client_name: "UsersClient".to_string(),
+        server: true,
+        server_name: "UsersService".to_string(),
+    }
+}
+
+fn user(id: &str) -> User {
+    User {
+        id: id.to_string(),
+        display_name: None,
+        manager: None,
+        scores: None,
+        tags: None,
+        r#type: None,
+    }
+}
+
+#[derive(Default)]
+struct Directory {
+    lookups: AtomicUsize,
+}
+
+impl UsersService for Directory {
+    async fn count_users(&self) -> Result<CountUsersResponse> {
+        Ok(CountUsersResponse { count: self.lookups.load(Ordering::SeqCst) as i64, note: Some("lookups".to_string()) })
+    }
+
+    async fn get_user(&self, args: GetUserArgs) -> Result<User> {
+        self.lookups.fetch_add(1, Ordering::SeqCst);
+        if args.user_id == "missing" {
+            return Err(JSONRPCError::new(JSONRPCErrorCode::ResourceNotFound, Some("No such user".to_string())));
+        }
+        Ok(User { display_name: args.verbose.map(|_| "Verbose".to_string()), ..user(&args.user_id) })
+    }
+
+    async fn list_users(&self, args: ListUsersArgs) -> Result<Vec<User>> {
+        let limit = args.limit.unwrap_or(2) as usize;
+        Ok(["a", "b", "c"].iter().take(limit).map(|id| user(id)).collect())
+    }
+}
+
+#[test]
+fn test_generated_service_matches_fixture() {
+    let manifest = ManifestParser::load_and_validate_json(&std::fs::read_to_string(MANIFEST_PATH).unwrap()).unwrap();
+    let source = RustCodeGenerator::generate(&manifest, &service_options()).unwrap();
+    assert_eq!(
+        source,
+        include_str!("fixtures/users_service.rs"),
+        "regenerate with `janus generate --manifest tests/fixtures/users_manifest.json --kind both --client-name UsersClient --server-name UsersService -o tests/fixtures/users_service.rs`"
+    );
+
+    // The embedded manifest is the one the code was generated from
+    assert_eq!(generated::manifest(), manifest);
+
+    // Server-only output leaves out the client wrapper
+    let options = RustCodegenOptions { client: false, ..service_options() };
+    let source = RustCodeGenerator::generate(&manifest, &options).unwrap();
+    assert!(source.contains("pub trait UsersService"));
+    assert!(!source.contains("pub struct UsersClient"));
+}
+
+#[tokio::test]
+async fn test_registered_service_handles_every_request() {
+    let socket_path = &unique_socket_path("codegen_service");
+    let mut server = JanusServer::new(ServerConfig {
+        socket_path: socket_path.to_string(),
+        ..Default::default()
+    });
+    generated::register_users_service(&mut server, Directory::default()).await;
+    server.start_listening().await.expect("Failed to start server");
+
+    let client = JanusClient::new(socket_path.to_string(), JanusClientConfig::default())
+        .await
+        .expect("Failed to create client");
+    let mut users = UsersClient::new(client).with_timeout(Duration::from_secs(5));
+
+    let found = users.get_user(GetUserArgs { user_id: "u1".to_string(), verbose: Some(true) }).await.unwrap();
+    assert_eq!(found.id, "u1");
+    assert_eq!(found.display_name.as_deref(), Some("Verbose"));
+
+    let error = users.get_user(GetUserArgs { user_id: "missing".to_string(), verbose: None }).await.unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ResourceNotFound.code());
+
+    let listed = users.list_users(ListUsersArgs { limit: Some(3) }).await.unwrap();
+    assert_eq!(listed.len(), 3);
+
+    let counted = users.count_users().await.unwrap();
+    assert_eq!(counted, CountUsersResponse { count: 2, note: Some("lookups".to_string()) });
+
+    // The embedded manifest is served and validates untyped callers
+    let client = users.client_mut();
+    let served = client.fetch_manifest().await.unwrap();
+    assert!(served.has_request("list-users"));
+
+    let mut args = HashMap::new();
+    args.insert("userId".to_string(), json!(42));
+    let response = client.send_request("get_user", Some(args), Some(Duration::from_secs(5))).await;
+    assert!(response.is_err() || !response.unwrap().success);
+
+    server.stop();
+}
@@ -0,0 +1,130 @@
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+use serde_json::json;
+use std::time::Duration;
+
+/// Rust Code Generation Tests
+/// Tests generated model, argument and client code against a fixture manifest and a live server
+
+mod generated {
+    include!("fixtures/users_client.rs");
+}
+
+use generated::{CountUsersResponse, GetUserArgs, ListUsersArgs, User, UsersClient};
+
+const MANIFEST_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/users_manifest.json");
+
+fn load_manifest() -> Manifest {
+    ManifestParser::load_and_validate_json(&std::fs::read_to_string(MANIFEST_PATH).unwrap()).unwrap()
+}
+
+fn client_options() -> RustCodegenOptions {
+    RustCodegenOptions {
+        client_name: "UsersClient".to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_generated_code_matches_fixture() {
+    let source = RustCodeGenerator::generate(&load_manifest(), &client_options()).unwrap();
+    assert_eq!(
+        source,
+        include_str!("fixtures/users_client.rs"),
+        "regenerate with `janus generate --manifest tests/fixtures/users_manifest.json --client-name UsersClient -o tests/fixtures/users_client.rs`"
+    );
+}
+
+#[test]
+fn test_generated_models_use_manifest_names() {
+    let user: User = serde_json::from_value(json!({
+        "id": "u1",
+        "displayName": "Ada",
+        "type": "admin",
+        "manager": {"id": "u0"},
+        "scores": {"q1": 0.5}
+    }))
+    .unwrap();
+    assert_eq!(user.display_name.as_deref(), Some("Ada"));
+    assert_eq!(user.r#type.as_deref(), Some("admin"));
+    assert_eq!(user.manager.as_ref().unwrap().id, "u0");
+    assert_eq!(user.tags, None);
+
+    let args = serde_json::to_value(GetUserArgs { user_id: "u1".to_string(), verbose: None }).unwrap();
+    assert_eq!(args, json!({"userId": "u1"}));
+}
+
+#[test]
+fn test_generation_errors() {
+    let mut manifest = load_manifest();
+    manifest.add_model("GetUserArgs".to_string(), ModelManifest::new());
+    let error = RustCodeGenerator::generate(&manifest, &client_options()).unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ManifestValidationError.code());
+    assert!(error.to_string().contains("Generated type 'GetUserArgs' for arguments of request 'get_user' conflicts with model 'GetUserArgs'"));
+
+    let mut manifest = load_manifest();
+    manifest.add_request(
+        "into-client".to_string(),
+        RequestManifest::new("Clashes with the wrapper".to_string(), ResponseManifest::new("object".to_string())),
+    );
+    let error = RustCodeGenerator::generate(&manifest, &client_options()).unwrap_err();
+    assert!(error.to_string().contains("would shadow the generated 'into_client' method"));
+}
+
+#[test]
+fn test_write_client_for_build_scripts() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("users_client.rs");
+
+    RustCodeGenerator::write(MANIFEST_PATH, &output, &client_options()).unwrap();
+    assert_eq!(std::fs::read_to_string(&output).unwrap(), include_str!("fixtures/users_client.rs"));
+
+    let error = RustCodeGenerator::write(dir.path().join("missing.json"), &output, &client_options()).unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ResourceNotFound.code());
+}
+
+#[tokio::test]
+async fn test_typed_client_calls_server() {
+    let socket_path = &unique_socket_path("codegen_client");
+    let mut server = JanusServer::new(ServerConfig {
+        socket_path: socket_path.to_string(),
+        ..Default::default()
+    });
+    server.set_manifest(load_manifest()).await;
+    server.register_handler("get_user", |cmd| {
+        let user_id = cmd.args.and_then(|args| args.get("userId").cloned()).unwrap_or_default();
+        if user_id == json!("missing") {
+            return Err(JSONRPCError::new(JSONRPCErrorCode::ResourceNotFound, Some("No such user".to_string())));
+        }
+        Ok(json!({"id": user_id, "tags": ["admin"], "manager": {"id": "root"}}))
+    }).await;
+    server.register_handler("list-users", |_| Ok(json!([{"id": "a"}, {"id": "b"}]))).await;
+    server.register_handler("count_users", |_| Ok(json!({"count": 2}))).await;
+    server.start_listening().await.expect("Failed to start server");
+
+    let client = JanusClient::new(socket_path.to_string(), JanusClientConfig::default())
+        .await
+        .expect("Failed to create client");
+    let mut users = UsersClient::new(client).with_timeout(Duration::from_secs(5));
+
+    let user = users.get_user(GetUserArgs { user_id: "u7".to_string(), verbose: Some(true) }).await.unwrap();
+    assert_eq!(user.id, "u7");
+    assert_eq!(user.tags, Some(vec!["admin".to_string()]));
+    assert_eq!(user.manager.unwrap().id, "root");
+
+    let listed = users.list_users(ListUsersArgs { limit: Some(10) }).await.unwrap();
+    assert_eq!(listed.iter().map(|user| user.id.as_str()).collect::<Vec<_>>(), vec!["a", "b"]);
+
+    assert_eq!(users.count_users().await.unwrap(), CountUsersResponse { count: 2, note: None });
+
+    let error = users.get_user(GetUserArgs { user_id: "missing".to_string(), verbose: None }).await.unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ResourceNotFound.code());
+
+    // The wrapped client stays reachable for untyped and built-in requests
+    assert_eq!(users.client().socket_path(), socket_path);
+    assert!(users.client_mut().ping().await);
+    assert!(users.into_client().manifest().is_some());
+
+    server.stop();
+}
@@ -0,0 +1,175 @@
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+use rust_janus::manifest::ResponseValidator;
+use serde_json::json;
+use std::time::Duration;
+
+/// Custom Validator Tests
+/// Tests named application validators referenced from Manifest validation constraints
+
+const TENANTS: [&str; 2] = ["acme", "globex"];
+
+fn tenant_exists(value: &serde_json::Value) -> std::result::Result<(), String> {
+    match value.as_str() {
+        Some(tenant) if TENANTS.contains(&tenant) => Ok(()),
+        _ => Err(format!("Tenant {} does not exist", value)),
+    }
+}
+
+fn cron_expression(value: &serde_json::Value) -> std::result::Result<(), String> {
+    let fields = value.as_str().map(|cron| cron.split_whitespace().count()).unwrap_or_default();
+    if fields == 5 {
+        Ok(())
+    } else {
+        Err(format!("Cron expressions have 5 fields, got {}", fields))
+    }
+}
+
+fn create_registry() -> ValidatorRegistry {
+    ValidatorRegistry::new()
+        .with_validator("tenant", tenant_exists)
+        .with_validator("cron", cron_expression)
+}
+
+fn create_schedule_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+
+    let mut job = ModelManifest::new();
+    job.add_property(
+        "tenant".to_string(),
+        ArgumentManifest::new("string".to_string()).with_validation(ValidationManifest::new().with_validator("tenant".to_string())),
+    );
+    manifest.add_model("Job".to_string(), job);
+
+    let mut response = ResponseManifest::new("object".to_string());
+    response.model_ref = Some("Job".to_string());
+    let mut request = RequestManifest::new("Schedule a job".to_string(), response);
+    request.add_argument(
+        "tenant".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .required()
+            .with_validation(ValidationManifest::new().with_validator("tenant".to_string())),
+    );
+    request.add_argument(
+        "schedule".to_string(),
+        ArgumentManifest::new("string".to_string()).with_validation(
+            ValidationManifest::new()
+                .with_length_range(Some(9), None)
+                .with_validator("cron".to_string()),
+        ),
+    );
+    request.add_argument(
+        "owner".to_string(),
+        ArgumentManifest::new("string".to_string()).with_validation(ValidationManifest::new().with_validator("ldap_user".to_string())),
+    );
+    manifest.add_request("schedule".to_string(), request);
+
+    manifest
+}
+
+#[test]
+fn test_custom_validator_failures_join_validation_errors() {
+    let manifest = create_schedule_manifest();
+    let registry = create_registry();
+    let validator = ArgumentValidator::new(&manifest).with_validators(&registry);
+    let request = manifest.get_request_manifest("schedule").unwrap();
+
+    assert!(validator.validate_args(args(json!({"tenant": "acme", "schedule": "0 3 * * 1"})).as_ref(), request).valid);
+
+    let result = validator.validate_args(args(json!({"tenant": "initech", "schedule": "@daily"})).as_ref(), request);
+    let errors: Vec<(&str, &str, &str)> = result.errors
+        .iter()
+        .map(|e| (e.field.as_str(), e.expected.as_str(), e.message.as_str()))
+        .collect();
+    assert_eq!(errors, vec![
+        ("schedule", "minimum length 9", "String is too short (6 < 9)"),
+        ("schedule", "validator cron", "Cron expressions have 5 fields, got 1"),
+        ("tenant", "validator tenant", "Tenant \"initech\" does not exist"),
+    ]);
+    assert_eq!(result.errors.iter().find(|e| e.field == "tenant").unwrap().actual, json!("initech"));
+
+    // Referencing a validator the registry does not know is a validation failure
+    let result = validator.validate_args(args(json!({"tenant": "acme", "owner": "ada"})).as_ref(), request);
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].message, "Validator 'ldap_user' is not registered");
+
+    // Without a registry, as on clients, named validators are skipped
+    let result = ArgumentValidator::new(&manifest).validate_args(args(json!({"tenant": "initech", "owner": "ada"})).as_ref(), request);
+    assert!(result.valid);
+}
+
+#[test]
+fn test_custom_validators_run_on_responses_and_survive_serialization() {
+    let manifest = create_schedule_manifest();
+    let response = manifest.get_request_manifest("schedule").unwrap().response.clone();
+
+    let serialized = ManifestParser::to_json(&manifest).unwrap();
+    assert!(serialized.contains("\"validators\""));
+    assert_eq!(ManifestParser::load_and_validate_json(&serialized).unwrap(), manifest);
+
+    let registry = create_registry();
+    let validator = ResponseValidator::new(&manifest).with_validators(&registry);
+    assert!(validator.validate_response(&json!({"tenant": "globex"}), &response).valid);
+    let result = validator.validate_response(&json!({"tenant": "hooli"}), &response);
+    assert_eq!(result.errors[0].field, "tenant");
+    assert_eq!(result.errors[0].expected, "validator tenant");
+
+    assert_eq!(registry.names(), vec!["cron", "tenant"]);
+}
+
+#[tokio::test]
+async fn test_server_rejects_arguments_failing_registered_validators() {
+    let socket_path = &unique_socket_path("custom_validators");
+    let mut server = create_manifest_server(socket_path, create_schedule_manifest()).await;
+    server.register_validator("tenant", tenant_exists).await;
+    server.register_validator("cron", cron_expression).await;
+    server.register_handler("schedule", |_| Ok(json!({"tenant": "acme"}))).await;
+    server.start_listening().await.expect("Failed to start server");
+
+    // Client-side validation has no registry and lets the request through
+    let mut client = create_client(socket_path, JanusClientConfig::default()).await;
+
+    let response = client
+        .send_request("schedule", args(json!({"tenant": "acme", "schedule": "*/5 * * * *"})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap();
+    assert!(response.success);
+
+    let response = client
+        .send_request("schedule", args(json!({"tenant": "initech"})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap();
+    let error = response.error.unwrap();
+    assert_eq!(error.code, JSONRPCErrorCode::InvalidParams.code());
+    let data = error.data.unwrap();
+    assert_eq!(data.field.as_deref(), Some("tenant"));
+    assert!(data.context.unwrap()["errors"][0]["message"].as_str().unwrap().contains("does not exist"));
+
+    // Validators registered while listening apply to the next request
+    let owner = args(json!({"tenant": "acme", "owner": "ada"}));
+    let response = client.send_request("schedule", owner.clone(), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(!response.success);
+    server.register_validator("ldap_user", |_| Ok(())).await;
+    let response = client.send_request("schedule", owner, Some(Duration::from_secs(5))).await.unwrap();
+    assert!(response.success);
+
+    server.stop();
+}
+
+#[test]
+fn test_validator_changes_are_classified() {
+    let old = create_schedule_manifest();
+    let mut new = create_schedule_manifest();
+    let request = new.requests.as_mut().unwrap().get_mut("schedule").unwrap();
+    request.args.get_mut("owner").unwrap().validation = None;
+    request.args.get_mut("tenant").unwrap().validation = Some(
+        ValidationManifest::new().with_validator("tenant".to_string()).with_validator("active_tenant".to_string()),
+    );
+
+    let report = ManifestCompatibility::compare(&old, &new);
+    let descriptions: Vec<&str> = report.changes.iter().map(|change| change.description.as_str()).collect();
+    assert!(descriptions.contains(&"validator 'active_tenant' added"), "{:?}", descriptions);
+    assert!(descriptions.contains(&"validator 'ldap_user' removed"), "{:?}", descriptions);
+    assert!(!report.is_compatible());
+}
@@ -0,0 +1,130 @@
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+use serde_json::json;
+use std::time::Duration;
+
+/// Declared Error Tests
+/// Tests mapping handler errors onto the error codes requests declare in their Manifest
+
+fn create_account_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+
+    let mut request = RequestManifest::new("Open an account".to_string(), ResponseManifest::new("object".to_string()));
+    request.add_argument("owner".to_string(), ArgumentManifest::new("string".to_string()).required());
+    request.add_error_code(
+        "owner_not_found".to_string(),
+        ErrorCodeManifest::new(404, "Owner not found".to_string()).with_description("No user has this id".to_string()),
+    );
+    request.add_error_code("account_exists".to_string(), ErrorCodeManifest::new(409, "Account already exists".to_string()));
+    request.add_error_code("quota_exceeded".to_string(), ErrorCodeManifest::new(-40001, "Account quota exceeded".to_string()));
+    manifest.add_request("open_account".to_string(), request);
+
+    manifest
+}
+
+/// Fail in a different way for each owner
+fn open_account(cmd: JanusRequest) -> std::result::Result<serde_json::Value, JSONRPCError> {
+    let owner = cmd.args.as_ref().and_then(|args| args.get("owner")).and_then(|owner| owner.as_str()).unwrap_or_default();
+    match owner {
+        "ghost" => Err(JSONRPCError::declared("owner_not_found", Some("No user 'ghost'".to_string()))),
+        "ada" => Err(JSONRPCError { code: 409, message: "duplicate".to_string(), data: None }),
+        "frozen" => Err(JSONRPCError::declared("account_frozen", None)),
+        "teapot" => Err(JSONRPCError { code: 418, message: "I'm a teapot".to_string(), data: None }),
+        "busy" => Err(JSONRPCError::new(JSONRPCErrorCode::ServiceUnavailable, None)),
+        _ => Ok(json!({"owner": owner})),
+    }
+}
+
+async fn start_account_server(socket_path: &str, strict: bool) -> JanusServer {
+    let mut server = create_manifest_server(socket_path, create_account_manifest()).await;
+    server.set_strict_responses(strict).await;
+    server.register_handler("open_account", open_account).await;
+    server.start_listening().await.expect("Failed to start server");
+    server
+}
+
+async fn open(client: &mut JanusClient, owner: &str) -> JSONRPCError {
+    client
+        .send_typed_request::<_, serde_json::Value>("open_account", &json!({"owner": owner}), Some(Duration::from_secs(5)))
+        .await
+        .unwrap_err()
+}
+
+#[test]
+fn test_errors_match_declarations_by_name_or_code() {
+    let manifest = create_account_manifest();
+    let request = manifest.get_request_manifest("open_account").unwrap();
+
+    let error = JSONRPCError::declared("owner_not_found", Some("No user 'ghost'".to_string()));
+    assert_eq!(error.error_name(), Some("owner_not_found"));
+    let declared = request.declared_error(&error).unwrap();
+    assert!(declared.is("owner_not_found"));
+    assert_eq!((declared.code, declared.message.as_str()), (404, "Owner not found"));
+    assert_eq!(declared.description.as_deref(), Some("No user has this id"));
+    assert_eq!(declared.to_string(), "owner_not_found (404): Owner not found: No user 'ghost'");
+
+    let by_code = request.declared_error(&JSONRPCError { code: 409, message: "duplicate".to_string(), data: None }).unwrap();
+    assert_eq!((by_code.name.as_str(), by_code.details), ("account_exists", None));
+
+    let negative = request.declared_error(&JSONRPCError { code: -40001, message: "quota".to_string(), data: None }).unwrap();
+    assert_eq!((negative.name.as_str(), negative.code), ("quota_exceeded", -40001));
+
+    // Names must be declared, even when the code matches
+    let mut renamed = JSONRPCError { code: 409, message: "duplicate".to_string(), data: None };
+    renamed.set_error_name("account_frozen");
+    assert_eq!(request.declared_error(&renamed), None);
+    assert_eq!(request.declared_error(&JSONRPCError::new(JSONRPCErrorCode::InternalError, None)), None);
+}
+
+#[tokio::test]
+async fn test_server_maps_handler_errors_to_declared_codes() {
+    let socket_path = &unique_socket_path("declared_errors");
+    let mut server = start_account_server(socket_path, false).await;
+    let mut client = create_client(socket_path, JanusClientConfig::default()).await;
+
+    let error = open(&mut client, "ghost").await;
+    assert_eq!((error.code, error.message.as_str()), (404, "Owner not found"));
+    assert_eq!(error.error_name(), Some("owner_not_found"));
+    assert_eq!(error.data.as_ref().unwrap().details.as_deref(), Some("No user 'ghost'"));
+
+    // Errors matched by code are named for clients
+    let error = open(&mut client, "ada").await;
+    assert_eq!((error.code, error.message.as_str(), error.error_name()), (409, "Account already exists", Some("account_exists")));
+    match client.declared_error("open_account", &error) {
+        Some(declared) if declared.is("account_exists") => {}
+        other => panic!("expected account_exists, got {:?}", other),
+    }
+
+    // Without strict mode undeclared errors pass through unchanged
+    let error = open(&mut client, "frozen").await;
+    assert_eq!((error.code, error.error_name()), (JSONRPCErrorCode::ServerError.code(), Some("account_frozen")));
+    assert_eq!(client.declared_error("open_account", &error), None);
+    assert_eq!(open(&mut client, "teapot").await.code, 418);
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_strict_server_rejects_undeclared_errors() {
+    let socket_path = &unique_socket_path("declared_errors_strict");
+    let mut server = start_account_server(socket_path, true).await;
+    let mut client = create_client(socket_path, JanusClientConfig::default()).await;
+
+    assert_eq!(open(&mut client, "ghost").await.code, 404);
+    assert_eq!(open(&mut client, "busy").await.code, JSONRPCErrorCode::ServiceUnavailable.code());
+
+    for (owner, undeclared) in [("frozen", "error 'account_frozen'"), ("teapot", "error code 418")] {
+        let error = open(&mut client, owner).await;
+        assert_eq!(error.code, JSONRPCErrorCode::InternalError.code());
+        let data = error.data.unwrap();
+        assert_eq!(data.details.unwrap(), format!("Request 'open_account' failed with undeclared {}", undeclared));
+        let context = data.context.unwrap();
+        assert_eq!(context["declaredErrors"], json!(["account_exists", "owner_not_found", "quota_exceeded"]));
+    }
+
+    let response = client.send_request("open_account", args(json!({"owner": "grace"})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(response.success);
+
+    server.stop();
+}
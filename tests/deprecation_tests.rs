@@ -0,0 +1,163 @@
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Deprecation Tests
+/// Tests deprecation metadata on requests and arguments and the warnings reported to callers
+
+fn create_user_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.4.0".to_string());
+
+    let mut get_user = RequestManifest::new("Look up a user".to_string(), ResponseManifest::new("object".to_string()));
+    get_user.add_argument("user_id".to_string(), ArgumentManifest::new("string".to_string()));
+    get_user.add_argument(
+        "username".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .with_deprecation(Some("user_id".to_string()), Some("2.0.0".to_string())),
+    );
+    get_user.add_argument(
+        "legacy_format".to_string(),
+        ArgumentManifest::new("boolean".to_string())
+            .with_default(json!(false))
+            .with_deprecation(None, None),
+    );
+    manifest.add_request("get_user".to_string(), get_user);
+
+    let find_user = RequestManifest::new("Look up a user by name".to_string(), ResponseManifest::new("object".to_string()))
+        .with_deprecation(Some("get_user".to_string()), Some("2.0.0".to_string()));
+    manifest.add_request("find_user".to_string(), find_user);
+
+    manifest
+}
+
+#[test]
+fn test_deprecation_warnings_cover_used_request_and_arguments() {
+    let manifest = create_user_manifest();
+
+    let find_user = manifest.get_request_manifest("find_user").unwrap();
+    let warnings = find_user.deprecation_warnings("find_user", None);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].argument, None);
+    assert_eq!(warnings[0].replacement.as_deref(), Some("get_user"));
+    assert_eq!(warnings[0].message, "Request 'find_user' is deprecated and will be removed in version 2.0.0, use 'get_user' instead");
+
+    let get_user = manifest.get_request_manifest("get_user").unwrap();
+    assert!(get_user.deprecation_warnings("get_user", args(json!({"user_id": "u1"})).as_ref()).is_empty());
+
+    let warnings = get_user.deprecation_warnings("get_user", args(json!({"username": "ada", "legacy_format": true})).as_ref());
+    let arguments: Vec<Option<&str>> = warnings.iter().map(|warning| warning.argument.as_deref()).collect();
+    assert_eq!(arguments, vec![Some("legacy_format"), Some("username")]);
+    assert_eq!(warnings[0].to_string(), "Argument 'legacy_format' of request 'get_user' is deprecated");
+    assert_eq!(
+        serde_json::to_value(&warnings[1]).unwrap(),
+        json!({
+            "request": "get_user",
+            "argument": "username",
+            "replacement": "user_id",
+            "removalVersion": "2.0.0",
+            "message": "Argument 'username' of request 'get_user' is deprecated and will be removed in version 2.0.0, use 'user_id' instead"
+        })
+    );
+}
+
+#[test]
+fn test_deprecation_metadata_is_validated_and_linted() {
+    let manifest = create_user_manifest();
+    let serialized = ManifestParser::to_json(&manifest).unwrap();
+    assert_eq!(ManifestParser::load_and_validate_json(&serialized).unwrap(), manifest);
+    assert!(!serde_json::to_string(&ArgumentManifest::new("string".to_string())).unwrap().contains("deprecated"));
+
+    let manifest_json = |request: serde_json::Value| json!({"version": "2.1.0", "requests": {"find_user": request}}).to_string();
+
+    let error = ManifestParser::load_and_validate_json(&manifest_json(json!({
+        "description": "Look up a user by name",
+        "replacement": "get_user",
+        "response": {"type": "object"}
+    }))).unwrap_err();
+    assert!(error.to_string().contains("Request 'find_user' has a replacement or removal version but is not deprecated"), "{}", error);
+
+    // The snake_case spelling is still accepted
+    let error = ManifestParser::load_and_validate_json(&manifest_json(json!({
+        "description": "Look up a user by name",
+        "deprecated": true,
+        "removal_version": "soon",
+        "response": {"type": "object"}
+    }))).unwrap_err();
+    assert!(error.to_string().contains("invalid removal version: soon"), "{}", error);
+
+    // Replacements must exist and removals should happen on time
+    let report = ManifestLinter::lint_json(&manifest_json(json!({
+        "description": "Look up a user by name",
+        "deprecated": true,
+        "replacement": "get_user",
+        "removalVersion": "2.0.0",
+        "response": {"type": "object"}
+    })));
+    assert_eq!(report.with_code("unknown-replacement")[0].path, "requests.find_user.replacement");
+    assert_eq!(report.with_code("removal-overdue")[0].path, "requests.find_user.removalVersion");
+    assert!(ManifestLinter::lint(&create_user_manifest()).with_code("unknown-replacement").is_empty());
+}
+
+#[test]
+fn test_deprecation_is_a_minor_change_and_exported() {
+    let mut old = create_user_manifest();
+    old.requests.as_mut().unwrap().get_mut("find_user").unwrap().deprecated = Some(false);
+    old.requests.as_mut().unwrap().get_mut("find_user").unwrap().replacement = None;
+    old.requests.as_mut().unwrap().get_mut("find_user").unwrap().removal_version = None;
+    let new = create_user_manifest();
+
+    let report = ManifestCompatibility::compare(&old, &new);
+    assert_eq!(report.changes.len(), 1);
+    assert_eq!(report.changes[0].description, "deprecated");
+    assert_eq!(report.required_bump(), VersionBump::Minor);
+
+    let schema = JsonSchemaExporter::export_manifest(&new);
+    assert_eq!(schema["requests"]["find_user"]["deprecated"], json!(true));
+    assert_eq!(schema["requests"]["get_user"]["args"]["properties"]["username"]["deprecated"], json!(true));
+    assert!(schema["requests"]["get_user"]["args"]["properties"]["user_id"].get("deprecated").is_none());
+
+    let openrpc = OpenRpcGenerator::generate(&new, "Users");
+    let methods = openrpc["methods"].as_array().unwrap();
+    let find_user = methods.iter().find(|method| method["name"] == "find_user").unwrap();
+    assert_eq!(find_user["deprecated"], json!(true));
+
+    let imported = JsonSchemaImporter::import_argument(&json!({"type": "string", "deprecated": true})).unwrap();
+    assert!(imported.is_deprecated());
+}
+
+#[tokio::test]
+async fn test_server_reports_deprecations_to_clients() {
+    let socket_path = &unique_socket_path("deprecations");
+    let mut server = create_manifest_server(socket_path, create_user_manifest()).await;
+    server.register_handler("get_user", |_| Ok(json!({"id": "u1"}))).await;
+    server.register_handler("find_user", |_| Ok(json!({"id": "u1"}))).await;
+    server.start_listening().await.expect("Failed to start server");
+
+    let reported: Arc<Mutex<Vec<DeprecationWarning>>> = Arc::new(Mutex::new(Vec::new()));
+    let mut client = create_client(socket_path, JanusClientConfig::default()).await;
+    let sink = Arc::clone(&reported);
+    client.on_deprecation(move |warning| sink.lock().unwrap().push(warning.clone()));
+
+    // Defaults filled in for deprecated arguments are not reported
+    let response = client.send_request("get_user", args(json!({"user_id": "u1"})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(response.success);
+    assert!(response.deprecations.is_none());
+    assert!(!serde_json::to_string(&response).unwrap().contains("deprecations"));
+
+    let response = client.send_request("get_user", args(json!({"username": "ada"})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(response.success);
+    let deprecations = response.deprecations.unwrap();
+    assert_eq!(deprecations.len(), 1);
+    assert_eq!(deprecations[0].argument.as_deref(), Some("username"));
+
+    client.send_request("find_user", None, Some(Duration::from_secs(5))).await.unwrap();
+
+    let reported = reported.lock().unwrap();
+    let summary: Vec<(&str, Option<&str>)> = reported.iter().map(|w| (w.request.as_str(), w.argument.as_deref())).collect();
+    assert_eq!(summary, vec![("get_user", Some("username")), ("find_user", None)]);
+
+    server.stop();
+}
@@ -0,0 +1,93 @@
+// Generated by rust_janus from manifest version 1.2.0. Do not edit by hand.
+
+/// Model `User`
+#[derive(Debug, Clone, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub struct User {
+    #[serde(rename = "displayName")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Stable user id
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manager: Option<Box<User>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scores: Option<::std::collections::HashMap<String, f64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Result of the `count_users` request
+#[derive(Debug, Clone, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub struct CountUsersResponse {
+    pub count: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Arguments of the `get_user` request
+#[derive(Debug, Clone, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub struct GetUserArgs {
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbose: Option<bool>,
+}
+
+/// Arguments of the `list-users` request
+#[derive(Debug, Clone, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub struct ListUsersArgs {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+/// Typed client for the requests of manifest version 1.2.0
+pub struct UsersClient {
+    client: ::rust_janus::JanusClient,
+    timeout: Option<::std::time::Duration>,
+}
+
+impl UsersClient {
+    /// Wrap a JanusClient connected to a server of this manifest
+    pub fn new(client: ::rust_janus::JanusClient) -> Self {
+        Self { client, timeout: None }
+    }
+
+    /// Set the timeout sent with every request
+    pub fn with_timeout(mut self, timeout: ::std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Get the underlying JanusClient
+    pub fn client(&self) -> &::rust_janus::JanusClient {
+        &self.client
+    }
+
+    /// Get the underlying JanusClient mutably
+    pub fn client_mut(&mut self) -> &mut ::rust_janus::JanusClient {
+        &mut self.client
+    }
+
+    /// Unwrap the underlying JanusClient
+    pub fn into_client(self) -> ::rust_janus::JanusClient {
+        self.client
+    }
+
+    /// Count users
+    /// across all tenants
+    pub async fn count_users(&mut self) -> ::std::result::Result<CountUsersResponse, ::rust_janus::JSONRPCError> {
+        self.client.send_typed_request("count_users", &(), self.timeout).await
+    }
+
+    /// Look up a user
+    pub async fn get_user(&mut self, args: GetUserArgs) -> ::std::result::Result<User, ::rust_janus::JSONRPCError> {
+        self.client.send_typed_request("get_user", &args, self.timeout).await
+    }
+
+    /// List users
+    pub async fn list_users(&mut self, args: ListUsersArgs) -> ::std::result::Result<Vec<User>, ::rust_janus::JSONRPCError> {
+        self.client.send_typed_request("list-users", &args, self.timeout).await
+    }
+}
@@ -0,0 +1,309 @@
+// Generated by rust_janus from manifest version 1.2.0. Do not edit by hand.
+
+/// Model `User`
+#[derive(Debug, Clone, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub struct User {
+    #[serde(rename = "displayName")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Stable user id
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manager: Option<Box<User>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scores: Option<::std::collections::HashMap<String, f64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Result of the `count_users` request
+#[derive(Debug, Clone, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub struct CountUsersResponse {
+    pub count: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Arguments of the `get_user` request
+#[derive(Debug, Clone, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub struct GetUserArgs {
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbose: Option<bool>,
+}
+
+/// Arguments of the `list-users` request
+#[derive(Debug, Clone, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+pub struct ListUsersArgs {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+/// Typed client for the requests of manifest version 1.2.0
+pub struct UsersClient {
+    client: ::rust_janus::JanusClient,
+    timeout: Option<::std::time::Duration>,
+}
+
+impl UsersClient {
+    /// Wrap a JanusClient connected to a server of this manifest
+    pub fn new(client: ::rust_janus::JanusClient) -> Self {
+        Self { client, timeout: None }
+    }
+
+    /// Set the timeout sent with every request
+    pub fn with_timeout(mut self, timeout: ::std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Get the underlying JanusClient
+    pub fn client(&self) -> &::rust_janus::JanusClient {
+        &self.client
+    }
+
+    /// Get the underlying JanusClient mutably
+    pub fn client_mut(&mut self) -> &mut ::rust_janus::JanusClient {
+        &mut self.client
+    }
+
+    /// Unwrap the underlying JanusClient
+    pub fn into_client(self) -> ::rust_janus::JanusClient {
+        self.client
+    }
+
+    /// Count users
+    /// across all tenants
+    pub async fn count_users(&mut self) -> ::std::result::Result<CountUsersResponse, ::rust_janus::JSONRPCError> {
+        self.client.send_typed_request("count_users", &(), self.timeout).await
+    }
+
+    /// Look up a user
+    pub async fn get_user(&mut self, args: GetUserArgs) -> ::std::result::Result<User, ::rust_janus::JSONRPCError> {
+        self.client.send_typed_request("get_user", &args, self.timeout).await
+    }
+
+    /// List users
+    pub async fn list_users(&mut self, args: ListUsersArgs) -> ::std::result::Result<Vec<User>, ::rust_janus::JSONRPCError> {
+        self.client.send_typed_request("list-users", &args, self.timeout).await
+    }
+}
+
+/// Manifest the generated code was built from
+pub const MANIFEST_JSON: &str = r#"{
+  "models": {
+    "User": {
+      "properties": {
+        "displayName": {
+          "default_value": null,
+          "description": null,
+          "modelRef": null,
+          "required": null,
+          "type": "string",
+          "validation": null
+        },
+        "id": {
+          "default_value": null,
+          "description": "Stable user id",
+          "modelRef": null,
+          "required": null,
+          "type": "string",
+          "validation": null
+        },
+        "manager": {
+          "default_value": null,
+          "description": null,
+          "modelRef": "User",
+          "required": null,
+          "type": "object",
+          "validation": null
+        },
+        "scores": {
+          "additionalProperties": {
+            "default_value": null,
+            "description": null,
+            "modelRef": null,
+            "required": null,
+            "type": "number",
+            "validation": null
+          },
+          "default_value": null,
+          "description": null,
+          "modelRef": null,
+          "required": null,
+          "type": "object",
+          "validation": null
+        },
+        "tags": {
+          "default_value": null,
+          "description": null,
+          "items": {
+            "default_value": null,
+            "description": null,
+            "modelRef": null,
+            "required": null,
+            "type": "string",
+            "validation": null
+          },
+          "modelRef": null,
+          "required": null,
+          "type": "array",
+          "validation": null
+        },
+        "type": {
+          "default_value": null,
+          "description": null,
+          "modelRef": null,
+          "required": null,
+          "type": "string",
+          "validation": null
+        }
+      },
+      "required": [
+        "id"
+      ],
+      "type": "object"
+    }
+  },
+  "requests": {
+    "count_users": {
+      "args": {},
+      "description": "Count users\nacross all tenants",
+      "error_codes": null,
+      "response": {
+        "modelRef": null,
+        "properties": {
+          "count": {
+            "default_value": null,
+            "description": null,
+            "modelRef": null,
+            "required": true,
+            "type": "integer",
+            "validation": null
+          },
+          "note": {
+            "default_value": null,
+            "description": null,
+            "modelRef": null,
+            "required": null,
+            "type": "string",
+            "validation": null
+          }
+        },
+        "type": "object"
+      }
+    },
+    "get_user": {
+      "args": {
+        "userId": {
+          "default_value": null,
+          "description": null,
+          "modelRef": null,
+          "required": true,
+          "type": "string",
+          "validation": null
+        },
+        "verbose": {
+          "default_value": null,
+          "description": null,
+          "modelRef": null,
+          "required": null,
+          "type": "boolean",
+          "validation": null
+        }
+      },
+      "description": "Look up a user",
+      "error_codes": null,
+      "response": {
+        "modelRef": "User",
+        "properties": null,
+        "type": "object"
+      }
+    },
+    "list-users": {
+      "args": {
+        "limit": {
+          "default_value": null,
+          "description": null,
+          "modelRef": null,
+          "required": null,
+          "type": "integer",
+          "validation": null
+        }
+      },
+      "description": "List users",
+      "error_codes": null,
+      "response": {
+        "items": {
+          "default_value": null,
+          "description": null,
+          "modelRef": "User",
+          "required": null,
+          "type": "object",
+          "validation": null
+        },
+        "modelRef": null,
+        "properties": null,
+        "type": "array"
+      }
+    }
+  },
+  "version": "1.2.0"
+}"#;
+
+/// Parse the embedded manifest
+pub fn manifest() -> ::rust_janus::Manifest {
+    ::rust_janus::ManifestParser::from_json(MANIFEST_JSON).expect("embedded manifest is valid")
+}
+
+/// Handlers for the requests of manifest version 1.2.0.
+/// Register an implementation with [`register_users_service`].
+pub trait UsersService: Send + Sync + 'static {
+    /// Count users
+    /// across all tenants
+    fn count_users(&self) -> impl ::std::future::Future<Output = ::std::result::Result<CountUsersResponse, ::rust_janus::JSONRPCError>> + Send;
+
+    /// Look up a user
+    fn get_user(&self, args: GetUserArgs) -> impl ::std::future::Future<Output = ::std::result::Result<User, ::rust_janus::JSONRPCError>> + Send;
+
+    /// List users
+    fn list_users(&self, args: ListUsersArgs) -> impl ::std::future::Future<Output = ::std::result::Result<Vec<User>, ::rust_janus::JSONRPCError>> + Send;
+}
+
+/// Attach the embedded manifest and register a handler for every request of `service`
+pub async fn register_users_service<S: UsersService>(server: &mut ::rust_janus::JanusServer, service: S) {
+    let service = ::std::sync::Arc::new(service);
+    server.set_manifest(manifest()).await;
+    {
+        let service = ::std::sync::Arc::clone(&service);
+        server.register_async_handler("count_users", move |_request| {
+            let service = ::std::sync::Arc::clone(&service);
+            async move {
+                ::rust_janus::JanusServer::encode_typed_result(service.count_users().await?)
+            }
+        }).await;
+    }
+    {
+        let service = ::std::sync::Arc::clone(&service);
+        server.register_async_handler("get_user", move |request| {
+            let service = ::std::sync::Arc::clone(&service);
+            async move {
+                let args = ::rust_janus::JanusServer::decode_typed_args::<GetUserArgs>(&request)?;
+                ::rust_janus::JanusServer::encode_typed_result(service.get_user(args).await?)
+            }
+        }).await;
+    }
+    {
+        let service = ::std::sync::Arc::clone(&service);
+        server.register_async_handler("list-users", move |request| {
+            let service = ::std::sync::Arc::clone(&service);
+            async move {
+                let args = ::rust_janus::JanusServer::decode_typed_args::<ListUsersArgs>(&request)?;
+                ::rust_janus::JanusServer::encode_typed_result(service.list_users(args).await?)
+            }
+        }).await;
+    }
+}
@@ -0,0 +1,274 @@
+use rust_janus::*;
+use serde_json::json;
+
+/// JSON Schema Export and Import Tests
+/// Tests conversion between manifest definitions and JSON Schema 2020-12 documents
+
+fn create_schema_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.2.0".to_string());
+
+    let mut address = ModelManifest::new();
+    address.add_property("street".to_string(), ArgumentManifest::new("string".to_string()).required());
+    address.add_property(
+        "zip".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .with_validation(ValidationManifest::new().with_pattern("^[0-9]{5}$".to_string())),
+    );
+    manifest.add_model("Address".to_string(), address);
+
+    let mut user = ModelManifest::new().with_required(vec!["id".to_string()]);
+    user.add_property("id".to_string(), ArgumentManifest::new("string".to_string()));
+    user.add_property(
+        "address".to_string(),
+        ArgumentManifest::new("object".to_string()).with_model_ref("Address".to_string()),
+    );
+    manifest.add_model("User".to_string(), user);
+
+    let mut response = ResponseManifest::new("object".to_string());
+    response.model_ref = Some("User".to_string());
+    let mut request = RequestManifest::new("Create a user".to_string(), response);
+    request.add_argument(
+        "name".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .required()
+            .with_description("Display name".to_string())
+            .with_validation(ValidationManifest::new().with_length_range(Some(1), Some(32))),
+    );
+    request.add_argument(
+        "age".to_string(),
+        ArgumentManifest::new("integer".to_string())
+            .with_default(json!(18))
+            .with_validation(ValidationManifest::new().with_numeric_range(Some(0.0), Some(150.5))),
+    );
+    request.add_argument(
+        "role".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .with_validation(ValidationManifest::new().with_enum(vec![json!("admin"), json!("member")])),
+    );
+    request.add_argument(
+        "tags".to_string(),
+        ArgumentManifest::new("array".to_string())
+            .with_validation(ValidationManifest::new().with_length_range(None, Some(5))),
+    );
+    request.add_argument(
+        "address".to_string(),
+        ArgumentManifest::new("object".to_string()).with_model_ref("Address".to_string()),
+    );
+    manifest.add_request("create_user".to_string(), request);
+
+    manifest
+}
+
+#[test]
+fn test_export_request_args_maps_constraints() {
+    let manifest = create_schema_manifest();
+    let schema = JsonSchemaExporter::export_request_args(&manifest, "create_user").unwrap();
+
+    assert_eq!(schema["$schema"], json!("https://json-schema.org/draft/2020-12/schema"));
+    assert_eq!(schema["type"], json!("object"));
+    assert_eq!(schema["required"], json!(["name"]));
+
+    let properties = &schema["properties"];
+    assert_eq!(properties["name"], json!({
+        "type": "string",
+        "description": "Display name",
+        "minLength": 1,
+        "maxLength": 32
+    }));
+    assert_eq!(properties["age"], json!({
+        "type": "integer",
+        "default": 18,
+        "minimum": 0,
+        "maximum": 150.5
+    }));
+    assert_eq!(properties["role"]["enum"], json!(["admin", "member"]));
+    assert_eq!(properties["tags"]["maxItems"], json!(5));
+    assert_eq!(properties["address"]["$ref"], json!("#/$defs/Address"));
+
+    // Only referenced models are embedded
+    assert!(schema["$defs"]["Address"].is_object());
+    assert!(schema["$defs"].get("User").is_none());
+}
+
+#[test]
+fn test_export_request_response_embeds_transitive_models() {
+    let manifest = create_schema_manifest();
+    let schema = JsonSchemaExporter::export_request_response(&manifest, "create_user").unwrap();
+
+    assert_eq!(schema["$ref"], json!("#/$defs/User"));
+    assert_eq!(schema["$defs"]["User"]["required"], json!(["id"]));
+    assert_eq!(schema["$defs"]["Address"]["properties"]["zip"]["pattern"], json!("^[0-9]{5}$"));
+}
+
+#[test]
+fn test_export_manifest_bundle() {
+    let manifest = create_schema_manifest();
+    let bundle = JsonSchemaExporter::export_manifest(&manifest);
+
+    assert_eq!(bundle["version"], json!("1.2.0"));
+    assert_eq!(bundle["$defs"].as_object().unwrap().len(), 2);
+    assert_eq!(bundle["requests"]["create_user"]["description"], json!("Create a user"));
+    assert_eq!(bundle["requests"]["create_user"]["response"]["$ref"], json!("#/$defs/User"));
+}
+
+#[test]
+fn test_export_model() {
+    let manifest = create_schema_manifest();
+    let schema = JsonSchemaExporter::export_model(&manifest, "User").unwrap();
+
+    assert_eq!(schema["title"], json!("User"));
+    assert_eq!(schema["properties"]["id"], json!({"type": "string"}));
+    assert!(schema["$defs"]["Address"].is_object());
+}
+
+#[test]
+fn test_export_unknown_request_and_model() {
+    let manifest = create_schema_manifest();
+
+    let error = JsonSchemaExporter::export_request_args(&manifest, "missing").unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ResourceNotFound.code());
+    assert!(JsonSchemaExporter::export_model(&manifest, "Missing").is_err());
+}
+
+#[test]
+fn test_import_models_from_defs() {
+    let document = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$defs": {
+            "Address": {
+                "type": "object",
+                "properties": {
+                    "street": {"type": "string", "minLength": 1},
+                    "zip": {"type": "string", "pattern": "^[0-9]{5}$"}
+                },
+                "required": ["street"]
+            },
+            "User": {
+                "type": "object",
+                "description": "A user account",
+                "properties": {
+                    "id": {"type": "string", "format": "uuid"},
+                    "age": {"type": "integer", "minimum": 0, "maximum": 150, "default": 18},
+                    "role": {"enum": ["admin", "member"]},
+                    "tags": {"type": "array", "maxItems": 5},
+                    "address": {"$ref": "#/$defs/Address", "description": "Home address"}
+                },
+                "required": ["id", "address"]
+            }
+        }
+    });
+
+    let models = JsonSchemaImporter::import_models(&document, None).unwrap();
+    assert_eq!(models.len(), 2);
+
+    let user = &models["User"];
+    assert!(user.is_property_required("id"));
+    assert!(!user.is_property_required("age"));
+
+    let age = &user.properties["age"];
+    assert_eq!(age.r#type, "integer");
+    assert_eq!(age.default_value, Some(json!(18)));
+    let age_validation = age.validation.as_ref().unwrap();
+    assert_eq!(age_validation.minimum, Some(0.0));
+    assert_eq!(age_validation.maximum, Some(150.0));
+
+    let role = &user.properties["role"];
+    assert_eq!(role.r#type, "string");
+    assert_eq!(role.validation.as_ref().unwrap().r#enum, Some(vec![json!("admin"), json!("member")]));
+
+    assert_eq!(user.properties["tags"].validation.as_ref().unwrap().max_length, Some(5));
+
+    let address = &user.properties["address"];
+    assert_eq!(address.model_ref.as_deref(), Some("Address"));
+    assert_eq!(address.description.as_deref(), Some("Home address"));
+
+    let zip = &models["Address"].properties["zip"];
+    assert_eq!(zip.validation.as_ref().unwrap().pattern.as_deref(), Some("^[0-9]{5}$"));
+}
+
+#[test]
+fn test_import_root_schema_as_model() {
+    let document = json!({
+        "type": "object",
+        "properties": {
+            "name": {"type": "string", "maxLength": 20}
+        },
+        "required": ["name"]
+    });
+
+    let models = JsonSchemaImporter::import_models(&document, Some("Person")).unwrap();
+    assert!(models["Person"].is_property_required("name"));
+}
+
+#[test]
+fn test_import_reports_path_qualified_errors() {
+    let document = json!({
+        "$defs": {
+            "Broken": {
+                "type": "object",
+                "properties": {
+                    "choice": {"oneOf": [{"type": "string"}, {"type": "integer"}]},
+                    "nullable": {"type": ["string", "null"]},
+                    "nested": {"type": "object", "properties": {"x": {"type": "string"}}},
+                    "remote": {"$ref": "https://example.com/schema.json"},
+                    "missing": {"$ref": "#/$defs/Nowhere"},
+                    "bad_pattern": {"type": "string", "pattern": "(unclosed"},
+                    "nothing": {"enum": [null]}
+                }
+            },
+            "NotAnObject": {"type": "string"}
+        }
+    });
+
+    let error = JsonSchemaImporter::import_models(&document, None).unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ManifestValidationError.code());
+
+    let errors = error.data.unwrap().context.unwrap()["errors"].clone();
+    let errors: Vec<String> = serde_json::from_value(errors).unwrap();
+    let expect = |fragment: &str| {
+        assert!(errors.iter().any(|e| e.contains(fragment)), "no error containing '{}' in {:?}", fragment, errors);
+    };
+    expect("#/$defs/Broken/properties/choice/oneOf: unsupported keyword 'oneOf'");
+    expect("#/$defs/Broken/properties/nullable/type: multiple types");
+    expect("#/$defs/Broken/properties/nested: inline object schemas");
+    expect("#/$defs/Broken/properties/remote/$ref: only local $defs references");
+    expect("#/$defs/Broken/properties/missing/$ref: $ref target 'Nowhere' is not defined");
+    expect("#/$defs/Broken/properties/bad_pattern/pattern: invalid regex pattern");
+    expect("#/$defs/Broken/properties/nothing/enum: null enum values cannot be represented");
+    expect("#/$defs/NotAnObject: only object schemas");
+}
+
+#[test]
+fn test_import_widens_mixed_numeric_enums() {
+    let document = json!({
+        "type": "object",
+        "properties": {
+            "ratio": {"enum": [1, 2.5]},
+            "level": {"enum": [1, 2]}
+        }
+    });
+
+    let models = JsonSchemaImporter::import_models(&document, Some("Scale")).unwrap();
+    let ratio = &models["Scale"].properties["ratio"];
+    assert_eq!(ratio.r#type, "number");
+    assert_eq!(ratio.validation.as_ref().unwrap().r#enum, Some(vec![json!(1), json!(2.5)]));
+    assert_eq!(models["Scale"].properties["level"].r#type, "integer");
+}
+
+#[test]
+fn test_import_into_manifest_round_trip() {
+    let source = create_schema_manifest();
+    let bundle = JsonSchemaExporter::export_manifest(&source);
+
+    let mut manifest = Manifest::new("1.0.0".to_string());
+    let imported = JsonSchemaImporter::import_into(&mut manifest, &bundle, None).unwrap();
+    assert_eq!(imported, vec!["Address", "User"]);
+    assert!(ManifestParser::validate(&manifest).is_ok());
+
+    let address = manifest.get_model("Address").unwrap();
+    assert!(address.is_property_required("street"));
+    assert_eq!(address.properties["zip"], source.get_model("Address").unwrap().properties["zip"]);
+
+    // Importing the same models again conflicts
+    assert!(JsonSchemaImporter::import_into(&mut manifest, &bundle, None).is_err());
+}
@@ -0,0 +1,132 @@
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+use serde_json::json;
+use std::time::Duration;
+
+/// Manifest Cache Tests
+/// Tests the Manifest cache shared by clients and its revalidation by fingerprint
+
+fn create_manifest(version: &str, max_length: usize) -> Manifest {
+    let mut manifest = Manifest::new(version.to_string());
+    let mut request = RequestManifest::new("Greet a user".to_string(), ResponseManifest::new("object".to_string()));
+    request.add_argument(
+        "name".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .required()
+            .with_validation(ValidationManifest::new().with_length_range(None, Some(max_length))),
+    );
+    manifest.add_request("greet".to_string(), request);
+    manifest
+}
+
+async fn start_server(socket_path: &str, manifest: Manifest) -> JanusServer {
+    let mut server = create_manifest_server(socket_path, manifest).await;
+    server.register_handler("greet", |cmd| Ok(json!({"greeting": cmd.args}))).await;
+    server.start_listening().await.expect("Failed to start server");
+    server
+}
+
+#[test]
+fn test_fingerprint_follows_manifest_content() {
+    let manifest = create_manifest("1.0.0", 3);
+    let fingerprint = manifest.fingerprint();
+    assert_eq!(fingerprint.len(), 16);
+    assert_eq!(create_manifest("1.0.0", 3).fingerprint(), fingerprint);
+
+    // Serialization round trips keep the fingerprint
+    let reloaded = ManifestParser::load_and_validate_json(&ManifestParser::to_json(&manifest).unwrap()).unwrap();
+    assert_eq!(reloaded.fingerprint(), fingerprint);
+
+    assert_ne!(create_manifest("1.0.0", 4).fingerprint(), fingerprint);
+    assert_ne!(create_manifest("1.0.1", 3).fingerprint(), fingerprint);
+}
+
+#[tokio::test]
+async fn test_server_confirms_known_fingerprint() {
+    let socket_path = &unique_socket_path("manifest_cache_known_hash");
+    let manifest = create_manifest("1.0.0", 3);
+    let mut server = start_server(socket_path, manifest.clone()).await;
+
+    let mut client = create_client(socket_path, server_validation_config()).await;
+
+    let response = client
+        .send_request("manifest", args(json!({"known_hash": manifest.fingerprint()})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap();
+    assert_eq!(response.result.unwrap(), json!({"unchanged": true, "fingerprint": manifest.fingerprint()}));
+
+    // Any other fingerprint gets the whole Manifest
+    let response = client
+        .send_request("manifest", args(json!({"known_hash": "0000000000000000"})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap();
+    assert_eq!(response.result.unwrap()["version"], json!("1.0.0"));
+
+    let response = client
+        .send_request("manifest", args(json!({"known_hash": 42})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap();
+    assert_eq!(response.error.unwrap().code, JSONRPCErrorCode::InvalidParams.code());
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_clients_share_cached_manifest() {
+    let socket_path = &unique_socket_path("manifest_cache_shared");
+    let mut server = start_server(socket_path, create_manifest("1.0.0", 3)).await;
+
+    let mut first = create_client(socket_path, JanusClientConfig::default()).await;
+    let response = first.send_request("greet", args(json!({"name": "bob"})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(response.success);
+    let cached = ManifestCache::get(socket_path).expect("manifest was not cached");
+    assert_eq!(cached.fingerprint, create_manifest("1.0.0", 3).fingerprint());
+
+    // A later client validates with the cached Manifest even though the server no longer serves one
+    server.clear_manifest().await;
+    let mut second = create_client(socket_path, JanusClientConfig::default()).await;
+    let error = second
+        .send_request("greet", args(json!({"name": "alice"})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ManifestValidationError.code());
+
+    // Invalidating forces a refetch
+    ManifestCache::invalidate(socket_path);
+    assert!(ManifestCache::get(socket_path).is_none());
+    server.set_manifest(create_manifest("1.0.0", 10)).await;
+    let response = second.send_request("greet", args(json!({"name": "alice"})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(response.success);
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_stale_cache_is_revalidated() {
+    let socket_path = &unique_socket_path("manifest_cache_stale");
+    let mut server = start_server(socket_path, create_manifest("1.0.0", 3)).await;
+
+    let config = JanusClientConfig { manifest_cache_ttl: Duration::from_millis(50), ..Default::default() };
+    let mut client = create_client(socket_path, config).await;
+    assert!(client.send_request("greet", args(json!({"name": "alice"})), Some(Duration::from_secs(5))).await.is_err());
+    let first = ManifestCache::get(socket_path).unwrap();
+    let first_validation = first.validated_at;
+
+    // An unchanged Manifest is confirmed in place
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(client.send_request("greet", args(json!({"name": "bob"})), Some(Duration::from_secs(5))).await.unwrap().success);
+    let cached = ManifestCache::get(socket_path).unwrap();
+    assert!(cached.validated_at > first_validation);
+    assert_eq!(cached.manifest.version, "1.0.0");
+    assert!(std::sync::Arc::ptr_eq(&cached.manifest, &first.manifest));
+
+    // A changed Manifest replaces the cached one
+    server.set_manifest(create_manifest("1.1.0", 10)).await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let response = client.send_request("greet", args(json!({"name": "alice"})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(response.success);
+    assert_eq!(ManifestCache::get(socket_path).unwrap().manifest.version, "1.1.0");
+
+    server.stop();
+}
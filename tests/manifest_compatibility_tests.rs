@@ -0,0 +1,184 @@
+use rust_janus::*;
+use serde_json::json;
+
+/// Manifest Compatibility Tests
+/// Tests change classification between manifest versions and semver bump checks
+
+fn create_base_manifest(version: &str) -> Manifest {
+    let mut manifest = Manifest::new(version.to_string());
+
+    let mut user = ModelManifest::new().with_required(vec!["id".to_string()]);
+    user.add_property("id".to_string(), ArgumentManifest::new("string".to_string()));
+    user.add_property("email".to_string(), ArgumentManifest::new("string".to_string()));
+    manifest.add_model("User".to_string(), user);
+
+    let mut response = ResponseManifest::new("object".to_string());
+    response.model_ref = Some("User".to_string());
+    let mut get_user = RequestManifest::new("Look up a user".to_string(), response);
+    get_user.add_argument(
+        "user_id".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .required()
+            .with_validation(ValidationManifest::new().with_length_range(Some(1), Some(64))),
+    );
+    get_user.add_argument(
+        "view".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .with_validation(ValidationManifest::new().with_enum(vec![json!("short"), json!("full")])),
+    );
+    get_user.add_argument(
+        "limit".to_string(),
+        ArgumentManifest::new("integer".to_string())
+            .with_validation(ValidationManifest::new().with_numeric_range(Some(1.0), Some(100.0))),
+    );
+    manifest.add_request("get_user".to_string(), get_user);
+
+    manifest.add_request(
+        "list_users".to_string(),
+        RequestManifest::new("List users".to_string(), ResponseManifest::new("array".to_string())),
+    );
+
+    manifest
+}
+
+fn get_user_args(manifest: &mut Manifest) -> &mut std::collections::HashMap<String, ArgumentManifest> {
+    &mut manifest.requests.as_mut().unwrap().get_mut("get_user").unwrap().args
+}
+
+fn find<'a>(report: &'a CompatibilityReport, path: &str) -> &'a ManifestChange {
+    report.changes
+        .iter()
+        .find(|change| change.path == path)
+        .unwrap_or_else(|| panic!("no change at '{}' in {:?}", path, report.changes))
+}
+
+#[test]
+fn test_identical_manifests_have_no_changes() {
+    let report = ManifestParser::diff(&create_base_manifest("1.0.0"), &create_base_manifest("1.0.0"));
+    assert!(report.changes.is_empty());
+    assert!(report.is_compatible());
+    assert_eq!(report.required_bump(), VersionBump::None);
+}
+
+#[test]
+fn test_removed_request_is_breaking() {
+    let old = create_base_manifest("1.0.0");
+    let mut new = create_base_manifest("1.1.0");
+    new.requests.as_mut().unwrap().remove("list_users");
+
+    let report = ManifestParser::diff(&old, &new);
+    assert_eq!(find(&report, "requests.list_users").severity, ChangeSeverity::Breaking);
+    assert!(!report.is_compatible());
+}
+
+#[test]
+fn test_argument_changes() {
+    let old = create_base_manifest("1.0.0");
+    let mut new = create_base_manifest("2.0.0");
+    let args = get_user_args(&mut new);
+    args.insert("tenant".to_string(), ArgumentManifest::new("string".to_string()).required());
+    args.insert("verbose".to_string(), ArgumentManifest::new("boolean".to_string()));
+    args.get_mut("view").unwrap().validation = Some(ValidationManifest::new().with_enum(vec![json!("short")]));
+    args.get_mut("limit").unwrap().validation = Some(ValidationManifest::new().with_numeric_range(Some(1.0), Some(50.0)));
+    args.get_mut("user_id").unwrap().validation = Some(ValidationManifest::new().with_length_range(Some(1), Some(128)));
+
+    let report = ManifestParser::diff(&old, &new);
+    assert_eq!(find(&report, "requests.get_user.args.tenant").severity, ChangeSeverity::Breaking);
+    assert_eq!(find(&report, "requests.get_user.args.verbose").severity, ChangeSeverity::NonBreaking);
+
+    let view = find(&report, "requests.get_user.args.view");
+    assert_eq!(view.severity, ChangeSeverity::Breaking);
+    assert!(view.description.contains("enum narrowed"));
+
+    let limit = find(&report, "requests.get_user.args.limit");
+    assert_eq!(limit.severity, ChangeSeverity::Breaking);
+    assert!(limit.description.contains("maximum lowered"));
+
+    // Loosening an input constraint keeps callers working
+    assert_eq!(find(&report, "requests.get_user.args.user_id").severity, ChangeSeverity::NonBreaking);
+}
+
+#[test]
+fn test_widened_enum_and_optional_argument_are_non_breaking() {
+    let old = create_base_manifest("1.0.0");
+    let mut new = create_base_manifest("1.1.0");
+    let args = get_user_args(&mut new);
+    args.get_mut("view").unwrap().validation =
+        Some(ValidationManifest::new().with_enum(vec![json!("short"), json!("full"), json!("debug")]));
+    args.get_mut("user_id").unwrap().required = Some(false);
+
+    let report = ManifestParser::check_compatibility(&old, &new).unwrap();
+    assert!(report.is_compatible());
+    assert_eq!(report.required_bump(), VersionBump::Minor);
+}
+
+#[test]
+fn test_response_and_model_changes() {
+    let old = create_base_manifest("1.0.0");
+    let mut new = create_base_manifest("2.0.0");
+    new.requests.as_mut().unwrap().get_mut("list_users").unwrap().response.r#type = "object".to_string();
+    let user = new.models.as_mut().unwrap().get_mut("User").unwrap();
+    user.properties.remove("email");
+    user.add_property("name".to_string(), ArgumentManifest::new("string".to_string()));
+
+    let report = ManifestParser::diff(&old, &new);
+    assert_eq!(find(&report, "requests.list_users.response").severity, ChangeSeverity::Breaking);
+    assert_eq!(find(&report, "models.User.properties.email").severity, ChangeSeverity::Breaking);
+    assert_eq!(find(&report, "models.User.properties.name").severity, ChangeSeverity::NonBreaking);
+}
+
+#[test]
+fn test_output_model_narrowing_is_non_breaking() {
+    let old = create_base_manifest("1.0.0");
+    let mut new = create_base_manifest("1.1.0");
+    // User is only returned, so promising a field more often cannot break callers
+    new.models.as_mut().unwrap().get_mut("User").unwrap().add_required("email".to_string());
+
+    let report = ManifestParser::diff(&old, &new);
+    assert_eq!(find(&report, "models.User.properties.email").severity, ChangeSeverity::NonBreaking);
+}
+
+#[test]
+fn test_version_bump_must_match_breaking_changes() {
+    let old = create_base_manifest("1.4.2");
+    let mut new = create_base_manifest("1.5.0");
+    new.requests.as_mut().unwrap().remove("list_users");
+
+    let error = ManifestParser::check_compatibility(&old, &new).unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ManifestValidationError.code());
+    assert!(error.to_string().contains("requests.list_users"));
+
+    new.version = "2.0.0".to_string();
+    assert!(ManifestParser::check_compatibility(&old, &new).is_ok());
+}
+
+#[test]
+fn test_version_bump_rules() {
+    let old = create_base_manifest("1.0.0");
+
+    // Additive change needs at least a minor bump
+    let mut new = create_base_manifest("1.0.1");
+    get_user_args(&mut new).insert("verbose".to_string(), ArgumentManifest::new("boolean".to_string()));
+    assert!(ManifestParser::check_compatibility(&old, &new).is_err());
+
+    // Version going backwards is rejected
+    let new = create_base_manifest("0.9.0");
+    assert!(ManifestParser::check_compatibility(&old, &new).is_err());
+
+    // Pre-1.0 manifests may break on a minor bump
+    let old = create_base_manifest("0.3.0");
+    let mut new = create_base_manifest("0.4.0");
+    new.requests.as_mut().unwrap().remove("list_users");
+    assert!(ManifestParser::check_compatibility(&old, &new).is_ok());
+}
+
+#[test]
+fn test_manifest_version_parsing() {
+    let version = ManifestVersion::parse("1.10.3").unwrap();
+    assert_eq!(version, ManifestVersion::new(1, 10, 3));
+    assert_eq!(version.to_string(), "1.10.3");
+    assert!(ManifestVersion::parse("1.0").is_err());
+    assert!(ManifestVersion::parse("1.0.x").is_err());
+    assert!(ManifestVersion::new(2, 0, 0) > ManifestVersion::new(1, 99, 99));
+    assert_eq!(ManifestVersion::new(1, 2, 0).bump_from(&ManifestVersion::new(1, 1, 9)), Some(VersionBump::Minor));
+}
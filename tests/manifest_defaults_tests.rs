@@ -0,0 +1,99 @@
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+use serde_json::json;
+use std::time::Duration;
+
+/// Manifest Default Value Tests
+/// Tests that omitted optional arguments are completed from Manifest defaults on both sides
+
+fn create_search_request() -> RequestManifest {
+    let mut request = RequestManifest::new(
+        "Search the catalog".to_string(),
+        ResponseManifest::new("object".to_string()),
+    );
+    request.add_argument("query".to_string(), ArgumentManifest::new("string".to_string()).required());
+    request.add_argument(
+        "limit".to_string(),
+        ArgumentManifest::new("integer".to_string())
+            .with_default(json!(10))
+            .with_validation(ValidationManifest::new().with_numeric_range(Some(1.0), Some(50.0))),
+    );
+    request.add_argument("order".to_string(), ArgumentManifest::new("string".to_string()).with_default(json!("relevance")));
+    request.add_argument("cursor".to_string(), ArgumentManifest::new("string".to_string()));
+    request
+}
+
+fn create_search_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+    manifest.add_request("search".to_string(), create_search_request());
+    manifest
+}
+
+async fn start_search_server(socket_path: &str) -> JanusServer {
+    let mut server = create_manifest_server(socket_path, create_search_manifest()).await;
+    server.register_handler("search", |cmd| Ok(json!(cmd.args))).await;
+    server.start_listening().await.expect("Failed to start server");
+    server
+}
+
+#[test]
+fn test_apply_defaults_fills_only_omitted_arguments() {
+    let request = create_search_request();
+
+    let mut provided = args(json!({"query": "rust", "order": "newest"}));
+    assert_eq!(request.apply_defaults(&mut provided), vec!["limit".to_string()]);
+    assert_eq!(provided, args(json!({"query": "rust", "order": "newest", "limit": 10})));
+
+    // Explicit nulls are the caller's choice and are kept
+    let mut explicit = args(json!({"query": "rust", "limit": null, "order": "newest"}));
+    assert!(request.apply_defaults(&mut explicit).is_empty());
+    assert_eq!(explicit, args(json!({"query": "rust", "limit": null, "order": "newest"})));
+
+    // Missing argument maps are created
+    let mut missing = None;
+    assert_eq!(request.apply_defaults(&mut missing), vec!["limit".to_string(), "order".to_string()]);
+    assert_eq!(missing, args(json!({"limit": 10, "order": "relevance"})));
+
+    // Required arguments are never filled in
+    let mut request = create_search_request();
+    request.add_argument("scope".to_string(), ArgumentManifest::new("string".to_string()).required().with_default(json!("all")));
+    let mut provided = args(json!({"query": "rust", "limit": 5, "order": "newest"}));
+    assert!(request.apply_defaults(&mut provided).is_empty());
+}
+
+#[tokio::test]
+async fn test_server_applies_defaults_before_dispatch() {
+    let socket_path = &unique_socket_path("defaults_server");
+    let mut server = start_search_server(socket_path).await;
+
+    let mut client = create_client(socket_path, server_validation_config()).await;
+
+    let response = client
+        .send_request("search", args(json!({"query": "rust"})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap();
+    assert!(response.success);
+    assert_eq!(response.result, Some(json!({"query": "rust", "limit": 10, "order": "relevance"})));
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_client_applies_defaults_before_sending() {
+    let socket_path = &unique_socket_path("defaults_client");
+    let mut server = start_search_server(socket_path).await;
+
+    let mut client = create_client(socket_path, JanusClientConfig::default()).await;
+    client.fetch_manifest().await.unwrap();
+
+    // With the server's Manifest detached, the defaults can only come from the client
+    server.clear_manifest().await;
+    let response = client
+        .send_request("search", args(json!({"query": "rust", "limit": 3})), Some(Duration::from_secs(5)))
+        .await
+        .unwrap();
+    assert_eq!(response.result, Some(json!({"query": "rust", "limit": 3, "order": "relevance"})));
+
+    server.stop();
+}
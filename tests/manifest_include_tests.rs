@@ -0,0 +1,131 @@
+use rust_janus::*;
+use std::path::{Path, PathBuf};
+
+/// Manifest Include Tests
+/// Tests resolving include directives across manifest files relative to the including file
+
+fn create_manifest_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rust_janus_include_{}", name));
+    let _ = std::fs::remove_dir_all(&dir);
+    for (file, content) in files {
+        let path = dir.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+    dir
+}
+
+fn path_str(dir: &Path, file: &str) -> String {
+    dir.join(file).to_string_lossy().to_string()
+}
+
+const SHARED_MODELS: &str = r#"{
+    "version": "1.0.0",
+    "include": ["base.json"],
+    "models": {
+        "User": {"type": "object", "properties": {"id": {"type": "string"}, "audit": {"type": "object", "modelRef": "Audit"}}}
+    }
+}"#;
+
+const BASE_MODELS: &str = r#"{
+    "version": "1.0.0",
+    "models": {
+        "Audit": {"type": "object", "properties": {"created_at": {"type": "string"}}}
+    }
+}"#;
+
+const USERS_SERVICE: &str = r#"{
+    "version": "2.3.0",
+    "include": ["../shared/models.json"],
+    "requests": {
+        "get_user": {
+            "description": "Look up a user",
+            "args": {"user_id": {"type": "string", "required": true}},
+            "response": {"type": "object", "modelRef": "User"}
+        }
+    }
+}"#;
+
+#[tokio::test]
+async fn test_includes_resolve_relative_to_including_file() {
+    let dir = create_manifest_dir("relative", &[
+        ("shared/models.json", SHARED_MODELS),
+        ("shared/base.json", BASE_MODELS),
+        ("users/api.json", USERS_SERVICE),
+    ]);
+
+    let manifest = ManifestParser::load_and_validate(&path_str(&dir, "users/api.json")).await.unwrap();
+    assert_eq!(manifest.version, "2.3.0");
+    assert!(manifest.has_request("get_user"));
+    assert!(manifest.get_model("User").is_some());
+    assert!(manifest.get_model("Audit").is_some());
+    assert!(manifest.include.is_none());
+}
+
+#[tokio::test]
+async fn test_shared_include_is_merged_once() {
+    let dir = create_manifest_dir("diamond", &[
+        ("shared/models.json", SHARED_MODELS),
+        ("shared/base.json", BASE_MODELS),
+        ("users/api.json", USERS_SERVICE),
+        ("audit/api.json", r#"{"version": "1.0.0", "include": ["../shared/base.json"]}"#),
+        ("gateway.json", r#"{"version": "3.0.0", "include": ["users/api.json", "audit/api.json"]}"#),
+    ]);
+
+    let manifest = ManifestParser::from_file(&path_str(&dir, "gateway.json")).await.unwrap();
+    assert_eq!(manifest.models.as_ref().unwrap().len(), 2);
+
+    // Listing files explicitly shares the same include tree
+    let files = vec![path_str(&dir, "users/api.json"), path_str(&dir, "audit/api.json")];
+    let merged = ManifestParser::parse_multiple_files(&files).await.unwrap();
+    assert!(merged.get_model("Audit").is_some());
+}
+
+#[tokio::test]
+async fn test_include_cycle_is_detected() {
+    let dir = create_manifest_dir("cycle", &[
+        ("a.json", r#"{"version": "1.0.0", "include": ["nested/b.json"]}"#),
+        ("nested/b.json", r#"{"version": "1.0.0", "include": ["../a.json"]}"#),
+    ]);
+
+    let error = ManifestParser::from_file(&path_str(&dir, "a.json")).await.unwrap_err();
+    let message = error.to_string();
+    assert!(message.contains("include cycle detected"));
+    assert!(message.contains("a.json ->"));
+    assert!(message.contains("b.json ->"));
+}
+
+#[tokio::test]
+async fn test_include_errors_carry_file_context() {
+    let dir = create_manifest_dir("errors", &[
+        ("missing.json", r#"{"version": "1.0.0", "include": ["nowhere.json"]}"#),
+        ("broken.json", r#"{"version": "1.0.0", "include": ["syntax.json"]}"#),
+        ("syntax.json", r#"{"version": "1.0.0", "models": "#),
+        ("conflict.json", r#"{"version": "1.0.0", "include": ["base.json"], "models": {"Audit": {"type": "object", "properties": {}}}}"#),
+        ("base.json", BASE_MODELS),
+    ]);
+
+    let error = ManifestParser::from_file(&path_str(&dir, "missing.json")).await.unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ResourceNotFound.code());
+    assert!(error.to_string().contains("nowhere.json"));
+    assert!(error.to_string().contains("included from:"));
+
+    let error = ManifestParser::from_file(&path_str(&dir, "broken.json")).await.unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ParseError.code());
+    assert!(error.to_string().contains("syntax.json"));
+
+    let error = ManifestParser::from_file(&path_str(&dir, "conflict.json")).await.unwrap_err();
+    let message = error.to_string();
+    assert!(message.contains("Model 'Audit' already exists"));
+    assert!(message.contains("base.json"));
+    assert!(message.contains("conflict.json"));
+}
+
+#[test]
+fn test_unresolved_includes_fail_validation() {
+    let manifest = ManifestParser::from_json(r#"{"version": "1.0.0", "include": ["models.json"]}"#).unwrap();
+    assert_eq!(manifest.include, Some(vec!["models.json".to_string()]));
+
+    let error = ManifestParser::validate(&manifest).unwrap_err();
+    assert!(error.to_string().contains("models.json"));
+}
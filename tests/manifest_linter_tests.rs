@@ -0,0 +1,162 @@
+use rust_janus::*;
+
+/// Manifest Linter Tests
+/// Tests collecting every manifest issue with severity and source location
+
+const BROKEN_MANIFEST: &str = r#"{
+  "version": "1.0",
+  "requests": {
+    "get_user": {
+      "description": "",
+      "args": {
+        "id": {"type": "string", "required": true, "description": "User id",
+               "validation": {"pattern": "([a-z"}},
+        "view": {"type": "string", "description": "Detail level", "default_value": "huge",
+                 "validation": {"enum": ["short", "full"]}}
+      },
+      "response": {"type": "object", "modelRef": "User"}
+    }
+  },
+  "models": {
+    "User": {
+      "type": "object",
+      "required": ["id", "nmae"],
+      "properties": {
+        "id": {"type": "string", "description": "User id"},
+        "friend": {"type": "object", "description": "Best friend", "modelRef": "Ghost"}
+      }
+    },
+    "Orphan": {"type": "object", "properties": {}}
+  }
+}"#;
+
+const CLEAN_MANIFEST: &str = r#"{
+  "version": "1.0.0",
+  "requests": {
+    "get_user": {
+      "description": "Look up a user",
+      "args": {"id": {"type": "string", "required": true, "description": "User id"}},
+      "response": {"type": "object", "modelRef": "User"}
+    }
+  },
+  "models": {
+    "User": {"type": "object", "required": ["id"], "properties": {"id": {"type": "string", "description": "User id"}}}
+  }
+}"#;
+
+fn find<'a>(report: &'a LintReport, code: &str) -> &'a LintIssue {
+    report.issues
+        .iter()
+        .find(|issue| issue.code == code)
+        .unwrap_or_else(|| panic!("no '{}' issue in {:?}", code, report.issues))
+}
+
+#[test]
+fn test_collects_every_issue_with_locations() {
+    let report = ManifestLinter::lint_json(BROKEN_MANIFEST);
+    assert!(report.has_errors());
+
+    let version = find(&report, "invalid-version");
+    assert_eq!((version.line, version.column), (Some(2), Some(3)));
+    assert_eq!(version.severity, LintSeverity::Error);
+
+    let pattern = find(&report, "invalid-pattern");
+    assert_eq!(pattern.path, "requests.get_user.args.id.validation.pattern");
+    assert_eq!((pattern.line, pattern.column), (Some(8), Some(31)));
+
+    let default = find(&report, "default-not-in-enum");
+    assert_eq!(default.line, Some(9));
+    assert!(default.message.contains("\"huge\""));
+
+    let required = find(&report, "unknown-required-property");
+    assert_eq!(required.line, Some(18));
+    assert!(required.message.contains("'nmae'"));
+
+    assert_eq!(find(&report, "unresolved-model-ref").path, "models.User.properties.friend.modelRef");
+    assert_eq!(find(&report, "missing-description").severity, LintSeverity::Warning);
+
+    let orphan: Vec<&str> = report.issues
+        .iter()
+        .filter(|issue| issue.path.starts_with("models.Orphan"))
+        .map(|issue| issue.code.as_str())
+        .collect();
+    assert_eq!(orphan, vec!["unused-model", "empty-properties"]);
+
+    // Issues are ordered as they appear in the source
+    let lines: Vec<usize> = report.issues.iter().map(|issue| issue.line.unwrap()).collect();
+    let mut sorted_lines = lines.clone();
+    sorted_lines.sort();
+    assert_eq!(lines, sorted_lines);
+}
+
+#[test]
+fn test_clean_manifest_has_no_issues() {
+    let report = ManifestLinter::lint_json(CLEAN_MANIFEST);
+    assert!(report.issues.is_empty(), "unexpected issues: {:?}", report.issues);
+}
+
+#[test]
+fn test_parse_errors_are_reported_with_location() {
+    let report = ManifestLinter::lint_json("{\n  \"version\": \"1.0.0\"\n  \"requests\": {}\n}");
+    assert_eq!(report.issues.len(), 1);
+    let issue = &report.issues[0];
+    assert_eq!(issue.code, "parse-error");
+    assert_eq!(issue.line, Some(3));
+}
+
+#[test]
+fn test_lint_parsed_manifest() {
+    let mut manifest = ManifestParser::from_json(CLEAN_MANIFEST).unwrap();
+    manifest.models.as_mut().unwrap().get_mut("User").unwrap().add_property(
+        "age".to_string(),
+        ArgumentManifest::new("integer".to_string())
+            .with_validation(ValidationManifest::new().with_length_range(Some(1), None).with_numeric_range(Some(10.0), Some(1.0))),
+    );
+
+    let report = ManifestParser::lint(&manifest);
+    assert!(report.issues.iter().all(|issue| issue.line.is_none()));
+    assert_eq!(find(&report, "invalid-range").path, "models.User.properties.age.validation");
+    assert_eq!(find(&report, "inapplicable-constraint").severity, LintSeverity::Warning);
+    assert_eq!(report.count(LintSeverity::Info), 1);
+    assert_eq!(
+        find(&report, "missing-description").to_string(),
+        "info[missing-description]: 'age' has no description (models.User.properties.age)"
+    );
+}
+
+#[tokio::test]
+async fn test_lint_file_resolves_includes() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("models.json"),
+        r#"{"version": "1.0.0", "models": {
+            "User": {"type": "object", "properties": {"id": {"type": "string", "description": "User id"}}},
+            "Unused": {"type": "object", "properties": {"id": {"type": "string", "description": "Id"}}}
+        }}"#,
+    ).unwrap();
+    let api_path = dir.path().join("api.json");
+    std::fs::write(&api_path, CLEAN_MANIFEST.replacen("\"version\": \"1.0.0\",", "\"version\": \"1.0.0\", \"include\": [\"models.json\"],", 1).replace(
+        "\"models\": {\n    \"User\": {\"type\": \"object\", \"required\": [\"id\"], \"properties\": {\"id\": {\"type\": \"string\", \"description\": \"User id\"}}}\n  }",
+        "\"models\": {}",
+    )).unwrap();
+
+    let report = ManifestLinter::lint_file(&api_path.to_string_lossy()).await.unwrap();
+    assert!(report.with_code("unresolved-model-ref").is_empty(), "{:?}", report.issues);
+
+    // Definitions from the included file are reported without a location in the including file
+    let unused = find(&report, "unused-model");
+    assert_eq!(unused.path, "models.Unused");
+    assert_eq!(unused.line, None);
+
+    let error = ManifestLinter::lint_file(&dir.path().join("missing.json").to_string_lossy()).await.unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ResourceNotFound.code());
+}
+
+#[cfg(feature = "yaml-support")]
+#[test]
+fn test_yaml_locations() {
+    let report = ManifestLinter::lint_yaml("version: \"1.0.0\"\nmodels:\n  User:\n    type: object\n    properties:\n      id:\n        type: strng\n        description: Id\n");
+    let issue = find(&report, "unknown-type");
+    assert_eq!((issue.line, issue.column), (Some(7), Some(9)));
+    assert_eq!(find(&report, "unused-model").line, Some(3));
+}
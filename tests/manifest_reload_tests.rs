@@ -0,0 +1,165 @@
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+use serde_json::json;
+use std::path::Path;
+use std::time::Duration;
+
+/// Manifest Hot-Reload Tests
+/// Tests that a watched manifest file is swapped in when it changes and validates
+
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+fn manifest_json(version: &str, max_length: usize) -> String {
+    json!({
+        "version": version,
+        "requests": {
+            "greet": {
+                "description": "Greet a user",
+                "args": {
+                    "name": {"type": "string", "required": true, "validation": {"max_length": max_length}}
+                },
+                "response": {"type": "object"}
+            }
+        }
+    })
+    .to_string()
+}
+
+/// Wait until the served manifest reaches the expected version
+async fn wait_for_version(server: &JanusServer, version: &str) -> bool {
+    for _ in 0..100 {
+        if server.manifest().await.is_some_and(|manifest| manifest.version == version) {
+            return true;
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+    false
+}
+
+async fn start_watching_server(socket_path: &str, manifest_path: &Path) -> JanusServer {
+    let mut server = JanusServer::new(ServerConfig {
+        socket_path: socket_path.to_string(),
+        ..Default::default()
+    });
+    server.register_handler("greet", |cmd| Ok(json!({"greeting": cmd.args}))).await;
+    server.watch_manifest(&manifest_path.to_string_lossy(), POLL_INTERVAL).await.unwrap();
+    server.start_listening().await.expect("Failed to start server");
+    server
+}
+
+#[tokio::test]
+async fn test_changed_manifest_is_served_and_enforced() {
+    let dir = tempfile::tempdir().unwrap();
+    let manifest_path = dir.path().join("api.json");
+    std::fs::write(&manifest_path, manifest_json("1.0.0", 3)).unwrap();
+
+    let socket_path = &unique_socket_path("manifest_reload");
+    let mut server = start_watching_server(socket_path, &manifest_path).await;
+    assert!(server.is_watching_manifest());
+
+    let mut client = create_client(socket_path, server_validation_config()).await;
+    let response = client.send_request("greet", args(json!({"name": "alice"})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(!response.success, "'alice' exceeds the original max_length of 3");
+
+    std::fs::write(&manifest_path, manifest_json("1.1.0", 10)).unwrap();
+    assert!(wait_for_version(&server, "1.1.0").await, "manifest was not reloaded");
+
+    let response = client.send_request("greet", args(json!({"name": "alice"})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(response.success);
+    assert_eq!(client.fetch_manifest().await.unwrap().version, "1.1.0");
+
+    server.stop();
+    assert!(!server.is_watching_manifest());
+}
+
+#[tokio::test]
+async fn test_invalid_manifest_keeps_the_current_one() {
+    let dir = tempfile::tempdir().unwrap();
+    let manifest_path = dir.path().join("api.json");
+    std::fs::write(&manifest_path, manifest_json("1.0.0", 3)).unwrap();
+
+    let socket_path = &unique_socket_path("manifest_reload_invalid");
+    let mut server = start_watching_server(socket_path, &manifest_path).await;
+
+    // An unparsable file, then one that parses but fails validation, are both ignored
+    std::fs::write(&manifest_path, "{\"version\": ").unwrap();
+    tokio::time::sleep(POLL_INTERVAL * 5).await;
+    std::fs::write(&manifest_path, manifest_json("not-a-version", 3)).unwrap();
+    tokio::time::sleep(POLL_INTERVAL * 5).await;
+    std::fs::remove_file(&manifest_path).unwrap();
+    tokio::time::sleep(POLL_INTERVAL * 5).await;
+    assert_eq!(server.manifest().await.unwrap().version, "1.0.0");
+
+    // Watching continues after failures
+    std::fs::write(&manifest_path, manifest_json("2.0.0", 3)).unwrap();
+    assert!(wait_for_version(&server, "2.0.0").await, "manifest was not reloaded after a failed reload");
+
+    // Definitions registered with handlers stay filled in after reloads
+    server.register_handler_with_manifest(
+        "farewell",
+        RequestManifest::new("Say goodbye".to_string(), ResponseManifest::new("object".to_string())),
+        |_| Ok(json!({})),
+    ).await;
+    std::fs::write(&manifest_path, manifest_json("2.0.1", 3)).unwrap();
+    assert!(wait_for_version(&server, "2.0.1").await);
+    assert!(server.manifest().await.unwrap().has_request("farewell"));
+
+    // After unwatching, changes are no longer picked up
+    server.unwatch_manifest();
+    std::fs::write(&manifest_path, manifest_json("3.0.0", 3)).unwrap();
+    tokio::time::sleep(POLL_INTERVAL * 5).await;
+    assert_eq!(server.manifest().await.unwrap().version, "2.0.1");
+
+    server.stop();
+}
+
+/// Wait until the served greet request allows names of the expected length
+async fn wait_for_max_length(server: &JanusServer, max_length: usize) -> bool {
+    for _ in 0..100 {
+        let served = server.manifest().await.and_then(|manifest| {
+            manifest.get_request_manifest("greet")?.args.get("name")?.validation.as_ref()?.max_length
+        });
+        if served == Some(max_length) {
+            return true;
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+    false
+}
+
+#[tokio::test]
+async fn test_changed_include_is_reloaded() {
+    let dir = tempfile::tempdir().unwrap();
+    let manifest_path = dir.path().join("api.json");
+    std::fs::write(&manifest_path, json!({"version": "1.0.0", "include": ["greet.json"]}).to_string()).unwrap();
+    std::fs::write(dir.path().join("greet.json"), manifest_json("1.0.0", 3)).unwrap();
+
+    let socket_path = &unique_socket_path("manifest_reload_include");
+    let mut server = start_watching_server(socket_path, &manifest_path).await;
+
+    std::fs::write(dir.path().join("greet.json"), manifest_json("1.0.0", 10)).unwrap();
+    assert!(wait_for_max_length(&server, 10).await, "changed include was not reloaded");
+
+    // Includes added by a reload are watched too, even while they are still missing
+    std::fs::write(&manifest_path, json!({"version": "1.0.0", "include": ["names/greet.json"]}).to_string()).unwrap();
+    tokio::time::sleep(POLL_INTERVAL * 5).await;
+    std::fs::create_dir(dir.path().join("names")).unwrap();
+    std::fs::write(dir.path().join("names/greet.json"), manifest_json("1.0.0", 20)).unwrap();
+    assert!(wait_for_max_length(&server, 20).await, "added include was not reloaded");
+
+    std::fs::write(dir.path().join("names/greet.json"), manifest_json("1.0.0", 30)).unwrap();
+    assert!(wait_for_max_length(&server, 30).await, "added include was not watched");
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_watch_requires_a_valid_initial_manifest() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut server = JanusServer::new(ServerConfig::default());
+
+    let error = server.watch_manifest(&dir.path().join("missing.json").to_string_lossy(), POLL_INTERVAL).await.unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ResourceNotFound.code());
+    assert!(!server.is_watching_manifest());
+}
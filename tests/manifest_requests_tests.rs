@@ -0,0 +1,180 @@
+use rust_janus::*;
+
+/// Manifest Request Definition Tests
+/// Tests top-level request definitions: parsing, validation, merging and round-trips
+
+fn create_request_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+
+    let mut request = RequestManifest::new(
+        "Look up a user by identifier".to_string(),
+        ResponseManifest::new("object".to_string()),
+    );
+    request.add_argument(
+        "user_id".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .required()
+            .with_validation(ValidationManifest::new().with_length_range(Some(1), Some(64))),
+    );
+    request.add_error_code(
+        "user_not_found".to_string(),
+        ErrorCodeManifest::new(404, "User not found".to_string()),
+    );
+    manifest.add_request("get_user".to_string(), request);
+
+    manifest
+}
+
+#[test]
+fn test_manifest_requests() {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+    assert!(manifest.requests.is_none());
+    assert!(!manifest.has_request("get_user"));
+
+    manifest.add_request(
+        "get_user".to_string(),
+        RequestManifest::new("Get a user".to_string(), ResponseManifest::new("object".to_string())),
+    );
+
+    assert!(manifest.has_request("get_user"));
+    assert_eq!(manifest.get_request_manifest("get_user").unwrap().description, "Get a user");
+    assert!(manifest.get_request_manifest("missing").is_none());
+    assert_eq!(manifest.request_names().len(), 1);
+}
+
+#[test]
+fn test_parse_requests_from_json() {
+    let json = r#"{
+        "version": "1.0.0",
+        "requests": {
+            "get_user": {
+                "description": "Look up a user",
+                "args": {
+                    "user_id": {"type": "string", "required": true}
+                },
+                "response": {"type": "object"}
+            }
+        }
+    }"#;
+
+    let manifest = ManifestParser::load_and_validate_json(json).unwrap();
+    assert!(manifest.has_request("get_user"));
+
+    let request = manifest.get_request_manifest("get_user").unwrap();
+    assert_eq!(request.description, "Look up a user");
+    assert_eq!(request.required_arguments(), vec!["user_id"]);
+    assert_eq!(request.response.r#type, "object");
+}
+
+#[test]
+fn test_manifest_without_requests_still_parses() {
+    let manifest = ManifestParser::from_json(r#"{"version": "1.0.0"}"#).unwrap();
+    assert!(manifest.requests.is_none());
+    assert!(!manifest.has_request("anything"));
+}
+
+#[test]
+fn test_json_round_trip_preserves_requests() {
+    let manifest = create_request_manifest();
+
+    let json = ManifestParser::to_json(&manifest).unwrap();
+    let parsed = ManifestParser::from_json(&json).unwrap();
+
+    assert_eq!(parsed, manifest);
+}
+
+#[cfg(feature = "yaml-support")]
+#[test]
+fn test_yaml_round_trip_preserves_requests() {
+    let manifest = create_request_manifest();
+
+    let yaml = ManifestParser::to_yaml(&manifest).unwrap();
+    let parsed = ManifestParser::from_yaml(&yaml).unwrap();
+
+    assert_eq!(parsed, manifest);
+}
+
+#[test]
+fn test_validate_rejects_reserved_request() {
+    let mut manifest = create_request_manifest();
+    manifest.add_request(
+        "ping".to_string(),
+        RequestManifest::new("Shadow ping".to_string(), ResponseManifest::new("object".to_string())),
+    );
+
+    let error = ManifestParser::validate(&manifest).unwrap_err();
+    assert!(error.to_string().contains("reserved"));
+}
+
+#[test]
+fn test_validate_rejects_request_without_description() {
+    let mut manifest = create_request_manifest();
+    manifest.add_request(
+        "undocumented".to_string(),
+        RequestManifest::new(String::new(), ResponseManifest::new("object".to_string())),
+    );
+
+    assert!(ManifestParser::validate(&manifest).is_err());
+}
+
+#[test]
+fn test_validate_rejects_invalid_argument_type() {
+    let mut manifest = create_request_manifest();
+    let mut request = RequestManifest::new(
+        "Bad argument".to_string(),
+        ResponseManifest::new("object".to_string()),
+    );
+    request.add_argument("value".to_string(), ArgumentManifest::new("decimal".to_string()));
+    manifest.add_request("bad_argument".to_string(), request);
+
+    let error = ManifestParser::validate_with_context(&manifest, Some("api.json")).unwrap_err();
+    let message = error.to_string();
+    assert!(message.contains("decimal"));
+    assert!(message.contains("api.json"));
+}
+
+#[test]
+fn test_validate_rejects_invalid_error_code() {
+    let mut manifest = create_request_manifest();
+    let mut request = RequestManifest::new(
+        "Bad error code".to_string(),
+        ResponseManifest::new("object".to_string()),
+    );
+    request.add_error_code("broken".to_string(), ErrorCodeManifest::new(42, "Broken".to_string()));
+    manifest.add_request("bad_error".to_string(), request);
+
+    assert!(ManifestParser::validate(&manifest).is_err());
+
+    // Negative codes are accepted outside the range JSON-RPC reserves for itself
+    let request = manifest.requests.as_mut().unwrap().get_mut("bad_error").unwrap();
+    request.add_error_code("broken".to_string(), ErrorCodeManifest::new(-32001, "Broken".to_string()));
+    assert!(ManifestParser::validate(&manifest).is_err());
+
+    let request = manifest.requests.as_mut().unwrap().get_mut("bad_error").unwrap();
+    request.add_error_code("broken".to_string(), ErrorCodeManifest::new(-40001, "Broken".to_string()));
+    ManifestParser::validate(&manifest).unwrap();
+}
+
+#[test]
+fn test_merge_manifests_combines_requests() {
+    let mut base = create_request_manifest();
+
+    let mut additional = Manifest::new("1.0.0".to_string());
+    additional.add_request(
+        "list_users".to_string(),
+        RequestManifest::new("List users".to_string(), ResponseManifest::new("array".to_string())),
+    );
+
+    ManifestParser::merge_manifests(&mut base, &additional).unwrap();
+    assert!(base.has_request("get_user"));
+    assert!(base.has_request("list_users"));
+}
+
+#[test]
+fn test_merge_manifests_rejects_request_conflict() {
+    let mut base = create_request_manifest();
+    let additional = create_request_manifest();
+
+    let error = ManifestParser::merge_manifests(&mut base, &additional).unwrap_err();
+    assert!(error.to_string().contains("get_user"));
+}
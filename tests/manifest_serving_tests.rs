@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use serde_json::json;
+
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+
+/// Manifest Serving Tests
+/// Tests that the built-in manifest request returns what the server actually exposes
+
+fn create_user_manifest() -> Manifest {
+    let mut manifest = Manifest::new("2.1.0".to_string());
+
+    let mut user = ModelManifest::new();
+    user.add_property("id".to_string(), ArgumentManifest::new("string".to_string()).required());
+    user.add_property("email".to_string(), ArgumentManifest::new("string".to_string()));
+    manifest.add_model("User".to_string(), user);
+
+    let mut request = RequestManifest::new(
+        "Look up a user".to_string(),
+        ResponseManifest::new("object".to_string()),
+    );
+    request.add_argument(
+        "user_id".to_string(),
+        ArgumentManifest::new("string".to_string()).required(),
+    );
+    manifest.add_request("get_user".to_string(), request);
+
+    manifest
+}
+
+fn create_echo_request_manifest() -> RequestManifest {
+    let mut request = RequestManifest::new(
+        "Echo a message back".to_string(),
+        ResponseManifest::new("object".to_string()),
+    );
+    request.add_argument(
+        "text".to_string(),
+        ArgumentManifest::new("string".to_string()).required(),
+    );
+    request
+}
+
+async fn start_server(socket_path: &str, manifest: Option<Manifest>) -> JanusServer {
+    let mut server = JanusServer::new(ServerConfig {
+        socket_path: socket_path.to_string(),
+        ..Default::default()
+    });
+    if let Some(manifest) = manifest {
+        server.set_manifest(manifest).await;
+    }
+    server.register_handler_with_manifest("say", create_echo_request_manifest(), |cmd| {
+        Ok(json!({"said": cmd.args.and_then(|args| args.get("text").cloned())}))
+    }).await;
+    server.start_listening().await.expect("Failed to start server");
+    server
+}
+
+#[tokio::test]
+async fn test_serves_configured_manifest() {
+    let socket_path = &unique_socket_path("manifest_serving_configured");
+    let mut server = start_server(socket_path, Some(create_user_manifest())).await;
+
+    let mut client = create_client(socket_path, JanusClientConfig::default()).await;
+    let fetched = client.fetch_manifest().await.expect("Failed to fetch manifest");
+
+    assert_eq!(fetched.version, "2.1.0");
+    assert!(fetched.get_model("User").is_some());
+    assert!(fetched.has_request("get_user"));
+    // Registered definitions fill in requests the configured manifest lacks
+    assert!(fetched.has_request("say"));
+    assert_eq!(Some(&fetched), server.manifest().await.as_ref());
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_serves_manifest_built_from_registered_handlers() {
+    let socket_path = &unique_socket_path("manifest_serving_registered");
+    let mut server = start_server(socket_path, None).await;
+
+    let mut client = create_client(socket_path, JanusClientConfig::default()).await;
+    let fetched = client.fetch_manifest().await.expect("Failed to fetch manifest");
+
+    assert_eq!(fetched.request_names(), vec!["say"]);
+    assert_eq!(fetched.get_request_manifest("say"), Some(&create_echo_request_manifest()));
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_empty_server_serves_valid_manifest() {
+    let socket_path = &unique_socket_path("manifest_serving_empty");
+    let mut server = JanusServer::new(ServerConfig {
+        socket_path: socket_path.to_string(),
+        ..Default::default()
+    });
+    server.start_listening().await.expect("Failed to start server");
+
+    let mut client = create_client(socket_path, JanusClientConfig::default()).await;
+    let fetched = client.fetch_manifest().await.expect("Failed to fetch manifest");
+    assert!(ManifestParser::validate(&fetched).is_ok());
+    assert!(fetched.requests.is_none());
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_client_validates_against_served_manifest() {
+    let socket_path = &unique_socket_path("manifest_serving_client_validation");
+    let mut server = start_server(socket_path, None).await;
+
+    let mut client = create_client(socket_path, JanusClientConfig::default()).await;
+
+    let mut args = HashMap::new();
+    args.insert("text".to_string(), json!(42));
+    let error = client
+        .send_request("say", Some(args), Some(Duration::from_secs(5)))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::ManifestValidationError.code());
+
+    let mut args = HashMap::new();
+    args.insert("text".to_string(), json!("hello"));
+    let response = client
+        .send_request("say", Some(args), Some(Duration::from_secs(5)))
+        .await
+        .expect("Request failed");
+    assert!(response.success);
+    assert_eq!(response.result.unwrap()["said"], json!("hello"));
+
+    server.stop();
+}
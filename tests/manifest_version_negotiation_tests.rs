@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use serde_json::json;
+
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+
+/// Manifest Version Negotiation Tests
+/// Tests version requirements and how client and server refuse incompatible manifests
+
+async fn start_server(socket_path: &str, version: &str) -> JanusServer {
+    let mut manifest = Manifest::new(version.to_string());
+    let mut request = RequestManifest::new("Echo text".to_string(), ResponseManifest::new("object".to_string()));
+    request.add_argument("text".to_string(), ArgumentManifest::new("string".to_string()).required());
+    manifest.add_request("say".to_string(), request);
+
+    let mut server = create_manifest_server(socket_path, manifest).await;
+    server.register_handler("say", |cmd| {
+        Ok(json!({"said": cmd.args.and_then(|args| args.get("text").cloned())}))
+    }).await;
+    server.start_listening().await.expect("Failed to start server");
+    server
+}
+
+fn client_config(requirement: &str) -> JanusClientConfig {
+    JanusClientConfig {
+        manifest_version_requirement: Some(requirement.to_string()),
+        ..Default::default()
+    }
+}
+
+fn version(text: &str) -> ManifestVersion {
+    ManifestVersion::parse(text).unwrap()
+}
+
+#[test]
+fn test_version_requirement_matching() {
+    let caret = VersionRequirement::parse("^1.2").unwrap();
+    assert!(caret.matches(&version("1.2.0")));
+    assert!(caret.matches(&version("1.9.4")));
+    assert!(!caret.matches(&version("1.1.9")));
+    assert!(!caret.matches(&version("2.0.0")));
+
+    let pre_release = VersionRequirement::parse("0.3.1").unwrap();
+    assert!(pre_release.matches(&version("0.3.7")));
+    assert!(!pre_release.matches(&version("0.4.0")));
+
+    let tilde = VersionRequirement::parse("~1.2.3").unwrap();
+    assert!(tilde.matches(&version("1.2.9")));
+    assert!(!tilde.matches(&version("1.3.0")));
+
+    let range = VersionRequirement::parse(">=1.0.0, <3.0.0").unwrap();
+    assert!(range.matches(&version("2.5.0")));
+    assert!(!range.matches(&version("3.0.0")));
+
+    let exact = VersionRequirement::parse("=2.0.1").unwrap();
+    assert!(exact.matches(&version("2.0.1")));
+    assert!(!exact.matches(&version("2.0.2")));
+
+    assert!(VersionRequirement::parse("*").unwrap().matches(&version("42.0.0")));
+    assert!(VersionRequirement::parse("!1.0").is_err());
+    assert!(VersionRequirement::parse("^1.x").is_err());
+    assert!(VersionRequirement::parse(">=1.0.0,").is_err());
+}
+
+#[test]
+fn test_negotiate_reports_incompatible_version() {
+    let requirement = VersionRequirement::parse("^2").unwrap();
+    assert_eq!(requirement.negotiate("2.4.1").unwrap(), version("2.4.1"));
+
+    let error = requirement.negotiate("1.9.0").unwrap_err();
+    assert_eq!(error.code, -32014);
+    assert_eq!(error.error_code(), Some(JSONRPCErrorCode::IncompatibleManifestVersion));
+    let context = error.data.unwrap().context.unwrap();
+    assert_eq!(context["manifestVersion"], json!("1.9.0"));
+    assert_eq!(context["requirement"], json!("^2"));
+
+    assert!(requirement.negotiate("latest").is_err());
+}
+
+#[tokio::test]
+async fn test_client_exposes_negotiated_version() {
+    let socket_path = &unique_socket_path("version_negotiation_ok");
+    let mut server = start_server(socket_path, "1.4.0").await;
+
+    let mut client = create_client(socket_path, client_config(">=1.2.0, <2.0.0")).await;
+    assert_eq!(client.negotiated_manifest_version(), None);
+
+    let mut args = HashMap::new();
+    args.insert("text".to_string(), json!("hello"));
+    let response = client
+        .send_request("say", Some(args), Some(Duration::from_secs(5)))
+        .await
+        .expect("Request failed");
+    assert!(response.success);
+    assert_eq!(client.negotiated_manifest_version(), Some(version("1.4.0")));
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_incompatible_manifest_is_refused() {
+    let socket_path = &unique_socket_path("version_negotiation_refused");
+    let mut server = start_server(socket_path, "2.0.0").await;
+
+    let mut client = create_client(socket_path, client_config("^1.2")).await;
+
+    let error = client.fetch_manifest().await.unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::IncompatibleManifestVersion.code());
+    assert!(client.manifest().is_none());
+    assert_eq!(client.negotiated_manifest_version(), None);
+
+    // Requests fail up front instead of on some argument mismatch
+    let mut args = HashMap::new();
+    args.insert("text".to_string(), json!("hello"));
+    let error = client
+        .send_request("say", Some(args), Some(Duration::from_secs(5)))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, JSONRPCErrorCode::IncompatibleManifestVersion.code());
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_client_without_requirement_accepts_any_version() {
+    let socket_path = &unique_socket_path("version_negotiation_any");
+    let mut server = start_server(socket_path, "7.1.3").await;
+
+    let mut client = create_client(socket_path, JanusClientConfig::default()).await;
+    client.fetch_manifest().await.expect("Failed to fetch manifest");
+    assert_eq!(client.negotiated_manifest_version(), Some(version("7.1.3")));
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_invalid_requirement_is_rejected_by_config() {
+    let config = JanusClientConfig {
+        manifest_version_requirement: Some("about 1.0".to_string()),
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+    assert!(JanusClient::new(unique_socket_path("version_negotiation_invalid"), config).await.is_err());
+}
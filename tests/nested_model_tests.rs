@@ -0,0 +1,194 @@
+// Nested Model Tests
+// Tests array item, map value and recursive model references across validation, parsing and JSON Schema
+
+use rust_janus::*;
+use rust_janus::manifest::ResponseValidator;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+
+manifest_model! {
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct Category {
+        pub label: String,
+        pub children: Vec<Category>,
+        pub parent: Option<Box<Category>>,
+    }
+}
+
+fn create_tree_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+
+    let mut node = ModelManifest::new().with_required(vec!["label".to_string()]);
+    node.add_property(
+        "label".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .with_validation(ValidationManifest::new().with_length_range(Some(1), None)),
+    );
+    node.add_property(
+        "children".to_string(),
+        ArgumentManifest::new("array".to_string())
+            .with_items(ArgumentManifest::new("object".to_string()).with_model_ref("Node".to_string())),
+    );
+    node.add_property(
+        "attributes".to_string(),
+        ArgumentManifest::new("object".to_string())
+            .with_additional_properties(ArgumentManifest::new("integer".to_string())),
+    );
+    manifest.add_model("Node".to_string(), node);
+
+    let response = ResponseManifest::new("array".to_string())
+        .with_items(ArgumentManifest::new("object".to_string()).with_model_ref("Node".to_string()));
+    let mut request = RequestManifest::new("Store a tree".to_string(), response);
+    request.add_argument(
+        "root".to_string(),
+        ArgumentManifest::new("object".to_string()).required().with_model_ref("Node".to_string()),
+    );
+    request.add_argument(
+        "index".to_string(),
+        ArgumentManifest::new("object".to_string())
+            .with_additional_properties(ArgumentManifest::new("object".to_string()).with_model_ref("Node".to_string())),
+    );
+    manifest.add_request("store_tree".to_string(), request);
+
+    manifest
+}
+
+fn validate_args(manifest: &Manifest, args: serde_json::Value) -> Vec<String> {
+    let args: HashMap<String, serde_json::Value> = serde_json::from_value(args).unwrap();
+    let request = JanusRequest::new("store_tree".to_string(), Some(args), None);
+    let result = ArgumentValidator::new(manifest).validate_request(&request);
+    result.errors.into_iter().map(|e| e.field).collect()
+}
+
+#[test]
+fn test_recursive_model_validates_every_level() {
+    let manifest = create_tree_manifest();
+    assert!(ManifestParser::validate(&manifest).is_ok());
+
+    let valid = json!({
+        "root": {
+            "label": "a",
+            "children": [
+                {"label": "b", "children": []},
+                {"label": "c", "children": [{"label": "d"}], "attributes": {"depth": 2}}
+            ]
+        }
+    });
+    assert!(validate_args(&manifest, valid).is_empty());
+
+    let invalid = json!({
+        "root": {
+            "label": "a",
+            "children": [
+                {"label": "b"},
+                {"label": "c", "children": [{"label": ""}, {"children": "none"}], "attributes": {"depth": "deep"}}
+            ]
+        }
+    });
+    let mut fields = validate_args(&manifest, invalid);
+    fields.sort();
+    assert_eq!(fields, vec![
+        "root.children[1].attributes.depth",
+        "root.children[1].children[0].label",
+        "root.children[1].children[1].children",
+        "root.children[1].children[1].label",
+    ]);
+}
+
+#[test]
+fn test_map_of_models() {
+    let manifest = create_tree_manifest();
+
+    let fields = validate_args(&manifest, json!({
+        "root": {"label": "a"},
+        "index": {"first": {"label": "x"}, "second": {"children": []}, "third": 7}
+    }));
+    assert_eq!(fields.len(), 2);
+    assert!(fields.contains(&"index.second.label".to_string()));
+    assert!(fields.contains(&"index.third".to_string()));
+}
+
+#[test]
+fn test_response_array_of_models() {
+    let manifest = create_tree_manifest();
+    let response_manifest = manifest.get_request_manifest("store_tree").unwrap().response.clone();
+    let validator = ResponseValidator::new(&manifest);
+
+    let result = validator.validate_response(&json!([{"label": "a", "children": [{"label": "b"}]}]), &response_manifest);
+    assert!(result.valid);
+
+    let result = validator.validate_response(&json!([{"label": "a", "children": [{"label": 5}]}]), &response_manifest);
+    assert!(!result.valid);
+    assert_eq!(result.errors[0].field, "[0].children[0].label");
+}
+
+#[test]
+fn test_parser_rejects_unresolved_nested_reference() {
+    let mut manifest = create_tree_manifest();
+    manifest.models.as_mut().unwrap().get_mut("Node").unwrap().add_property(
+        "links".to_string(),
+        ArgumentManifest::new("array".to_string())
+            .with_items(ArgumentManifest::new("object".to_string()).with_model_ref("Link".to_string())),
+    );
+
+    let error = ManifestParser::validate(&manifest).unwrap_err();
+    assert!(error.to_string().contains("Unresolved model reference 'Link' at models.Node.properties.links.items"));
+}
+
+#[test]
+fn test_parser_rejects_required_cycles() {
+    let mut manifest = create_tree_manifest();
+    let mut parent = ArgumentManifest::new("object".to_string()).with_model_ref("Node".to_string());
+
+    // An optional self reference is a valid recursive model
+    manifest.models.as_mut().unwrap().get_mut("Node").unwrap().add_property("parent".to_string(), parent.clone());
+    assert!(ManifestParser::validate(&manifest).is_ok());
+
+    // A required one can never be satisfied
+    parent = parent.required();
+    manifest.models.as_mut().unwrap().get_mut("Node").unwrap().add_property("parent".to_string(), parent);
+    let error = ManifestParser::validate(&manifest).unwrap_err();
+    assert!(error.to_string().contains("Model 'Node' requires itself through Node.parent"));
+
+    // Cycles through several models are reported with every step
+    let mut manifest = Manifest::new("1.0.0".to_string());
+    let mut author = ModelManifest::new().with_required(vec!["latest_book".to_string()]);
+    author.add_property("latest_book".to_string(), ArgumentManifest::new("object".to_string()).with_model_ref("Book".to_string()));
+    let mut book = ModelManifest::new();
+    book.add_property("author".to_string(), ArgumentManifest::new("object".to_string()).required().with_model_ref("Author".to_string()));
+    manifest.add_model("Author".to_string(), author);
+    manifest.add_model("Book".to_string(), book);
+
+    let error = ManifestParser::validate(&manifest).unwrap_err();
+    assert!(error.to_string().contains("Author.latest_book -> Book.author"));
+}
+
+#[test]
+fn test_nested_definitions_round_trip_through_json_schema() {
+    let manifest = create_tree_manifest();
+    let bundle = JsonSchemaExporter::export_manifest(&manifest);
+
+    let node = &bundle["$defs"]["Node"];
+    assert_eq!(node["properties"]["children"]["items"], json!({"$ref": "#/$defs/Node"}));
+    assert_eq!(node["properties"]["attributes"]["additionalProperties"], json!({"type": "integer"}));
+    assert_eq!(bundle["requests"]["store_tree"]["response"]["items"], json!({"$ref": "#/$defs/Node"}));
+
+    let models = JsonSchemaImporter::import_models(&bundle, None).unwrap();
+    assert_eq!(models["Node"], *manifest.get_model("Node").unwrap());
+}
+
+#[test]
+fn test_typed_recursive_model() {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+    manifest.add_typed_request::<Category, Vec<Category>>("add_category", "Add a category tree");
+
+    let category = manifest.get_model("Category").unwrap();
+    let children = &category.properties["children"];
+    assert_eq!(children.items.as_ref().unwrap().model_ref.as_deref(), Some("Category"));
+    assert!(!category.properties["parent"].is_required());
+
+    let response = &manifest.get_request_manifest("add_category").unwrap().response;
+    assert_eq!(response.items.as_ref().unwrap().model_ref.as_deref(), Some("Category"));
+    assert!(ManifestParser::validate(&manifest).is_ok());
+}
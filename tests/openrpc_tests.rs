@@ -0,0 +1,103 @@
+use rust_janus::*;
+use serde_json::json;
+
+/// OpenRPC Generation Tests
+/// Tests conversion of manifest requests into OpenRPC methods
+
+fn create_openrpc_manifest() -> Manifest {
+    let mut manifest = Manifest::new("3.0.1".to_string());
+
+    let mut user = ModelManifest::new();
+    user.add_property("id".to_string(), ArgumentManifest::new("string".to_string()).required());
+    manifest.add_model("User".to_string(), user);
+
+    let mut response = ResponseManifest::new("object".to_string());
+    response.model_ref = Some("User".to_string());
+    let mut get_user = RequestManifest::new("Look up a user".to_string(), response);
+    get_user.add_argument(
+        "user_id".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .required()
+            .with_description("User identifier".to_string()),
+    );
+    get_user.add_argument(
+        "include_email".to_string(),
+        ArgumentManifest::new("boolean".to_string()).with_default(json!(false)),
+    );
+    get_user.add_error_code(
+        "user_not_found".to_string(),
+        ErrorCodeManifest::new(404, "User not found".to_string())
+            .with_description("No user has the given identifier".to_string()),
+    );
+    get_user.add_error_code(
+        "forbidden".to_string(),
+        ErrorCodeManifest::new(403, "Access denied".to_string()),
+    );
+    manifest.add_request("get_user".to_string(), get_user);
+
+    manifest.add_request(
+        "list_users".to_string(),
+        RequestManifest::new("List users".to_string(), ResponseManifest::new("array".to_string())),
+    );
+
+    manifest
+}
+
+#[test]
+fn test_generate_document_structure() {
+    let document = OpenRpcGenerator::generate(&create_openrpc_manifest(), "User Service");
+
+    assert_eq!(document["openrpc"], json!("1.2.6"));
+    assert_eq!(document["info"], json!({"title": "User Service", "version": "3.0.1"}));
+
+    let methods = document["methods"].as_array().unwrap();
+    let names: Vec<&str> = methods.iter().map(|m| m["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["get_user", "list_users"]);
+
+    assert_eq!(document["components"]["schemas"]["User"]["required"], json!(["id"]));
+}
+
+#[test]
+fn test_method_params_result_and_errors() {
+    let document = OpenRpcGenerator::generate(&create_openrpc_manifest(), "User Service");
+    let method = &document["methods"][0];
+
+    assert_eq!(method["description"], json!("Look up a user"));
+    assert_eq!(method["paramStructure"], json!("by-name"));
+    assert_eq!(method["params"], json!([
+        {
+            "name": "include_email",
+            "required": false,
+            "schema": {"type": "boolean", "default": false}
+        },
+        {
+            "name": "user_id",
+            "description": "User identifier",
+            "required": true,
+            "schema": {"type": "string", "description": "User identifier"}
+        }
+    ]));
+
+    assert_eq!(method["result"]["name"], json!("get_userResult"));
+    assert_eq!(method["result"]["schema"]["$ref"], json!("#/components/schemas/User"));
+
+    assert_eq!(method["errors"], json!([
+        {"code": 403, "message": "Access denied", "data": {"name": "forbidden"}},
+        {
+            "code": 404,
+            "message": "User not found",
+            "data": {"name": "user_not_found", "description": "No user has the given identifier"}
+        }
+    ]));
+
+    let list_users = &document["methods"][1];
+    assert_eq!(list_users["params"], json!([]));
+    assert!(list_users.get("errors").is_none());
+}
+
+#[test]
+fn test_to_json_round_trips() {
+    let json_text = OpenRpcGenerator::to_json(&create_openrpc_manifest(), "User Service").unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json_text).unwrap();
+    assert_eq!(parsed, OpenRpcGenerator::generate(&create_openrpc_manifest(), "User Service"));
+}
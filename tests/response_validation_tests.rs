@@ -12,7 +12,7 @@ use test_utils::*;
 #[tokio::test]
 async fn test_response_against_manifest_validation() {
     let manifest = load_test_manifest();
-    let validator = ResponseValidator::new(manifest.clone());
+    let validator = ResponseValidator::new(&manifest);
     
     // Create a basic response manifest
     let response_manifest = ResponseManifest::new("string".to_string());
@@ -28,7 +28,7 @@ async fn test_response_against_manifest_validation() {
 #[tokio::test]
 async fn test_request_response_validation() {
     let manifest = load_test_manifest();
-    let validator = ResponseValidator::new(manifest.clone());
+    let validator = ResponseValidator::new(&manifest);
     
     // Test request response validation using test Manifest
     let response = serde_json::json!({
@@ -45,7 +45,7 @@ async fn test_request_response_validation() {
 #[tokio::test]
 async fn test_type_validation_engine() {
     let manifest = load_test_manifest();
-    let validator = ResponseValidator::new(manifest.clone());
+    let validator = ResponseValidator::new(&manifest);
     
     // Test different type validations
     let string_manifest = ResponseManifest::new("string".to_string());
@@ -69,7 +69,7 @@ async fn test_type_validation_engine() {
 #[tokio::test]
 async fn test_string_constraint_validation() {
     let manifest = load_test_manifest();
-    let validator = ResponseValidator::new(manifest.clone());
+    let validator = ResponseValidator::new(&manifest);
     
     // Create response manifest with string validation
     let mut response_manifest = ResponseManifest::new("string".to_string());
@@ -85,7 +85,7 @@ async fn test_string_constraint_validation() {
 #[tokio::test]  
 async fn test_numeric_range_validation() {
     let manifest = load_test_manifest();
-    let validator = ResponseValidator::new(manifest.clone());
+    let validator = ResponseValidator::new(&manifest);
     
     // Create number response manifest
     let number_manifest = ResponseManifest::new("number".to_string());
@@ -101,7 +101,7 @@ async fn test_numeric_range_validation() {
 #[tokio::test]
 async fn test_object_property_validation() {
     let manifest = load_test_manifest();
-    let validator = ResponseValidator::new(manifest.clone());
+    let validator = ResponseValidator::new(&manifest);
     
     // Create object response manifest with properties
     let mut properties = HashMap::new();
@@ -125,7 +125,7 @@ async fn test_object_property_validation() {
 #[tokio::test]
 async fn test_enum_value_validation() {
     let manifest = load_test_manifest();
-    let validator = ResponseValidator::new(manifest.clone());
+    let validator = ResponseValidator::new(&manifest);
     
     // Create string manifest (enum validation would be in ValidationManifest if implemented)
     let enum_manifest = ResponseManifest::new("string".to_string());
@@ -141,7 +141,7 @@ async fn test_enum_value_validation() {
 #[tokio::test]
 async fn test_model_reference_resolution() {
     let manifest = load_test_manifest();
-    let validator = ResponseValidator::new(manifest.clone());
+    let validator = ResponseValidator::new(&manifest);
     
     // Test model reference (depends on test Manifest having models)
     let mut model_manifest = ResponseManifest::new("object".to_string());
@@ -161,7 +161,7 @@ async fn test_model_reference_resolution() {
 #[tokio::test]
 async fn test_validation_timing_metrics() {
     let manifest = load_test_manifest();
-    let validator = ResponseValidator::new(manifest.clone());
+    let validator = ResponseValidator::new(&manifest);
     
     let simple_manifest = ResponseManifest::new("string".to_string());
     let response = serde_json::json!("test response");
@@ -178,7 +178,7 @@ async fn test_validation_timing_metrics() {
 #[tokio::test]
 async fn test_field_count_tracking() {
     let manifest = load_test_manifest();
-    let validator = ResponseValidator::new(manifest.clone());
+    let validator = ResponseValidator::new(&manifest);
     
     // Simple field count
     let simple_manifest = ResponseManifest::new("string".to_string());
@@ -0,0 +1,139 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use serde_json::json;
+
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+
+/// Server-Side Validation Tests
+/// Tests that an attached Manifest rejects invalid arguments before handlers run
+
+fn create_server_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+
+    let mut request = RequestManifest::new(
+        "Greet a user".to_string(),
+        ResponseManifest::new("object".to_string()),
+    );
+    request.add_argument(
+        "name".to_string(),
+        ArgumentManifest::new("string".to_string())
+            .required()
+            .with_validation(ValidationManifest::new().with_length_range(Some(1), Some(16))),
+    );
+    request.add_argument(
+        "times".to_string(),
+        ArgumentManifest::new("integer".to_string())
+            .with_validation(ValidationManifest::new().with_numeric_range(Some(1.0), Some(3.0))),
+    );
+    manifest.add_request("greet".to_string(), request);
+
+    manifest
+}
+
+async fn start_greet_server(socket_path: &str, calls: Arc<AtomicUsize>) -> JanusServer {
+    let mut server = create_manifest_server(socket_path, create_server_manifest()).await;
+    server.register_handler("greet", move |cmd| {
+        calls.fetch_add(1, Ordering::SeqCst);
+        let name = cmd.args.as_ref().and_then(|args| args.get("name")).cloned();
+        Ok(json!({"greeting": name}))
+    }).await;
+    server.start_listening().await.expect("Failed to start server");
+    server
+}
+
+#[tokio::test]
+async fn test_valid_request_reaches_handler() {
+    let socket_path = &unique_socket_path("server_validation_valid");
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut server = start_greet_server(socket_path, Arc::clone(&calls)).await;
+
+    let mut client = create_client(socket_path, server_validation_config()).await;
+    let response = client
+        .send_request("greet", args(json!({"name": "alice", "times": 2})), Some(Duration::from_secs(5)))
+        .await
+        .expect("Request failed");
+
+    assert!(response.success);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_invalid_request_rejected_before_handler() {
+    let socket_path = &unique_socket_path("server_validation_invalid");
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut server = start_greet_server(socket_path, Arc::clone(&calls)).await;
+
+    let mut client = create_client(socket_path, server_validation_config()).await;
+    let response = client
+        .send_request("greet", args(json!({"name": "", "times": 9})), Some(Duration::from_secs(5)))
+        .await
+        .expect("Request failed");
+
+    assert!(!response.success);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    let error = response.error.expect("Missing error");
+    assert_eq!(error.code, JSONRPCErrorCode::InvalidParams.code());
+
+    let data = error.data.expect("Missing error data");
+    assert!(data.field.is_some());
+    assert!(data.constraints.is_some());
+    assert_eq!(data.context.unwrap()["errorCount"], json!(2));
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_missing_required_argument_rejected() {
+    let socket_path = &unique_socket_path("server_validation_missing");
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut server = start_greet_server(socket_path, Arc::clone(&calls)).await;
+
+    let mut client = create_client(socket_path, server_validation_config()).await;
+    let response = client
+        .send_request("greet", None, Some(Duration::from_secs(5)))
+        .await
+        .expect("Request failed");
+
+    assert!(!response.success);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert_eq!(response.error.unwrap().data.unwrap().field.as_deref(), Some("name"));
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_builtin_requests_skip_manifest_validation() {
+    let socket_path = &unique_socket_path("server_validation_builtin");
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut server = start_greet_server(socket_path, calls).await;
+
+    let mut client = create_client(socket_path, server_validation_config()).await;
+    let response = client
+        .send_request("echo", args(json!({"message": "hello"})), Some(Duration::from_secs(5)))
+        .await
+        .expect("Request failed");
+
+    assert!(response.success);
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_load_manifest_rejects_invalid_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let manifest_path = dir.path().join("invalid_manifest.json");
+    std::fs::write(&manifest_path, r#"{"version": "not-a-version"}"#).unwrap();
+
+    let mut server = JanusServer::new(ServerConfig {
+        socket_path: unique_socket_path("server_validation_load"),
+        ..Default::default()
+    });
+    assert!(server.load_manifest(&manifest_path.to_string_lossy()).await.is_err());
+    assert!(server.manifest().await.is_none());
+}
@@ -0,0 +1,110 @@
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+use rust_janus::manifest::ResponseValidator;
+use serde_json::json;
+use std::time::Duration;
+
+/// Strict Response Tests
+/// Tests that handler results are checked against the response definitions of their requests
+
+fn create_user_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+
+    let mut user = ModelManifest::new();
+    user.add_property("id".to_string(), ArgumentManifest::new("integer".to_string()).required());
+    user.add_property(
+        "email".to_string(),
+        ArgumentManifest::new("string".to_string()).with_validation(ValidationManifest::new().with_format(StringFormat::Email)),
+    );
+    manifest.add_model("User".to_string(), user);
+
+    let mut response = ResponseManifest::new("object".to_string());
+    response.model_ref = Some("User".to_string());
+    let mut request = RequestManifest::new("Look up a user".to_string(), response);
+    request.add_argument("id".to_string(), ArgumentManifest::new("integer".to_string()).required());
+    manifest.add_request("get_user".to_string(), request);
+
+    manifest
+}
+
+/// Serve users whose odd ids come back with a malformed email
+async fn start_user_server(socket_path: &str, strict: bool) -> JanusServer {
+    let mut server = create_manifest_server(socket_path, create_user_manifest()).await;
+    server.set_strict_responses(strict).await;
+    server.register_handler("get_user", |cmd| {
+        let id = cmd.args.as_ref().and_then(|args| args.get("id")).and_then(|id| id.as_i64()).unwrap_or_default();
+        match id {
+            0 => Err(JSONRPCError::new(JSONRPCErrorCode::ResourceNotFound, Some("No user 0".to_string()))),
+            id if id % 2 == 0 => Ok(json!({"id": id, "email": "ada@example.com"})),
+            id => Ok(json!({"id": id, "email": "ada"})),
+        }
+    }).await;
+    server.start_listening().await.expect("Failed to start server");
+    server
+}
+
+#[test]
+fn test_request_responses_are_validated_against_their_definition() {
+    let manifest = create_user_manifest();
+    let validator = ResponseValidator::new(&manifest);
+
+    let result = validator.validate_request_response(&json!({"id": 7, "email": "ada@example.com"}), "get_user");
+    assert!(result.valid);
+    assert_eq!(result.fields_validated, 2);
+
+    let result = validator.validate_request_response(&json!({"email": "ada"}), "get_user");
+    let mut fields: Vec<&str> = result.errors.iter().map(|e| e.field.as_str()).collect();
+    fields.sort();
+    assert_eq!(fields, vec!["email", "id"]);
+
+    let result = validator.validate_request_response(&json!({}), "delete_user");
+    assert!(!result.valid);
+    assert_eq!(result.errors[0].message, "No response manifest found for request 'delete_user'");
+}
+
+#[tokio::test]
+async fn test_strict_server_rejects_results_violating_the_manifest() {
+    let socket_path = &unique_socket_path("strict_responses");
+    let mut server = start_user_server(socket_path, true).await;
+    let mut client = create_client(socket_path, server_validation_config()).await;
+
+    let response = client.send_request("get_user", args(json!({"id": 2})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(response.success);
+    assert_eq!(response.result, Some(json!({"id": 2, "email": "ada@example.com"})));
+
+    let response = client.send_request("get_user", args(json!({"id": 3})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(!response.success);
+    assert!(response.result.is_none());
+    let error = response.error.unwrap();
+    assert_eq!(error.code, JSONRPCErrorCode::InternalError.code());
+    let data = error.data.unwrap();
+    assert_eq!(data.field.as_deref(), Some("email"));
+    assert!(data.details.unwrap().starts_with("Result of request 'get_user' violates its manifest definition"));
+    assert_eq!(data.context.unwrap()["errorCount"], json!(1));
+
+    // Handler errors and built-in requests pass through unchanged
+    let response = client.send_request("get_user", args(json!({"id": 0})), Some(Duration::from_secs(5))).await.unwrap();
+    assert_eq!(response.error.unwrap().code, JSONRPCErrorCode::ResourceNotFound.code());
+    assert!(client.send_request("ping", None, Some(Duration::from_secs(5))).await.unwrap().success);
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_results_are_not_checked_by_default() {
+    let socket_path = &unique_socket_path("lenient_responses");
+    let mut server = start_user_server(socket_path, false).await;
+    let mut client = create_client(socket_path, server_validation_config()).await;
+
+    let response = client.send_request("get_user", args(json!({"id": 3})), Some(Duration::from_secs(5))).await.unwrap();
+    assert!(response.success);
+    assert_eq!(response.result, Some(json!({"id": 3, "email": "ada"})));
+
+    // Enabling strict mode while listening applies to the next response
+    server.set_strict_responses(true).await;
+    let response = client.send_request("get_user", args(json!({"id": 3})), Some(Duration::from_secs(5))).await.unwrap();
+    assert_eq!(response.error.unwrap().code, JSONRPCErrorCode::InternalError.code());
+
+    server.stop();
+}
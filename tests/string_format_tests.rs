@@ -0,0 +1,156 @@
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+use rust_janus::manifest::ResponseValidator;
+use serde_json::json;
+
+/// String Format Tests
+/// Tests the built-in `format` validators in argument, response and manifest validation
+
+fn create_format_manifest() -> Manifest {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+
+    let mut response = ResponseManifest::new("object".to_string());
+    response.model_ref = Some("Device".to_string());
+    let mut request = RequestManifest::new("Register a device".to_string(), response);
+    for (name, format) in [
+        ("contact", StringFormat::Email),
+        ("id", StringFormat::Uuid),
+        ("seen_at", StringFormat::DateTime),
+        ("callback", StringFormat::Uri),
+        ("address", StringFormat::Ipv4),
+        ("address6", StringFormat::Ipv6),
+        ("log_path", StringFormat::AbsolutePath),
+    ] {
+        request.add_argument(
+            name.to_string(),
+            ArgumentManifest::new("string".to_string()).with_validation(ValidationManifest::new().with_format(format)),
+        );
+    }
+    manifest.add_request("register".to_string(), request);
+
+    let mut device = ModelManifest::new();
+    device.add_property(
+        "id".to_string(),
+        ArgumentManifest::new("string".to_string()).with_validation(ValidationManifest::new().with_format(StringFormat::Uuid)),
+    );
+    manifest.add_model("Device".to_string(), device);
+
+    manifest
+}
+
+#[test]
+fn test_builtin_formats() {
+    let cases = [
+        (StringFormat::Email, vec!["ada@example.com", "first.last+tag@mail.example.org", "root@localhost"], vec!["ada", "ada@", "@example.com", "a b@example.com", "ada@-example.com"]),
+        (StringFormat::Uuid, vec!["0f8fad5b-d9cb-469f-a165-70867728950e", "0F8FAD5B-D9CB-469F-A165-70867728950E"], vec!["0f8fad5bd9cb469fa16570867728950e", "0f8fad5b-d9cb-469f-a165-70867728950", "zf8fad5b-d9cb-469f-a165-70867728950e"]),
+        (StringFormat::DateTime, vec!["2024-02-29T12:30:00Z", "2024-02-29T12:30:00.125+02:00"], vec!["2024-02-29", "2023-02-29T12:30:00Z", "2024-02-29 12:30:00"]),
+        (StringFormat::Uri, vec!["https://example.com/a?b=c#d", "urn:isbn:0451450523", "file:///tmp/x%20y"], vec!["/relative/path", "https://exa mple.com", "http://example.com/%zz", "1http://example.com"]),
+        (StringFormat::Ipv4, vec!["127.0.0.1", "255.255.255.255"], vec!["256.0.0.1", "1.2.3", "01.2.3.4", "::1"]),
+        (StringFormat::Ipv6, vec!["::1", "2001:db8::8a2e:370:7334", "::ffff:192.0.2.1"], vec!["2001:db8::g", "1:2:3:4:5:6:7:8:9", "127.0.0.1"]),
+        (StringFormat::AbsolutePath, vec!["/tmp/janus.sock", "/"], vec!["tmp/janus.sock", "./janus.sock", "", "/tmp/\0"]),
+    ];
+
+    for (format, valid, invalid) in cases {
+        for value in valid {
+            assert!(format.matches(value), "'{}' should be a valid {}", value, format);
+        }
+        for value in invalid {
+            assert!(!format.matches(value), "'{}' should not be a valid {}", value, format);
+        }
+        assert_eq!(StringFormat::from_name(format.name()), Some(format));
+    }
+    assert_eq!(StringFormat::from_name("hostname"), None);
+}
+
+#[test]
+fn test_arguments_are_checked_against_formats() {
+    let manifest = create_format_manifest();
+    let validator = ArgumentValidator::new(&manifest);
+    let request_manifest = manifest.get_request_manifest("register").unwrap();
+
+    let valid = args(json!({
+        "contact": "ops@example.com",
+        "id": "0f8fad5b-d9cb-469f-a165-70867728950e",
+        "seen_at": "2024-05-01T08:00:00Z",
+        "callback": "https://example.com/hook",
+        "address": "10.0.0.1",
+        "address6": "fe80::1",
+        "log_path": "/var/log/device.log"
+    }));
+    assert!(validator.validate_args(valid.as_ref(), request_manifest).valid);
+
+    let invalid = args(json!({"contact": "ops", "seen_at": "yesterday", "log_path": "device.log"}));
+    let result = validator.validate_args(invalid.as_ref(), request_manifest);
+    // Violations are reported in argument name order
+    let failures: Vec<(&str, &str)> = result.errors.iter().map(|e| (e.field.as_str(), e.expected.as_str())).collect();
+    assert_eq!(failures, vec![("contact", "format email"), ("log_path", "format absolute-path"), ("seen_at", "format date-time")]);
+    let contact = result.errors.iter().find(|e| e.field == "contact").unwrap();
+    assert_eq!(contact.message, "String is not an email address");
+}
+
+#[test]
+fn test_responses_are_checked_against_formats() {
+    let manifest = create_format_manifest();
+    let response_manifest = manifest.get_request_manifest("register").unwrap().response.clone();
+    let validator = ResponseValidator::new(&manifest);
+
+    assert!(validator.validate_response(&json!({"id": "0f8fad5b-d9cb-469f-a165-70867728950e"}), &response_manifest).valid);
+
+    let result = validator.validate_response(&json!({"id": "device-1"}), &response_manifest);
+    assert!(!result.valid);
+    assert_eq!(result.errors[0].field, "id");
+    assert_eq!(result.errors[0].message, "String is not a UUID");
+}
+
+#[test]
+fn test_unknown_formats_are_rejected_when_loading() {
+    let manifest_json = r#"{
+        "version": "1.0.0",
+        "requests": {
+            "lookup": {
+                "description": "Look up a host",
+                "args": {"host": {"type": "string", "validation": {"format": "hostname"}}},
+                "response": {"type": "object"}
+            }
+        }
+    }"#;
+
+    let error = ManifestParser::load_and_validate_json(manifest_json).unwrap_err();
+    assert!(error.to_string().contains("Unknown string format 'hostname'"), "{}", error);
+
+    let report = ManifestLinter::lint_json(manifest_json);
+    let issue = report.with_code("unknown-format")[0];
+    assert_eq!(issue.path, "requests.lookup.args.host.validation.format");
+    assert_eq!(issue.line, Some(6));
+
+    // Formats survive serialization and are omitted when unset
+    let manifest = create_format_manifest();
+    let serialized = ManifestParser::to_json(&manifest).unwrap();
+    assert_eq!(ManifestParser::load_and_validate_json(&serialized).unwrap(), manifest);
+    assert!(!serde_json::to_string(&ValidationManifest::new()).unwrap().contains("format"));
+}
+
+#[test]
+fn test_formats_map_to_json_schema() {
+    let manifest = create_format_manifest();
+    let schema = JsonSchemaExporter::export_request_args(&manifest, "register").unwrap();
+    assert_eq!(schema["properties"]["seen_at"]["format"], json!("date-time"));
+
+    let document = json!({
+        "$defs": {
+            "Host": {
+                "type": "object",
+                "properties": {
+                    "address": {"type": "string", "format": "ipv6"},
+                    "name": {"type": "string", "format": "hostname"}
+                }
+            }
+        }
+    });
+    let models = JsonSchemaImporter::import_models(&document, None).unwrap();
+    let host = &models["Host"];
+    assert_eq!(host.properties["address"].validation.as_ref().unwrap().format.as_deref(), Some("ipv6"));
+    // Formats without a built-in validator stay annotations
+    assert!(host.properties["name"].validation.is_none());
+}
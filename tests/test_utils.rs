@@ -1,5 +1,5 @@
 use rust_janus::*;
-use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::collections::HashMap;
 use tempfile::TempDir;
 use std::path::PathBuf;
@@ -78,6 +78,10 @@ pub async fn fetch_test_manifest(server_socket_path: &str) -> rust_janus::manife
         max_request_name_length: 128,
         max_args_data_size: 500_000,
         enable_validation: true,
+        manifest_version_requirement: None,
+        coercion_mode: CoercionMode::Strict,
+        manifest_cache_ttl: std::time::Duration::from_secs(60),
+        response_validation: ResponseValidationMode::Off,
     };
     
     // Create core client for manifest fetching
@@ -147,6 +151,10 @@ pub fn create_test_config() -> JanusClientConfig {
         max_request_name_length: 128,
         max_args_data_size: 500_000,  // 500KB
         enable_validation: true,
+        manifest_version_requirement: None,
+        coercion_mode: CoercionMode::Strict,
+        manifest_cache_ttl: std::time::Duration::from_secs(60),
+        response_validation: ResponseValidationMode::Off,
     }
 }
 
@@ -295,4 +303,47 @@ pub fn create_large_test_data_map(size_multiplier: usize) -> HashMap<String, ser
     }
     
     args
-}
\ No newline at end of file
+}
+
+/// Create a socket path unique to this test process and call,
+/// so test targets running in parallel never bind the same socket
+pub fn unique_socket_path(name: &str) -> String {
+    static NEXT_SOCKET: AtomicUsize = AtomicUsize::new(0);
+    format!(
+        "/tmp/rust_janus_{}_{}_{}.sock",
+        name,
+        std::process::id(),
+        NEXT_SOCKET.fetch_add(1, Ordering::SeqCst)
+    )
+}
+
+/// Build request arguments from a JSON object
+pub fn args(value: serde_json::Value) -> Option<HashMap<String, serde_json::Value>> {
+    Some(serde_json::from_value(value).expect("Request arguments must be a JSON object"))
+}
+
+/// Create a server for the socket path serving the given Manifest.
+/// Callers register handlers before starting it with `start_listening`.
+pub async fn create_manifest_server(socket_path: &str, manifest: Manifest) -> JanusServer {
+    let mut server = JanusServer::new(ServerConfig {
+        socket_path: socket_path.to_string(),
+        ..Default::default()
+    });
+    server.set_manifest(manifest).await;
+    server
+}
+
+/// Create a client for a test server
+pub async fn create_client(socket_path: &str, config: JanusClientConfig) -> JanusClient {
+    JanusClient::new(socket_path.to_string(), config)
+        .await
+        .expect("Failed to create client")
+}
+
+/// Client configuration without client-side validation, leaving the checks to the server
+pub fn server_validation_config() -> JanusClientConfig {
+    JanusClientConfig {
+        enable_validation: false,
+        ..Default::default()
+    }
+}
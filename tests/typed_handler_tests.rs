@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use rust_janus::*;
+mod test_utils;
+use test_utils::*;
+
+// Typed Handler Tests
+// Tests manifest derivation from Rust types and typed handler registration
+
+manifest_model! {
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Address {
+        pub street: String,
+        pub zip: Option<String>,
+    }
+}
+
+manifest_model! {
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct User {
+        pub id: String,
+        pub age: u32,
+        pub tags: Vec<String>,
+        pub address: Option<Address>,
+    }
+}
+
+manifest_model! {
+    #[derive(Debug, Deserialize)]
+    pub struct CreateUserArgs {
+        pub name: String,
+        pub age: u32,
+        pub address: Option<Address>,
+    }
+}
+
+manifest_model! {
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct TreeNode {
+        pub label: String,
+        pub children: Vec<TreeNode>,
+    }
+}
+
+manifest_model! {
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Account {
+        pub account_id: String,
+        #[serde(rename = "displayName", default)]
+        pub name: Option<String>,
+        pub r#type: String,
+    }
+}
+
+manifest_model! {
+    #[derive(Debug, Default, Serialize, Deserialize)]
+    pub struct Paging {
+        pub limit: u32,
+        pub cursor: Option<String>,
+    }
+}
+
+manifest_model! {
+    #[derive(Debug, Deserialize)]
+    pub struct SearchArgs {
+        pub query: String,
+        #[serde(default)]
+        pub exact: bool,
+        #[serde(skip)]
+        pub cache: HashMap<String, String>,
+        #[serde(flatten)]
+        pub paging: Paging,
+    }
+}
+
+#[test]
+fn test_model_manifest_from_fields() {
+    let model = User::model_manifest();
+
+    assert_eq!(model.r#type, "object");
+    assert_eq!(model.properties["id"].r#type, "string");
+    assert_eq!(model.properties["age"].r#type, "integer");
+    assert_eq!(model.properties["tags"].r#type, "array");
+    assert_eq!(model.properties["address"].model_ref.as_deref(), Some("Address"));
+    assert!(model.is_property_required("id"));
+    assert!(!model.is_property_required("address"));
+}
+
+#[test]
+fn test_model_properties_follow_serde_renames() {
+    let model = Account::model_manifest();
+    let mut properties: Vec<&str> = model.properties.keys().map(String::as_str).collect();
+    properties.sort();
+    assert_eq!(properties, vec!["accountId", "displayName", "type"]);
+    assert_eq!(model.required_properties().len(), 2);
+    assert!(!model.is_property_required("displayName"));
+
+    // Serialized values use exactly the published property names
+    let account = Account { account_id: "a1".to_string(), name: Some("Ada".to_string()), r#type: "admin".to_string() };
+    let value = serde_json::to_value(&account).unwrap();
+    let keys: Vec<&str> = value.as_object().unwrap().keys().map(String::as_str).collect();
+    assert_eq!(keys, properties);
+
+    use rust_janus::manifest::manifest_schema::property_name;
+    assert_eq!(property_name("user_id", &[], &["serde(rename_all = \"SCREAMING-KEBAB-CASE\")"]), "USER-ID");
+    assert_eq!(property_name("user_id", &[], &["serde(deny_unknown_fields, rename_all = \"PascalCase\")"]), "UserId");
+}
+
+#[test]
+fn test_model_follows_serde_default_skip_and_flatten() {
+    let model = SearchArgs::model_manifest();
+    let mut properties: Vec<&str> = model.properties.keys().map(String::as_str).collect();
+    properties.sort();
+    assert_eq!(properties, vec!["cursor", "exact", "limit", "query"]);
+
+    let mut required = model.required_properties();
+    required.sort();
+    assert_eq!(required, vec!["limit", "query"]);
+    assert!(!model.properties["exact"].is_required());
+
+    // Flattened structs are merged, not published as models
+    let mut manifest = Manifest::new("1.0.0".to_string());
+    manifest.add_typed_request::<SearchArgs, ()>("search", "Search users");
+    assert!(manifest.get_model("Paging").is_none());
+    assert!(manifest.get_request_manifest("search").unwrap().args["limit"].is_required());
+}
+
+#[test]
+fn test_typed_request_adds_arguments_and_models() {
+    let mut manifest = Manifest::new("1.0.0".to_string());
+    manifest.add_typed_request::<CreateUserArgs, User>("create_user", "Create a user");
+
+    let request = manifest.get_request_manifest("create_user").unwrap();
+    assert_eq!(request.description, "Create a user");
+    assert_eq!(request.response.model_ref.as_deref(), Some("User"));
+
+    let mut required = request.required_arguments();
+    required.sort();
+    assert_eq!(required, vec!["age", "name"]);
+
+    assert!(manifest.get_model("User").is_some());
+    assert!(manifest.get_model("Address").is_some());
+    // Argument structs are flattened into arguments, not published as models
+    assert!(manifest.get_model("CreateUserArgs").is_none());
+
+    assert!(ManifestParser::validate(&manifest).is_ok());
+}
+
+#[test]
+fn test_recursive_model_collection_terminates() {
+    let mut models = HashMap::new();
+    TreeNode::collect_models(&mut models);
+
+    assert_eq!(models.len(), 1);
+    assert!(models.contains_key("TreeNode"));
+}
+
+#[test]
+fn test_primitive_response_manifest() {
+    let response = <Vec<String> as ManifestSchema>::response_manifest();
+    assert_eq!(response.r#type, "array");
+    assert!(response.model_ref.is_none());
+}
+
+#[tokio::test]
+async fn test_typed_handler_serves_derived_manifest() {
+    let socket_path = &unique_socket_path("typed_handler");
+    let mut server = JanusServer::new(ServerConfig {
+        socket_path: socket_path.to_string(),
+        ..Default::default()
+    });
+    server.register_typed_handler("create_user", "Create a user", |args: CreateUserArgs| {
+        Ok(User {
+            id: format!("user-{}", args.name),
+            age: args.age,
+            tags: vec![],
+            address: args.address,
+        })
+    }).await;
+    server.register_typed_async_handler("count_tags", "Count user tags", |args: User| async move {
+        Ok(args.tags.len() as u64)
+    }).await;
+    server.start_listening().await.expect("Failed to start server");
+
+    let mut client = JanusClient::new(socket_path.to_string(), JanusClientConfig::default())
+        .await
+        .expect("Failed to create client");
+
+    let fetched = client.fetch_manifest().await.expect("Failed to fetch manifest");
+    assert!(fetched.has_request("create_user"));
+    assert!(fetched.has_request("count_tags"));
+    assert!(fetched.get_model("User").is_some());
+    assert!(ManifestParser::validate(&fetched).is_ok());
+
+    let mut args = HashMap::new();
+    args.insert("name".to_string(), json!("alice"));
+    args.insert("age".to_string(), json!(30));
+    args.insert("address".to_string(), json!({"street": "Main St"}));
+    let response = client
+        .send_request("create_user", Some(args), Some(Duration::from_secs(5)))
+        .await
+        .expect("Request failed");
+    assert!(response.success);
+    let user: User = serde_json::from_value(response.result.unwrap()).unwrap();
+    assert_eq!(user.id, "user-alice");
+    assert_eq!(user.address.unwrap().street, "Main St");
+
+    let mut args = HashMap::new();
+    args.insert("id".to_string(), json!("u1"));
+    args.insert("age".to_string(), json!(5));
+    args.insert("tags".to_string(), json!(["a", "b"]));
+    let response = client
+        .send_request("count_tags", Some(args), Some(Duration::from_secs(5)))
+        .await
+        .expect("Request failed");
+    assert_eq!(response.result, Some(json!(2)));
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_typed_handler_rejects_invalid_arguments() {
+    let socket_path = &unique_socket_path("typed_handler_invalid");
+    let mut server = JanusServer::new(ServerConfig {
+        socket_path: socket_path.to_string(),
+        ..Default::default()
+    });
+    server.register_typed_handler("create_user", "Create a user", |args: CreateUserArgs| {
+        Ok(args.name)
+    }).await;
+    server.start_listening().await.expect("Failed to start server");
+
+    let config = JanusClientConfig {
+        enable_validation: false,
+        ..Default::default()
+    };
+    let mut client = JanusClient::new(socket_path.to_string(), config)
+        .await
+        .expect("Failed to create client");
+
+    let mut args = HashMap::new();
+    args.insert("name".to_string(), json!("alice"));
+    args.insert("age".to_string(), json!("thirty"));
+    let response = client
+        .send_request("create_user", Some(args), Some(Duration::from_secs(5)))
+        .await
+        .expect("Request failed");
+
+    assert!(!response.success);
+    assert_eq!(response.error.unwrap().code, JSONRPCErrorCode::InvalidParams.code());
+
+    server.stop();
+}
+
+#[tokio::test]
+async fn test_typed_handlers_accept_defaults_and_no_arguments() {
+    let socket_path = &unique_socket_path("typed_handler_defaults");
+    let mut server = JanusServer::new(ServerConfig {
+        socket_path: socket_path.to_string(),
+        ..Default::default()
+    });
+    server.register_typed_handler("search", "Search users", |args: SearchArgs| {
+        Ok(json!({"query": args.query, "exact": args.exact, "limit": args.paging.limit}))
+    }).await;
+    server.register_typed_handler("stats", "Report user statistics", |_: ()| {
+        Ok(json!({"users": 2}))
+    }).await;
+    server.start_listening().await.expect("Failed to start server");
+
+    let mut client = create_client(socket_path, JanusClientConfig::default()).await;
+
+    // The server validates against the derived manifest, which leaves `exact` optional
+    let response = client
+        .send_request("search", args(json!({"query": "ada", "limit": 5})), Some(Duration::from_secs(5)))
+        .await
+        .expect("Request failed");
+    assert_eq!(response.result, Some(json!({"query": "ada", "exact": false, "limit": 5})));
+
+    let response = client.send_request("stats", None, Some(Duration::from_secs(5))).await.expect("Request failed");
+    assert_eq!(response.result, Some(json!({"users": 2})));
+    let stats: serde_json::Value = client
+        .send_typed_request("stats", &(), Some(Duration::from_secs(5)))
+        .await
+        .expect("Typed request failed");
+    assert_eq!(stats, json!({"users": 2}));
+
+    server.stop();
+}